@@ -0,0 +1,7 @@
+#![no_main]
+
+libfuzzer_sys::fuzz_target!(|input: (openrtb2::BidRequest, openrtb2::BidResponse)| {
+    let (req, res) = input;
+    openrtb2_fuzz::check_round_trip!(openrtb2::BidRequest, req);
+    openrtb2_fuzz::check_round_trip!(openrtb2::BidResponse, res);
+});
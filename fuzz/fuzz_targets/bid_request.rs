@@ -0,0 +1,9 @@
+#![no_main]
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+    openrtb2_fuzz::round_trip!(openrtb2::BidRequest, data);
+});
+
+libfuzzer_sys::fuzz_mutator!(|data: &mut [u8], size: usize, max_size: usize, seed: u32| {
+    openrtb2_fuzz::mutate(data, size, max_size, seed)
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+    openrtb2_fuzz::round_trip!(openrtb2::Native, data);
+    // the seeds in tests/json are bid requests, their Native objects are checked too
+    if let Ok(req) = serde_json::from_slice::<openrtb2::BidRequest>(data) {
+        for native in req.imp.iter().filter_map(|imp| imp.native.as_ref()) {
+            openrtb2_fuzz::check_round_trip!(openrtb2::Native, *native);
+        }
+    }
+});
+
+libfuzzer_sys::fuzz_mutator!(|data: &mut [u8], size: usize, max_size: usize, seed: u32| {
+    openrtb2_fuzz::mutate(data, size, max_size, seed)
+});
@@ -0,0 +1,198 @@
+//! Checks and structure-aware mutator shared by the fuzz targets.
+
+pub use serde_json;
+
+/// Checks that serializing `$value` of type `$ty` and parsing the JSON again gives an equal value
+/// which serializes to the same JSON.
+#[macro_export]
+macro_rules! check_round_trip {
+    ($ty:ty, $value:expr) => {{
+        let value = &$value;
+        let json = $crate::serde_json::to_vec(value).expect("a parsed value serializes");
+        let reparsed: $ty = $crate::serde_json::from_slice(&json)
+            .unwrap_or_else(|e| panic!("{}: {}", e, String::from_utf8_lossy(&json)));
+        assert_eq!(*value, reparsed, "{}", String::from_utf8_lossy(&json));
+        let json2 = $crate::serde_json::to_vec(&reparsed).expect("a parsed value serializes");
+        assert_eq!(json, json2, "{}", String::from_utf8_lossy(&json));
+    }};
+}
+
+/// Parses `$data` as `$ty` and checks its round-trip with [`check_round_trip`]. Inputs which do
+/// not parse are ignored.
+#[macro_export]
+macro_rules! round_trip {
+    ($ty:ty, $data:expr) => {{
+        if let Ok(value) = $crate::serde_json::from_slice::<$ty>($data) {
+            $crate::check_round_trip!($ty, value);
+        }
+    }};
+}
+
+/// Mutates the JSON document in `data[..size]` and returns its new size.
+///
+/// Documents which parse as JSON are mutated structurally most of the time, so the inputs stay
+/// valid JSON and reach the OpenRTB deserializers: values are replaced with numbers at the
+/// boundaries of the custom `Deserialize` implementations, strings, arrays and objects are
+/// emptied or grown, `site` and `app` are swapped or both sent, and nodes are spliced into other
+/// places. Other inputs go through the libFuzzer byte mutators.
+pub fn mutate(data: &mut [u8], size: usize, max_size: usize, seed: u32) -> usize {
+    let mut rng = Rng(seed | 1);
+    match serde_json::from_slice::<serde_json::Value>(&data[..size]) {
+        Ok(mut value) if rng.below(8) != 0 => {
+            for _ in 0..=rng.below(3) {
+                mutate_value(&mut value, &mut rng);
+            }
+            let json = serde_json::to_vec(&value).expect("a JSON value serializes");
+            if json.len() > max_size {
+                return libfuzzer_sys::fuzzer_mutate(data, size, max_size);
+            }
+            data[..json.len()].copy_from_slice(&json);
+            json.len()
+        }
+        _ => libfuzzer_sys::fuzzer_mutate(data, size, max_size),
+    }
+}
+
+/// Numbers around the limits of the integer types and of the custom `Deserialize`
+/// implementations, e.g. `AuctionType` (500) and `StartDelay` (-2).
+const NUMBERS: &[f64] = &[
+    0.0,
+    1.0,
+    -1.0,
+    -2.0,
+    -3.0,
+    2.0,
+    3.0,
+    500.0,
+    501.0,
+    127.0,
+    128.0,
+    -129.0,
+    255.0,
+    256.0,
+    32768.0,
+    2147483647.0,
+    2147483648.0,
+    -2147483649.0,
+    1.5,
+    1e-7,
+    1e38,
+    1e300,
+];
+
+/// Members whose values go through custom `Deserialize` implementations or `json_ext`.
+const KEYS: &[&str] = &[
+    "at",
+    "startdelay",
+    "maxextended",
+    "cattax",
+    "gender",
+    "test",
+    "ext",
+    "site",
+    "app",
+    "bidfloor",
+    "price",
+];
+
+fn mutate_value(root: &mut serde_json::Value, rng: &mut Rng) {
+    let count = count(root);
+    let donor = nth(root, &mut rng.below(count)).cloned().unwrap_or_default();
+    let Some(node) = nth(root, &mut rng.below(count)) else {
+        return;
+    };
+    if rng.below(4) == 0 {
+        *node = donor;
+        return;
+    }
+    match node {
+        serde_json::Value::String(s) => match rng.below(4) {
+            0 => s.clear(),
+            1 => *s = s.repeat(2),
+            2 => *s = "\u{0}\u{e9}\"\\\u{1F600}".into(),
+            _ => *node = number(rng),
+        },
+        serde_json::Value::Array(items) => match rng.below(3) {
+            0 => items.clear(),
+            1 if !items.is_empty() => {
+                let item = items[rng.below(items.len())].clone();
+                items.push(item);
+            }
+            _ if !items.is_empty() => {
+                items.remove(rng.below(items.len()));
+            }
+            _ => items.push(donor),
+        },
+        serde_json::Value::Object(map) => match rng.below(4) {
+            0 if !map.is_empty() => {
+                let key = map.keys().nth(rng.below(map.len())).cloned().unwrap_or_default();
+                map.remove(&key);
+            }
+            1 => match (map.remove("site"), map.remove("app")) {
+                (Some(site), app) => {
+                    map.insert("app".into(), site);
+                    map.extend(app.map(|app| ("site".into(), app)));
+                }
+                (None, Some(app)) => {
+                    map.insert("site".into(), app);
+                }
+                (None, None) => {
+                    map.insert("ext".into(), serde_json::Value::Object(Default::default()));
+                }
+            },
+            2 => {
+                let channel = map.get("site").or_else(|| map.get("app")).cloned().unwrap_or(donor);
+                map.insert("site".into(), channel.clone());
+                map.insert("app".into(), channel);
+            }
+            _ => {
+                let key = KEYS[rng.below(KEYS.len())];
+                map.insert(key.into(), number(rng));
+            }
+        },
+        _ => *node = number(rng),
+    }
+}
+
+fn number(rng: &mut Rng) -> serde_json::Value {
+    let n = NUMBERS[rng.below(NUMBERS.len())];
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        (n as i64).into()
+    } else {
+        n.into()
+    }
+}
+
+/// Returns the number of values in `value`, including itself.
+fn count(value: &serde_json::Value) -> usize {
+    1 + match value {
+        serde_json::Value::Array(items) => items.iter().map(count).sum(),
+        serde_json::Value::Object(map) => map.values().map(count).sum(),
+        _ => 0,
+    }
+}
+
+/// Returns the `n`th value of `value` in pre-order.
+fn nth<'v>(value: &'v mut serde_json::Value, n: &mut usize) -> Option<&'v mut serde_json::Value> {
+    if *n == 0 {
+        return Some(value);
+    }
+    *n -= 1;
+    match value {
+        serde_json::Value::Array(items) => items.iter_mut().find_map(|item| nth(item, n)),
+        serde_json::Value::Object(map) => map.values_mut().find_map(|item| nth(item, n)),
+        _ => None,
+    }
+}
+
+/// xorshift32, seeded by libFuzzer so mutations are reproducible.
+struct Rng(u32);
+
+impl Rng {
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0 as usize % n.max(1)
+    }
+}
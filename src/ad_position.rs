@@ -7,6 +7,8 @@
 /// Practitioners should keep in sync with updates to the IQG values as published on IAB.com. Values
 /// “4” - “7” apply to apps per the mobile addendum to IQG version 2.1.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum AdPosition {
     /// Unknown
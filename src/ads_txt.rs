@@ -0,0 +1,190 @@
+//! ads.txt and app-ads.txt files.
+//!
+//! Publishers list the advertising systems authorized to sell their inventory in an `ads.txt`
+//! file on their website, or an `app-ads.txt` file on the developer website of their apps. Both
+//! share the same format: one record per line, `#` comments, and `NAME=value` variables.
+//!
+//! ```
+//! # use openrtb2::ads_txt::{AdsTxt, Relationship};
+//! let ads_txt: AdsTxt = "
+//!     # ads.txt of example.com
+//!     greenadexchange.com, XF7342, DIRECT, 5jyxf8k54
+//!     blueadexchange.com, 4536, RESELLER
+//!     CONTACT=adops@example.com
+//! "
+//! .parse()?;
+//! assert_eq!(ads_txt.records.len(), 2);
+//! assert_eq!(ads_txt.relationship("BlueAdExchange.com", "4536"), Some(Relationship::Reseller));
+//! assert_eq!(ads_txt.variable("contact").collect::<Vec<_>>(), vec!["adops@example.com"]);
+//! # Ok::<(), std::convert::Infallible>(())
+//! ```
+
+/// The relationship between the publisher and the account of the advertising system.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Relationship {
+    /// The publisher directly controls the account.
+    Direct,
+    /// The publisher authorized another entity to control the account and resell its inventory.
+    Reseller,
+}
+
+/// One authorized seller.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Record {
+    /// Canonical domain of the advertising system, in lowercase.
+    pub domain: String,
+    /// Publisher account ID in the advertising system.
+    pub account_id: String,
+    /// Type of account.
+    pub relationship: Relationship,
+    /// ID of the advertising system within a certification authority, e.g. the TAG-ID.
+    pub certification_authority: Option<String>,
+}
+
+/// A parsed ads.txt or app-ads.txt file.
+///
+/// Parsing follows the specification in ignoring malformed lines; their numbers are kept in
+/// `invalid_lines`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AdsTxt {
+    /// Authorized sellers in file order.
+    pub records: Vec<Record>,
+    /// Variables in file order, names in uppercase.
+    pub variables: Vec<(String, String)>,
+    /// 1-based numbers of the lines that could not be parsed.
+    pub invalid_lines: Vec<usize>,
+}
+
+impl AdsTxt {
+    /// Parses the content of an ads.txt file.
+    pub fn parse(s: &str) -> Self {
+        let mut ads_txt = Self::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            match parse_line(line) {
+                Some(Line::Record(record)) => ads_txt.records.push(record),
+                Some(Line::Variable(name, value)) => ads_txt.variables.push((name, value)),
+                None => ads_txt.invalid_lines.push(i + 1),
+            }
+        }
+        ads_txt
+    }
+
+    /// Reads and parses an ads.txt file.
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Returns the values of the variable `name`, case-insensitively.
+    pub fn variable<'s>(&'s self, name: &'s str) -> impl Iterator<Item = &'s str> + 's {
+        self.variables
+            .iter()
+            .filter(move |(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the records of account `account_id` in advertising system `domain`.
+    pub fn find<'s>(&'s self, domain: &'s str, account_id: &'s str) -> impl Iterator<Item = &'s Record> + 's {
+        self.records
+            .iter()
+            .filter(move |record| record.domain.eq_ignore_ascii_case(domain) && record.account_id == account_id)
+    }
+
+    /// Returns the relationship of account `account_id` in advertising system `domain`, Direct if
+    /// the account is listed as both, None if it is not listed.
+    pub fn relationship(&self, domain: &str, account_id: &str) -> Option<Relationship> {
+        self.find(domain, account_id)
+            .map(|record| record.relationship)
+            .min_by_key(|relationship| *relationship == Relationship::Reseller)
+    }
+}
+
+impl std::str::FromStr for AdsTxt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s))
+    }
+}
+
+enum Line {
+    Record(Record),
+    Variable(String, String),
+}
+
+fn parse_line(line: &str) -> Option<Line> {
+    // extension fields follow a semicolon
+    let line = line.split(';').next().unwrap_or_default();
+    if let Some((name, value)) = line.split_once('=') {
+        if !name.contains(',') {
+            let name = name.trim();
+            if name.is_empty() || name.contains(char::is_whitespace) {
+                return None;
+            }
+            return Some(Line::Variable(name.to_ascii_uppercase(), value.trim().to_owned()));
+        }
+    }
+
+    let mut fields = line.split(',').map(str::trim);
+    let domain = fields.next().filter(|f| !f.is_empty())?.to_ascii_lowercase();
+    let account_id = fields.next().filter(|f| !f.is_empty())?.to_owned();
+    let relationship = match fields.next()?.to_ascii_uppercase().as_str() {
+        "DIRECT" => Relationship::Direct,
+        "RESELLER" => Relationship::Reseller,
+        _ => return None,
+    };
+    let certification_authority = fields.next().filter(|f| !f.is_empty()).map(ToOwned::to_owned);
+    if fields.next().is_some() {
+        return None;
+    }
+    Some(Line::Record(Record {
+        domain,
+        account_id,
+        relationship,
+        certification_authority,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let ads_txt = AdsTxt::parse(
+            "# comment\n\
+             GreenAdExchange.com , XF7342 , direct , 5jyxf8k54 # inline comment\n\
+             redssp.com, 1, RESELLER; ext=1\n\
+             redssp.com, 1, DIRECT\n\
+             missing.com, 1\n\
+             bad.com, 1, PARTNER\n\
+             \n\
+             subdomain=news.example.com\n\
+             OWNERDOMAIN=example.com\n",
+        );
+        assert_eq!(
+            ads_txt.records[0],
+            Record {
+                domain: "greenadexchange.com".into(),
+                account_id: "XF7342".into(),
+                relationship: Relationship::Direct,
+                certification_authority: Some("5jyxf8k54".into()),
+            }
+        );
+        assert_eq!(ads_txt.records.len(), 3);
+        assert_eq!(ads_txt.relationship("redssp.com", "1"), Some(Relationship::Direct));
+        assert_eq!(ads_txt.relationship("redssp.com", "2"), None);
+        assert_eq!(ads_txt.find("redssp.com", "1").count(), 2);
+        assert_eq!(
+            ads_txt.variables,
+            vec![
+                ("SUBDOMAIN".into(), "news.example.com".into()),
+                ("OWNERDOMAIN".into(), "example.com".into())
+            ]
+        );
+        assert_eq!(ads_txt.invalid_lines, vec![5, 6]);
+    }
+}
@@ -2,6 +2,8 @@
 ///
 /// The following table is a list of API frameworks supported by the publisher.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum ApiFramework {
     /// VPAID 1.0
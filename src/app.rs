@@ -5,6 +5,8 @@
 /// Site object. At a minimum, it is useful to provide an App ID or bundle, but this is not strictly
 /// required.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct App<'a> {
     /// string; recommended
     /// Exchange-specific app ID.
@@ -50,6 +52,12 @@ pub struct App<'a> {
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub pagecat: Option<Vec<std::borrow::Cow<'a, str>>>,
 
+    /// integer; default 1
+    /// The taxonomy in use. If no cattax field is supplied IAB Content Category Taxonomy 1.0 is
+    /// assumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
     /// string
     /// Application version.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
@@ -58,11 +66,15 @@ pub struct App<'a> {
     /// integer
     /// Indicates if the app has a privacy policy, where 0 = no, 1 = yes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub privacypolicy: Option<json_ext::Flag>,
 
     /// integer
     /// 0 = app is free, 1 = the app is a paid version.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub paid: Option<json_ext::Flag>,
 
     /// object
@@ -83,6 +95,8 @@ pub struct App<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
@@ -0,0 +1,67 @@
+//! Generators of spec-valid field values for the `arbitrary::Arbitrary` implementations.
+//!
+//! Most types derive `Arbitrary`; the fields whose Rust type admits values the spec or the JSON
+//! representation does not use these generators instead, e.g. prices are finite and non-negative
+//! with at most 4 decimals so they survive a JSON round-trip.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Returns a vector of at least one element, e.g. `BidRequest.imp`.
+pub(crate) fn non_empty<'a, T: Arbitrary<'a>>(u: &mut Unstructured<'a>) -> Result<Vec<T>> {
+    let mut items = vec![T::arbitrary(u)?];
+    items.extend(u.arbitrary_iter()?.take(7).collect::<Result<Vec<T>>>()?);
+    Ok(items)
+}
+
+/// Returns a CPM price between 0 and 100000 with at most 4 decimals.
+pub(crate) fn price(u: &mut Unstructured) -> Result<f64> {
+    Ok(u.int_in_range(0..=1_000_000_000u32)? as f64 / 10_000.0)
+}
+
+/// Returns an optional CPM price, see [`price`].
+pub(crate) fn option_price(u: &mut Unstructured) -> Result<Option<f64>> {
+    u.arbitrary::<bool>()?.then(|| price(u)).transpose()
+}
+
+/// Returns an optional latitude between -90 and 90 with at most 4 decimals.
+pub(crate) fn option_latitude(u: &mut Unstructured) -> Result<Option<f32>> {
+    u.arbitrary::<bool>()?
+        .then(|| Ok(u.int_in_range(-900_000..=900_000i32)? as f32 / 10_000.0))
+        .transpose()
+}
+
+/// Returns an optional longitude between -180 and 180 with at most 4 decimals.
+pub(crate) fn option_longitude(u: &mut Unstructured) -> Result<Option<f32>> {
+    u.arbitrary::<bool>()?
+        .then(|| Ok(u.int_in_range(-1_800_000..=1_800_000i32)? as f32 / 10_000.0))
+        .transpose()
+}
+
+/// Returns a probability between 0 and 1 with at most 3 decimals, e.g. `Metric.value`.
+pub(crate) fn probability(u: &mut Unstructured) -> Result<f32> {
+    Ok(u.int_in_range(0..=1000u16)? as f32 / 1000.0)
+}
+
+/// Returns an optional 0/1 flag.
+pub(crate) fn option_flag(u: &mut Unstructured) -> Result<Option<json_ext::Flag>> {
+    Ok(Option::<bool>::arbitrary(u)?.map(crate::ext::flag))
+}
+
+/// Returns an optional extension object of up to 4 string, integer or boolean members.
+pub(crate) fn option_object<'a>(u: &mut Unstructured) -> Result<Option<json_ext::Object<'a>>> {
+    if !u.arbitrary::<bool>()? {
+        return Ok(None);
+    }
+    let mut map = serde_json::Map::new();
+    for _ in 0..u.int_in_range(0..=4u8)? {
+        let value = match u.int_in_range(0..=2u8)? {
+            0 => serde_json::Value::String(u.arbitrary()?),
+            1 => u.arbitrary::<i64>()?.into(),
+            _ => u.arbitrary::<bool>()?.into(),
+        };
+        map.insert(u.arbitrary()?, value);
+    }
+    serde::Deserialize::deserialize(serde_json::Value::Object(map))
+        .map(Some)
+        .map_err(|_| arbitrary::Error::IncorrectFormat)
+}
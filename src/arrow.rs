@@ -0,0 +1,671 @@
+//! Arrow record batches of bid requests and responses.
+//!
+//! [`requests`] and [`responses`] flatten batches of objects into Arrow [`RecordBatch`]es for
+//! columnar stores, and with the `parquet` feature [`write_parquet`] writes them to Parquet files.
+//! With [`Layout::Nested`] a request is one row with its impressions in an `imp` list of structs,
+//! and a response is one row with its bids in a `bid` list; with [`Layout::Exploded`] every
+//! impression or bid is a row of its own, its columns prefixed with `imp_` or `bid_`, next to the
+//! columns of its request or response. Objects without impressions or bids are kept as a single
+//! row whose `imp_` or `bid_` columns are null.
+//!
+//! Enums are written as their integer value plus a `_name` column holding the name of the value,
+//! e.g. `device_devicetype` = 4 and `device_devicetype_name` = `Phone`. Flags are booleans and
+//! `ext` objects are JSON strings. All columns are nullable except the `id` of requests and
+//! responses.
+//!
+//! The names are part of the schema and do not depend on the Rust types:
+//!
+//! | Column                  | Names                                                                 |
+//! |-------------------------|-----------------------------------------------------------------------|
+//! | `at`                    | `FirstPrice`, `SecondPricePlus`, `ExchangeSpecific`                   |
+//! | `device_devicetype`     | `Mobile`, `PersonalComputer`, `ConnectedTv`, `Phone`, `Tablet`, `ConnectedDevice`, `SetTopBox` |
+//! | `device_connectiontype` | `Unknown`, `Ethernet`, `WiFi`, `CellUnknown`, `Cell2G`, `Cell3G`, `Cell4G` |
+//! | `banner_pos`            | `Unknown`, `AboveTheFold`, `LikelyBelowTheFold`, `BelowTheFold`, `Header`, `Footer`, `Sidebar`, `FullScreen` |
+//! | `video_placement`       | `InStream`, `InBanner`, `InArticle`, `InFeed`, `Floating`             |
+//! | `nbr`                   | `UnknownError`, `TechnicalError`, `InvalidRequest`, `KnownWebSpider`, `SuspectedNonHumanTraffic`, `CloudDataCenterProxyIp`, `UnsupportedDevice`, `BlockedPublisher`, `UnmatchedUser`, `DailyReaderCap`, `DailyDomainCap` |
+//!
+//! Request columns:
+//!
+//! | Column                                             | Type         | Source                                 |
+//! |----------------------------------------------------|--------------|----------------------------------------|
+//! | `id`                                               | Utf8         | `id`                                   |
+//! | `at`, `at_name`                                    | Int32, Utf8  | `at`                                   |
+//! | `tmax`                                             | Int32        | `tmax`                                 |
+//! | `test`                                             | Boolean      | `test`                                 |
+//! | `cur`, `bcat`, `badv`                              | List\<Utf8\> | `cur`, `bcat`, `badv`                  |
+//! | `site_id`, `site_domain`, `site_page`              | Utf8         | `site`                                 |
+//! | `app_id`, `app_bundle`                             | Utf8         | `app`                                  |
+//! | `publisher_id`                                     | Utf8         | `site.publisher.id` or `app.publisher.id` |
+//! | `device_ua`, `device_ip`, `device_ipv6`            | Utf8         | `device`                               |
+//! | `device_devicetype`, `device_devicetype_name`      | Int32, Utf8  | `device.devicetype`                    |
+//! | `device_make`, `device_model`, `device_os`         | Utf8         | `device`                               |
+//! | `device_connectiontype`, `device_connectiontype_name` | Int32, Utf8 | `device.connectiontype`             |
+//! | `device_country`, `device_region`                  | Utf8         | `device.geo`                           |
+//! | `user_id`, `user_buyeruid`                         | Utf8         | `user`                                 |
+//! | `regs_coppa`                                       | Boolean      | `regs.coppa`                           |
+//! | `source_tid`                                       | Utf8         | `source.tid`                           |
+//! | `ext`, `device_ext`, `user_ext`, `regs_ext`        | Utf8 (JSON)  | `ext` of the request and its objects   |
+//! | `imp` or `imp_*`                                   |              | impression columns                     |
+//!
+//! Impression columns:
+//!
+//! | Column                                             | Type         | Source                                 |
+//! |----------------------------------------------------|--------------|----------------------------------------|
+//! | `id`, `tagid`                                      | Utf8         | `id`, `tagid`                          |
+//! | `bidfloor`, `bidfloorcur`                          | Float64, Utf8 | `bidfloor`, `bidfloorcur`             |
+//! | `instl`, `secure`                                  | Boolean      | `instl`, `secure`                      |
+//! | `media_types`                                      | List\<Utf8\> | `banner`, `video`, `audio` and `native` present |
+//! | `banner_w`, `banner_h`                             | Int32        | `banner.w/h`, or its first `format`    |
+//! | `banner_pos`, `banner_pos_name`                    | Int32, Utf8  | `banner.pos`                           |
+//! | `video_mimes`                                      | List\<Utf8\> | `video.mimes`                          |
+//! | `video_minduration`, `video_maxduration`           | Int32        | `video`                                |
+//! | `video_w`, `video_h`                               | Int32        | `video`                                |
+//! | `video_placement`, `video_placement_name`          | Int32, Utf8  | `video.placement`                      |
+//! | `deal_ids`                                         | List\<Utf8\> | `pmp.deals[].id`                       |
+//! | `ext`                                              | Utf8 (JSON)  | `ext`                                  |
+//!
+//! Response columns are `id`, `bidid`, `cur`, `nbr`, `nbr_name`, `ext` and `bid` or `bid_*`. Bid
+//! columns are `seat` (of the seat bid), `id`, `impid`, `price` (Float64), `adid`, `crid`, `cid`,
+//! `dealid`, `nurl`, `burl`, `lurl`, `adomain` and `cat` (List\<Utf8\>), `w`, `h` (Int32) and
+//! `ext`. The markup in `adm` is not written.
+//!
+//! ```
+//! # use openrtb2::arrow::{self, Layout};
+//! let req: openrtb2::BidRequest =
+//!     serde_json::from_str(include_str!("../tests/json/6.3.4_video.json"))?;
+//! let batch = arrow::requests(&[req], Layout::Exploded)?;
+//! assert_eq!(batch.num_rows(), 1);
+//! assert_eq!(batch.schema(), arrow::request_schema(Layout::Exploded));
+//! assert!(batch.column_by_name("imp_video_placement_name").is_some());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`requests`]: ./fn.requests.html
+//! [`responses`]: ./fn.responses.html
+//! [`RecordBatch`]: https://docs.rs/arrow/54/arrow/record_batch/struct.RecordBatch.html
+//! [`write_parquet`]: ./fn.write_parquet.html
+//! [`Layout::Nested`]: ./enum.Layout.html#variant.Nested
+//! [`Layout::Exploded`]: ./enum.Layout.html#variant.Exploded
+
+use ::arrow::array::{
+    ArrayRef, BooleanArray, Float64Array, Int32Array, ListArray, ListBuilder, StringArray, StringBuilder, StructArray,
+};
+use ::arrow::buffer::OffsetBuffer;
+use ::arrow::datatypes::{DataType, Field, Fields, Schema, SchemaRef};
+use ::arrow::error::ArrowError;
+use ::arrow::record_batch::RecordBatch;
+use std::sync::Arc;
+
+/// How the impressions of a request, or the bids of a response, are laid out.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Layout {
+    /// One row per object, with a list of structs.
+    Nested,
+    /// One row per impression or bid, with prefixed columns.
+    Exploded,
+}
+
+/// Returns the schema of the record batches of [`requests`].
+///
+/// [`requests`]: ./fn.requests.html
+pub fn request_schema(layout: Layout) -> SchemaRef {
+    requests(&[], layout)
+        .expect("an empty batch matches its schema")
+        .schema()
+}
+
+/// Returns the schema of the record batches of [`responses`].
+///
+/// [`responses`]: ./fn.responses.html
+pub fn response_schema(layout: Layout) -> SchemaRef {
+    responses(&[], layout)
+        .expect("an empty batch matches its schema")
+        .schema()
+}
+
+/// Converts bid requests to a record batch.
+pub fn requests(reqs: &[crate::BidRequest], layout: Layout) -> Result<RecordBatch, ArrowError> {
+    match layout {
+        Layout::Nested => {
+            let rows: Vec<_> = reqs.iter().collect();
+            let imps: Vec<_> = reqs.iter().flat_map(|req| req.imp.iter().map(Some)).collect();
+            let mut columns = request_columns(&rows)?;
+            let lengths = reqs.iter().map(|req| req.imp.len());
+            columns.list("imp", imp_columns(&imps)?, lengths)?;
+            columns.finish()
+        }
+        Layout::Exploded => {
+            let (rows, imps): (Vec<_>, Vec<_>) = reqs
+                .iter()
+                .flat_map(|req| match req.imp.is_empty() {
+                    true => vec![(req, None)],
+                    false => req.imp.iter().map(|imp| (req, Some(imp))).collect(),
+                })
+                .unzip();
+            let mut columns = request_columns(&rows)?;
+            columns.prefixed("imp_", imp_columns(&imps)?);
+            columns.finish()
+        }
+    }
+}
+
+/// Converts bid responses to a record batch.
+pub fn responses(res: &[crate::BidResponse], layout: Layout) -> Result<RecordBatch, ArrowError> {
+    match layout {
+        Layout::Nested => {
+            let rows: Vec<_> = res.iter().collect();
+            let bids: Vec<_> = res.iter().map(bids).collect();
+            let mut columns = response_columns(&rows)?;
+            let lengths = bids.iter().map(Vec::len);
+            let flat: Vec<_> = bids.iter().flatten().copied().map(Some).collect();
+            columns.list("bid", bid_columns(&flat)?, lengths)?;
+            columns.finish()
+        }
+        Layout::Exploded => {
+            let (rows, flat): (Vec<_>, Vec<_>) = res
+                .iter()
+                .flat_map(|res| match bids(res) {
+                    bids if bids.is_empty() => vec![(res, None)],
+                    bids => bids.into_iter().map(|bid| (res, Some(bid))).collect(),
+                })
+                .unzip();
+            let mut columns = response_columns(&rows)?;
+            columns.prefixed("bid_", bid_columns(&flat)?);
+            columns.finish()
+        }
+    }
+}
+
+/// Returns the bids of a response with the seat of their seat bid.
+fn bids<'r>(res: &'r crate::BidResponse) -> Vec<(Option<&'r str>, &'r crate::Bid<'r>)> {
+    res.seatbid
+        .iter()
+        .flatten()
+        .flat_map(|seatbid| seatbid.bid.iter().map(move |bid| (seatbid.seat.as_deref(), bid)))
+        .collect()
+}
+
+/// Writes record batches to a Parquet file.
+///
+/// ```no_run
+/// # use openrtb2::arrow::{self, Layout};
+/// # let reqs: Vec<openrtb2::BidRequest> = Vec::new();
+/// let batch = arrow::requests(&reqs, Layout::Nested)?;
+/// let file = std::fs::File::create("requests.parquet")?;
+/// arrow::write_parquet(file, batch.schema(), [&batch])?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "parquet")]
+pub fn write_parquet<'b, W>(
+    writer: W,
+    schema: SchemaRef,
+    batches: impl IntoIterator<Item = &'b RecordBatch>,
+) -> Result<(), parquet::errors::ParquetError>
+where
+    W: std::io::Write + Send,
+{
+    let mut writer = parquet::arrow::ArrowWriter::try_new(writer, schema, None)?;
+    for batch in batches {
+        writer.write(batch)?;
+    }
+    writer.close()?;
+    Ok(())
+}
+
+fn request_columns(reqs: &[&crate::BidRequest]) -> Result<Columns, ArrowError> {
+    fn site<'r>(req: &'r crate::BidRequest) -> Option<&'r crate::Site<'r>> {
+        match &req.channel {
+            Some(crate::DistributionChannel::Site(site)) => Some(site),
+            _ => None,
+        }
+    }
+    fn app<'r>(req: &'r crate::BidRequest) -> Option<&'r crate::App<'r>> {
+        match &req.channel {
+            Some(crate::DistributionChannel::App(app)) => Some(app),
+            _ => None,
+        }
+    }
+    fn device<'r>(req: &'r crate::BidRequest) -> Option<&'r crate::Device<'r>> {
+        req.device.as_ref()
+    }
+    fn geo<'r>(req: &'r crate::BidRequest) -> Option<&'r crate::Geo<'r>> {
+        device(req)?.geo.as_ref()
+    }
+    fn user<'r>(req: &'r crate::BidRequest) -> Option<&'r crate::User<'r>> {
+        req.user.as_ref()
+    }
+
+    let mut c = Columns::default();
+    c.required_utf8("id", reqs.iter().map(|req| req.id.as_ref()));
+    c.code("at", reqs.iter().map(|req| req.at));
+    c.int32("tmax", reqs.iter().map(|req| req.tmax));
+    c.flag("test", reqs.iter().map(|req| req.test));
+    c.utf8_list("cur", reqs.iter().map(|req| req.cur.as_deref()));
+    c.utf8_list("bcat", reqs.iter().map(|req| req.bcat.as_deref()));
+    c.utf8_list("badv", reqs.iter().map(|req| req.badv.as_deref()));
+    c.utf8("site_id", reqs.iter().map(|req| site(req)?.id.as_deref()));
+    c.utf8("site_domain", reqs.iter().map(|req| site(req)?.domain.as_deref()));
+    c.utf8("site_page", reqs.iter().map(|req| site(req)?.page.as_deref()));
+    c.utf8("app_id", reqs.iter().map(|req| app(req)?.id.as_deref()));
+    c.utf8("app_bundle", reqs.iter().map(|req| app(req)?.bundle.as_deref()));
+    c.utf8(
+        "publisher_id",
+        reqs.iter().map(|req| {
+            let publisher = site(req).map_or_else(|| app(req)?.publisher.as_ref(), |site| site.publisher.as_ref());
+            publisher?.id.as_deref()
+        }),
+    );
+    c.utf8("device_ua", reqs.iter().map(|req| device(req)?.ua.as_deref()));
+    c.utf8("device_ip", reqs.iter().map(|req| device(req)?.ip.as_deref()));
+    c.utf8("device_ipv6", reqs.iter().map(|req| device(req)?.ipv6.as_deref()));
+    c.code("device_devicetype", reqs.iter().map(|req| device(req)?.devicetype));
+    c.utf8("device_make", reqs.iter().map(|req| device(req)?.make.as_deref()));
+    c.utf8("device_model", reqs.iter().map(|req| device(req)?.model.as_deref()));
+    c.utf8("device_os", reqs.iter().map(|req| device(req)?.os.as_deref()));
+    c.code(
+        "device_connectiontype",
+        reqs.iter().map(|req| device(req)?.connectiontype),
+    );
+    c.utf8("device_country", reqs.iter().map(|req| geo(req)?.country.as_deref()));
+    c.utf8("device_region", reqs.iter().map(|req| geo(req)?.region.as_deref()));
+    c.utf8("user_id", reqs.iter().map(|req| user(req)?.id.as_deref()));
+    c.utf8("user_buyeruid", reqs.iter().map(|req| user(req)?.buyeruid.as_deref()));
+    c.flag("regs_coppa", reqs.iter().map(|req| req.regs.as_ref()?.coppa));
+    c.utf8("source_tid", reqs.iter().map(|req| req.source.as_ref()?.tid.as_deref()));
+    c.json("ext", reqs.iter().map(|req| req.ext.as_ref()))?;
+    c.json("device_ext", reqs.iter().map(|req| device(req)?.ext.as_ref()))?;
+    c.json("user_ext", reqs.iter().map(|req| user(req)?.ext.as_ref()))?;
+    c.json("regs_ext", reqs.iter().map(|req| req.regs.as_ref()?.ext.as_ref()))?;
+    Ok(c)
+}
+
+#[allow(deprecated)]
+fn imp_columns(imps: &[Option<&crate::Imp>]) -> Result<Columns, ArrowError> {
+    fn banner<'r>(imp: &Option<&'r crate::Imp<'r>>) -> Option<&'r crate::Banner<'r>> {
+        imp.and_then(|imp| imp.banner.as_ref())
+    }
+    fn video<'r>(imp: &Option<&'r crate::Imp<'r>>) -> Option<&'r crate::Video<'r>> {
+        imp.and_then(|imp| imp.video.as_ref())
+    }
+    fn size(imp: &Option<&crate::Imp>) -> Option<(Option<i32>, Option<i32>)> {
+        let banner = banner(imp)?;
+        match (banner.w, banner.h) {
+            (None, None) => banner.format.as_ref()?.first().map(|format| (format.w, format.h)),
+            size => Some(size),
+        }
+    }
+
+    let mut c = Columns::default();
+    c.utf8("id", imps.iter().map(|imp| Some(imp.as_ref()?.id.as_ref())));
+    c.utf8("tagid", imps.iter().map(|imp| imp.as_ref()?.tagid.as_deref()));
+    c.float64("bidfloor", imps.iter().map(|imp| imp.as_ref()?.bidfloor));
+    c.utf8(
+        "bidfloorcur",
+        imps.iter().map(|imp| imp.as_ref()?.bidfloorcur.as_deref()),
+    );
+    c.flag("instl", imps.iter().map(|imp| imp.as_ref()?.instl));
+    c.flag("secure", imps.iter().map(|imp| imp.as_ref()?.secure));
+    c.utf8_list(
+        "media_types",
+        imps.iter().map(|imp| {
+            let imp = imp.as_ref()?;
+            let media = [
+                ("banner", imp.banner.is_some()),
+                ("video", imp.video.is_some()),
+                ("audio", imp.audio.is_some()),
+                ("native", imp.native.is_some()),
+            ];
+            Some(media.into_iter().filter(|(_, present)| *present).map(|(name, _)| name))
+        }),
+    );
+    c.int32("banner_w", imps.iter().map(|imp| size(imp)?.0));
+    c.int32("banner_h", imps.iter().map(|imp| size(imp)?.1));
+    c.code("banner_pos", imps.iter().map(|imp| banner(imp)?.pos));
+    c.utf8_list("video_mimes", imps.iter().map(|imp| Some(&video(imp)?.mimes)));
+    c.int32("video_minduration", imps.iter().map(|imp| video(imp)?.minduration));
+    c.int32("video_maxduration", imps.iter().map(|imp| video(imp)?.maxduration));
+    c.int32("video_w", imps.iter().map(|imp| video(imp)?.w));
+    c.int32("video_h", imps.iter().map(|imp| video(imp)?.h));
+    c.code("video_placement", imps.iter().map(|imp| video(imp)?.placement));
+    c.utf8_list(
+        "deal_ids",
+        imps.iter().map(|imp| {
+            let deals = imp.as_ref()?.pmp.as_ref()?.deals.as_ref()?;
+            Some(deals.iter().map(|deal| &deal.id))
+        }),
+    );
+    c.json("ext", imps.iter().map(|imp| imp.as_ref()?.ext.as_ref()))?;
+    Ok(c)
+}
+
+fn response_columns(res: &[&crate::BidResponse]) -> Result<Columns, ArrowError> {
+    let mut c = Columns::default();
+    c.required_utf8("id", res.iter().map(|res| res.id.as_ref()));
+    c.utf8("bidid", res.iter().map(|res| res.bidid.as_deref()));
+    c.utf8("cur", res.iter().map(|res| res.cur.as_deref()));
+    c.code("nbr", res.iter().map(|res| res.nbr));
+    c.json("ext", res.iter().map(|res| res.ext.as_ref()))?;
+    Ok(c)
+}
+
+fn bid_columns(bids: &[Option<(Option<&str>, &crate::Bid)>]) -> Result<Columns, ArrowError> {
+    fn bid<'r>(bid: &Option<(Option<&str>, &'r crate::Bid<'r>)>) -> Option<&'r crate::Bid<'r>> {
+        bid.map(|(_, bid)| bid)
+    }
+
+    let mut c = Columns::default();
+    c.utf8("seat", bids.iter().map(|bid| bid.and_then(|(seat, _)| seat)));
+    c.utf8("id", bids.iter().map(|b| Some(bid(b)?.id.as_ref())));
+    c.utf8("impid", bids.iter().map(|b| Some(bid(b)?.impid.as_ref())));
+    c.float64("price", bids.iter().map(|b| Some(bid(b)?.price)));
+    c.utf8("adid", bids.iter().map(|b| bid(b)?.adid.as_deref()));
+    c.utf8("crid", bids.iter().map(|b| bid(b)?.crid.as_deref()));
+    c.utf8("cid", bids.iter().map(|b| bid(b)?.cid.as_deref()));
+    c.utf8("dealid", bids.iter().map(|b| bid(b)?.dealid.as_deref()));
+    c.utf8("nurl", bids.iter().map(|b| bid(b)?.nurl.as_deref()));
+    c.utf8("burl", bids.iter().map(|b| bid(b)?.burl.as_deref()));
+    c.utf8("lurl", bids.iter().map(|b| bid(b)?.lurl.as_deref()));
+    c.utf8_list("adomain", bids.iter().map(|b| bid(b)?.adomain.as_deref()));
+    c.utf8_list("cat", bids.iter().map(|b| bid(b)?.cat.as_deref()));
+    c.int32("w", bids.iter().map(|b| bid(b)?.w));
+    c.int32("h", bids.iter().map(|b| bid(b)?.h));
+    c.json("ext", bids.iter().map(|b| bid(b)?.ext.as_ref()))?;
+    Ok(c)
+}
+
+/// Columns of a record batch under construction.
+#[derive(Default)]
+struct Columns {
+    fields: Vec<Field>,
+    arrays: Vec<ArrayRef>,
+}
+
+impl Columns {
+    fn push(&mut self, name: &str, array: ArrayRef, nullable: bool) {
+        self.fields.push(Field::new(name, array.data_type().clone(), nullable));
+        self.arrays.push(array);
+    }
+
+    fn required_utf8<'s>(&mut self, name: &str, values: impl Iterator<Item = &'s str>) {
+        self.push(name, Arc::new(values.map(Some).collect::<StringArray>()), false);
+    }
+
+    fn utf8<'s>(&mut self, name: &str, values: impl Iterator<Item = Option<&'s str>>) {
+        self.push(name, Arc::new(values.collect::<StringArray>()), true);
+    }
+
+    fn int32(&mut self, name: &str, values: impl Iterator<Item = Option<i32>>) {
+        self.push(name, Arc::new(values.collect::<Int32Array>()), true);
+    }
+
+    fn float64(&mut self, name: &str, values: impl Iterator<Item = Option<f64>>) {
+        self.push(name, Arc::new(values.collect::<Float64Array>()), true);
+    }
+
+    fn flag(&mut self, name: &str, values: impl Iterator<Item = Option<json_ext::Flag>>) {
+        let values = values.map(|flag| flag.map(|flag| crate::ext::is_set(Some(flag))));
+        self.push(name, Arc::new(values.collect::<BooleanArray>()), true);
+    }
+
+    /// Pushes the integer value of an enum and its name.
+    fn code<E: Named>(&mut self, name: &str, values: impl Iterator<Item = Option<E>>) {
+        let (codes, names): (Vec<_>, Vec<_>) = values
+            .map(|value| match value {
+                Some(value) => {
+                    let code = serde_json::to_value(&value).ok().and_then(|code| code.as_i64());
+                    (code.and_then(|code| i32::try_from(code).ok()), Some(value.name()))
+                }
+                None => (None, None),
+            })
+            .unzip();
+        self.int32(name, codes.into_iter());
+        self.utf8(&format!("{}_name", name), names.into_iter());
+    }
+
+    fn utf8_list<I>(&mut self, name: &str, values: impl Iterator<Item = Option<I>>)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        for value in values {
+            match value {
+                Some(items) => {
+                    for item in items {
+                        builder.values().append_value(item);
+                    }
+                    builder.append(true);
+                }
+                None => builder.append(false),
+            }
+        }
+        self.push(name, Arc::new(builder.finish()), true);
+    }
+
+    fn json<'o>(
+        &mut self,
+        name: &str,
+        values: impl Iterator<Item = Option<&'o json_ext::Object<'o>>>,
+    ) -> Result<(), ArrowError> {
+        let values = values
+            .map(|ext| ext.map(serde_json::to_string).transpose())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| ArrowError::ExternalError(Box::new(err)))?;
+        self.utf8(name, values.iter().map(Option::as_deref));
+        Ok(())
+    }
+
+    /// Pushes child columns as a list of structs, `lengths` giving the number of elements per row.
+    fn list(&mut self, name: &str, child: Columns, lengths: impl Iterator<Item = usize>) -> Result<(), ArrowError> {
+        let fields = Fields::from(child.fields);
+        let values = StructArray::try_new(fields.clone(), child.arrays, None)?;
+        let item = Arc::new(Field::new("item", DataType::Struct(fields), true));
+        let list = ListArray::try_new(item, OffsetBuffer::from_lengths(lengths), Arc::new(values), None)?;
+        self.push(name, Arc::new(list), true);
+        Ok(())
+    }
+
+    /// Pushes child columns with prefixed names.
+    fn prefixed(&mut self, prefix: &str, child: Columns) {
+        for (field, array) in child.fields.into_iter().zip(child.arrays) {
+            self.push(&format!("{}{}", prefix, field.name()), array, true);
+        }
+    }
+
+    fn finish(self) -> Result<RecordBatch, ArrowError> {
+        RecordBatch::try_new(Arc::new(Schema::new(self.fields)), self.arrays)
+    }
+}
+
+/// Enums written with a `_name` column.
+trait Named: serde::Serialize {
+    /// Returns the name of the value written in the `_name` column.
+    fn name(&self) -> &'static str;
+}
+
+macro_rules! impl_named {
+    ($($ty:ident { $($variant:pat => $name:literal),* $(,)? })*) => {
+        $(
+            impl Named for crate::$ty {
+                #[allow(deprecated)]
+                fn name(&self) -> &'static str {
+                    use crate::$ty::*;
+                    match self {
+                        $($variant => $name),*
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_named! {
+    AuctionType {
+        FirstPrice => "FirstPrice",
+        SecondPricePlus => "SecondPricePlus",
+        ExchangeSpecific(_) => "ExchangeSpecific",
+    }
+    DeviceType {
+        Mobile => "Mobile",
+        PersonalComputer => "PersonalComputer",
+        ConnectedTv => "ConnectedTv",
+        Phone => "Phone",
+        Tablet => "Tablet",
+        ConnectedDevice => "ConnectedDevice",
+        SetTopBox => "SetTopBox",
+    }
+    ConnectionType {
+        Unknown => "Unknown",
+        Ethernet => "Ethernet",
+        WiFi => "WiFi",
+        CellUnknown => "CellUnknown",
+        Cell2G => "Cell2G",
+        Cell3G => "Cell3G",
+        Cell4G => "Cell4G",
+    }
+    AdPosition {
+        Unknown => "Unknown",
+        AboveTheFold => "AboveTheFold",
+        LikelyBelowTheFold => "LikelyBelowTheFold",
+        BelowTheFold => "BelowTheFold",
+        Header => "Header",
+        Footer => "Footer",
+        Sidebar => "Sidebar",
+        FullScreen => "FullScreen",
+    }
+    VideoPlacementType {
+        InStream => "InStream",
+        InBanner => "InBanner",
+        InArticle => "InArticle",
+        InFeed => "InFeed",
+        Floating => "Floating",
+    }
+    NoBidReason {
+        UnknownError => "UnknownError",
+        TechnicalError => "TechnicalError",
+        InvalidRequest => "InvalidRequest",
+        KnownWebSpider => "KnownWebSpider",
+        SuspectedNonHumanTraffic => "SuspectedNonHumanTraffic",
+        CloudDataCenterProxyIp => "CloudDataCenterProxyIp",
+        UnsupportedDevice => "UnsupportedDevice",
+        BlockedPublisher => "BlockedPublisher",
+        UnmatchedUser => "UnmatchedUser",
+        DailyReaderCap => "DailyReaderCap",
+        DailyDomainCap => "DailyDomainCap",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::arrow::array::{Array, AsArray};
+    use ::arrow::datatypes::Int32Type;
+
+    fn requests() -> Vec<crate::BidRequest<'static>> {
+        [
+            include_str!("../tests/json/6.3.1_simple_banner.json"),
+            include_str!("../tests/json/6.3.4_video.json"),
+            include_str!("../tests/json/6.3.5_pmp_with_direct_deal.json"),
+            r#"{"id":"empty","imp":[]}"#,
+        ]
+        .into_iter()
+        .map(|json| serde_json::from_str(json).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn request() -> Result<(), ArrowError> {
+        let mut reqs = requests();
+        reqs[0].imp.push(crate::Imp {
+            id: "2".into(),
+            ..Default::default()
+        });
+
+        let nested = super::requests(&reqs, Layout::Nested)?;
+        assert_eq!(nested.schema(), request_schema(Layout::Nested));
+        assert_eq!(nested.num_rows(), 4);
+        let imp = nested.column_by_name("imp").unwrap().as_list::<i32>();
+        assert_eq!(imp.value_length(0), 2);
+        assert_eq!(imp.value_length(3), 0);
+        let imp = imp.values().as_struct();
+        let w = imp.column_by_name("banner_w").unwrap().as_primitive::<Int32Type>();
+        assert_eq!((w.value(0), w.is_null(1)), (300, true));
+
+        let exploded = super::requests(&reqs, Layout::Exploded)?;
+        assert_eq!(exploded.schema(), request_schema(Layout::Exploded));
+        assert_eq!(exploded.num_rows(), 5);
+        let id = exploded.column_by_name("id").unwrap().as_string::<i32>();
+        assert_eq!(id.value(0), id.value(1));
+        let imp_id = exploded.column_by_name("imp_id").unwrap().as_string::<i32>();
+        assert_eq!((imp_id.value(1), imp_id.is_null(4)), ("2", true));
+        let placement = exploded
+            .column_by_name("imp_video_placement_name")
+            .unwrap()
+            .as_string::<i32>();
+        assert!(placement.is_null(0));
+        let deals = exploded.column_by_name("imp_deal_ids").unwrap().as_list::<i32>();
+        assert_eq!(deals.value_length(3), 2);
+        let at = exploded.column_by_name("at_name").unwrap().as_string::<i32>();
+        assert_eq!(at.value(3), "FirstPrice");
+        let ext = exploded.column_by_name("ext").unwrap();
+        assert!(ext.is_null(4));
+
+        Ok(())
+    }
+
+    #[test]
+    fn response() -> Result<(), ArrowError> {
+        let res: Vec<crate::BidResponse> = [
+            include_str!("../tests/json/6.4.1_ad_served_on_win_notice.json"),
+            include_str!("../tests/json/6.4.3_direct_deal_ad_served_on_win_notice.json"),
+            r#"{"id":"nobid","nbr":2}"#,
+        ]
+        .into_iter()
+        .map(|json| serde_json::from_str(json).unwrap())
+        .collect();
+
+        let nested = responses(&res, Layout::Nested)?;
+        assert_eq!(nested.schema(), response_schema(Layout::Nested));
+        assert_eq!(nested.num_rows(), 3);
+
+        let exploded = responses(&res, Layout::Exploded)?;
+        assert_eq!(exploded.num_rows(), 3);
+        let nbr = exploded.column_by_name("nbr_name").unwrap().as_string::<i32>();
+        assert_eq!(nbr.value(2), "InvalidRequest");
+        let seat = exploded.column_by_name("bid_seat").unwrap().as_string::<i32>();
+        assert_eq!(seat.value(1), "512");
+        let dealid = exploded.column_by_name("bid_dealid").unwrap().as_string::<i32>();
+        assert_eq!(dealid.value(1), "ABC-1234-6789");
+
+        Ok(())
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(crate::AuctionType::ExchangeSpecific(501).name(), "ExchangeSpecific");
+        assert_eq!(crate::DeviceType::ConnectedTv.name(), "ConnectedTv");
+        assert_eq!(crate::ConnectionType::Cell4G.name(), "Cell4G");
+        assert_eq!(crate::AdPosition::AboveTheFold.name(), "AboveTheFold");
+        assert_eq!(crate::VideoPlacementType::InFeed.name(), "InFeed");
+        assert_eq!(
+            crate::NoBidReason::CloudDataCenterProxyIp.name(),
+            "CloudDataCenterProxyIp"
+        );
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn parquet() -> Result<(), Box<dyn std::error::Error>> {
+        let batch = super::requests(&requests(), Layout::Nested)?;
+        let path = std::env::temp_dir().join(format!("openrtb2-{}.parquet", std::process::id()));
+        write_parquet(std::fs::File::create(&path)?, batch.schema(), [&batch])?;
+
+        let file = std::fs::File::open(&path)?;
+        let reader = parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+        let batches = reader.collect::<Result<Vec<_>, _>>()?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(batches, [batch]);
+
+        Ok(())
+    }
+}
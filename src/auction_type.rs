@@ -42,6 +42,32 @@ impl<'de> serde::Deserialize<'de> for AuctionType {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AuctionType {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => Self::FirstPrice,
+            1 => Self::SecondPricePlus,
+            _ => Self::ExchangeSpecific(u.int_in_range(501..=i32::MAX)?),
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for AuctionType {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "AuctionType".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Auction type, where 1 = First Price, 2 = Second Price Plus. Exchange-specific auction types can be defined using values greater than 500.",
+            "type": "integer",
+            "anyOf": [{ "enum": [1, 2] }, { "minimum": 501 }]
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -11,6 +11,8 @@
 /// also be offered as banner, video, and/or native by also including as Imp subordinates objects of
 /// those types. However, any given bid for the impression must conform to one of the offered types.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Audio<'a> {
     /// string array; required
     /// Content MIME types supported (e.g., “audio/mp4”).
@@ -104,6 +106,8 @@ pub struct Audio<'a> {
     /// Indicates if the ad is stitched with audio content or delivered independently, where 0 =
     /// no, 1 = yes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub stitched: Option<json_ext::Flag>,
 
     /// integer
@@ -114,6 +118,8 @@ pub struct Audio<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
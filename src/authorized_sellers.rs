@@ -0,0 +1,262 @@
+//! Authorized sellers verification.
+//!
+//! An [`Index`] holds the ads.txt and app-ads.txt files of publishers and the sellers.json
+//! documents of advertising systems. Given a bid request, it reports for every hop of the supply
+//! chain whether the publisher authorized the seller, so bidders can drop unauthorized inventory.
+//!
+//! ```
+//! # use openrtb2::ads_txt::{AdsTxt, Relationship};
+//! # use openrtb2::authorized_sellers::{Index, Status};
+//! let mut index = Index::new();
+//! index.insert_ads_txt("example.com", AdsTxt::parse("exchange1.com, 1234, DIRECT"));
+//!
+//! let req: openrtb2::BidRequest = serde_json::from_str(
+//!     r#"{"id":"1","imp":[],"site":{"domain":"news.example.com","publisher":{"id":"1234"}}}"#,
+//! )?;
+//! let report = index.report(&req, "exchange1.com");
+//! assert_eq!(report.hops[0].status, Status::Authorized(Relationship::Direct));
+//! assert!(report.is_authorized());
+//! assert!(!index.report(&req, "exchange2.com").is_authorized());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`Index`]: ./struct.Index.html
+
+use crate::ads_txt::{AdsTxt, Relationship};
+use crate::sellers_json::{SellerType, SellersJson};
+
+/// ads.txt files and sellers.json documents indexed by domain.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Index {
+    ads_txt: std::collections::HashMap<String, AdsTxt>,
+    sellers_json: std::collections::HashMap<String, SellersJson<'static>>,
+}
+
+impl Index {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the ads.txt file of a site domain, or the app-ads.txt file of an app bundle.
+    pub fn insert_ads_txt(&mut self, inventory: &str, ads_txt: AdsTxt) {
+        self.ads_txt.insert(inventory.to_ascii_lowercase(), ads_txt);
+    }
+
+    /// Adds the sellers.json document of advertising system `domain`.
+    pub fn insert_sellers_json(&mut self, domain: &str, sellers_json: SellersJson<'static>) {
+        self.sellers_json.insert(domain.to_ascii_lowercase(), sellers_json);
+    }
+
+    /// Returns the ads.txt file covering `inventory`. As ads.txt files apply to subdomains, the
+    /// parent domains are looked up if there is no file for `inventory` itself.
+    pub fn ads_txt(&self, inventory: &str) -> Option<&AdsTxt> {
+        let mut inventory = inventory.trim().to_ascii_lowercase();
+        loop {
+            if let Some(ads_txt) = self.ads_txt.get(&inventory) {
+                return Some(ads_txt);
+            }
+            match inventory.split_once('.') {
+                Some((_, parent)) if parent.contains('.') => inventory = parent.to_owned(),
+                _ => return None,
+            }
+        }
+    }
+
+    /// Returns the sellers.json document of advertising system `domain`.
+    pub fn sellers_json(&self, domain: &str) -> Option<&SellersJson<'static>> {
+        self.sellers_json.get(&domain.to_ascii_lowercase())
+    }
+
+    /// Checks the sellers of `req` received from advertising system `asi`.
+    ///
+    /// The hops are the nodes of the supply chain of the request if it has a valid one. Otherwise
+    /// `asi` is the only hop, selling the account `publisher.id`.
+    pub fn report(&self, req: &crate::BidRequest, asi: &str) -> Report {
+        let (inventory, publisher) = match req.channel {
+            Some(crate::DistributionChannel::Site(ref site)) => (site.domain.as_deref(), site.publisher.as_ref()),
+            Some(crate::DistributionChannel::App(ref app)) => (app.bundle.as_deref(), app.publisher.as_ref()),
+            None => (None, None),
+        };
+        // bundles are not domains, their app-ads.txt does not cover parents
+        let ads_txt = match req.channel {
+            Some(crate::DistributionChannel::App(_)) => {
+                inventory.and_then(|bundle| self.ads_txt.get(&bundle.trim().to_ascii_lowercase()))
+            }
+            _ => inventory.and_then(|domain| self.ads_txt(domain)),
+        };
+
+        let sellers: Vec<(String, String)> = match req.source.as_ref().and_then(crate::Source::supply_chain) {
+            Some(Ok(schain)) => schain
+                .nodes
+                .into_iter()
+                .map(|node| (node.asi.into_owned(), node.sid.into_owned()))
+                .collect(),
+            _ => publisher
+                .and_then(|publisher| publisher.id.as_deref())
+                .map(|id| vec![(asi.to_owned(), id.to_owned())])
+                .unwrap_or_default(),
+        };
+
+        let hops = sellers
+            .into_iter()
+            .map(|(asi, sid)| {
+                let status = match ads_txt {
+                    Some(ads_txt) => match ads_txt.relationship(&asi, &sid) {
+                        Some(relationship) => Status::Authorized(relationship),
+                        None => Status::Unauthorized,
+                    },
+                    None => Status::Unknown,
+                };
+                let seller = self
+                    .sellers_json(&asi)
+                    .map(|sellers_json| sellers_json.seller(&sid).map(|seller| seller.seller_type));
+                Hop {
+                    asi,
+                    sid,
+                    status,
+                    seller_type: seller.flatten(),
+                    in_sellers_json: seller.map(|seller_type| seller_type.is_some()),
+                }
+            })
+            .collect();
+
+        Report {
+            inventory: inventory.map(ToOwned::to_owned),
+            hops,
+        }
+    }
+}
+
+/// Whether the publisher authorized a seller in its ads.txt file.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Status {
+    /// The seller is listed with this relationship.
+    Authorized(Relationship),
+    /// The seller is not listed.
+    Unauthorized,
+    /// The ads.txt file of the publisher is not in the index.
+    Unknown,
+}
+
+/// The verification of one seller of a bid request.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hop {
+    /// Domain of the advertising system.
+    pub asi: String,
+    /// Seller account in the advertising system.
+    pub sid: String,
+    /// Authorization of the account by the publisher.
+    pub status: Status,
+    /// Type of the account according to the sellers.json of the advertising system.
+    pub seller_type: Option<SellerType>,
+    /// Whether the sellers.json of the advertising system lists the account, None if the index has
+    /// no sellers.json for the advertising system.
+    pub in_sellers_json: Option<bool>,
+}
+
+/// The verification of every seller of a bid request.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Report {
+    /// Site domain or app bundle of the request.
+    pub inventory: Option<String>,
+    /// Sellers in supply chain order.
+    pub hops: Vec<Hop>,
+}
+
+impl Report {
+    /// Returns true if there is at least one hop and every hop is authorized in the ads.txt file
+    /// and not missing from a known sellers.json.
+    pub fn is_authorized(&self) -> bool {
+        !self.hops.is_empty() && self.unauthorized().next().is_none()
+    }
+
+    /// Returns the hops which are not authorized or missing from a known sellers.json.
+    pub fn unauthorized(&self) -> impl Iterator<Item = &Hop> {
+        self.hops
+            .iter()
+            .filter(|hop| !matches!(hop.status, Status::Authorized(_)) || hop.in_sellers_json == Some(false))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn index() -> serde_json::Result<Index> {
+        let mut index = Index::new();
+        index.insert_ads_txt(
+            "com.example.app",
+            AdsTxt::parse("exchange1.com, pub-1, DIRECT\nexchange2.com, 42, RESELLER\n"),
+        );
+        index.insert_sellers_json(
+            "Exchange2.com",
+            serde_json::from_str(
+                r#"{"version":"1.0","sellers":[{"seller_id":"42","seller_type":"INTERMEDIARY","name":"Exchange 1"}]}"#,
+            )?,
+        );
+        Ok(index)
+    }
+
+    #[test]
+    fn supply_chain() -> serde_json::Result<()> {
+        let index = index()?;
+        let req: crate::BidRequest = serde_json::from_str(
+            r#"{
+                "id": "1",
+                "imp": [],
+                "app": {"bundle": "com.example.app", "publisher": {"id": "ignored"}},
+                "source": {"ext": {"schain": {"complete": 1, "ver": "1.0", "nodes": [
+                    {"asi": "exchange1.com", "sid": "pub-1", "hp": 1},
+                    {"asi": "exchange2.com", "sid": "42", "hp": 1}
+                ]}}}
+            }"#,
+        )?;
+        let report = index.report(&req, "exchange2.com");
+        assert_eq!(report.inventory.as_deref(), Some("com.example.app"));
+        assert_eq!(
+            report.hops,
+            vec![
+                Hop {
+                    asi: "exchange1.com".into(),
+                    sid: "pub-1".into(),
+                    status: Status::Authorized(Relationship::Direct),
+                    seller_type: None,
+                    in_sellers_json: None,
+                },
+                Hop {
+                    asi: "exchange2.com".into(),
+                    sid: "42".into(),
+                    status: Status::Authorized(Relationship::Reseller),
+                    seller_type: Some(SellerType::Intermediary),
+                    in_sellers_json: Some(true),
+                },
+            ]
+        );
+        assert!(report.is_authorized());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unauthorized() -> serde_json::Result<()> {
+        let index = index()?;
+        let req: crate::BidRequest =
+            serde_json::from_str(r#"{"id":"1","imp":[],"app":{"bundle":"com.example.app","publisher":{"id":"43"}}}"#)?;
+        let report = index.report(&req, "exchange2.com");
+        assert_eq!(report.hops[0].status, Status::Unauthorized);
+        assert_eq!(report.hops[0].in_sellers_json, Some(false));
+        assert_eq!(report.unauthorized().count(), 1);
+        assert!(!report.is_authorized());
+
+        let req: crate::BidRequest =
+            serde_json::from_str(r#"{"id":"1","imp":[],"site":{"domain":"other.com","publisher":{"id":"1"}}}"#)?;
+        assert_eq!(index.report(&req, "exchange1.com").hops[0].status, Status::Unknown);
+
+        assert!(!index
+            .report(&crate::BidRequest::default(), "exchange1.com")
+            .is_authorized());
+
+        Ok(())
+    }
+}
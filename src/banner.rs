@@ -12,6 +12,8 @@
 /// also be offered as video, audio, and/or native by also including as Imp subordinates objects of
 /// those types. However, any given bid for the impression must conform to one of the offered types.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Banner<'a> {
     /// object array; recommended
     /// Array of format objects (Section 3.2.10) representing the banner sizes permitted. If none
@@ -79,6 +81,8 @@ pub struct Banner<'a> {
     /// integer
     /// Indicates if the banner is in the top frame as opposed to an iframe, where 0 = no, 1 = yes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub topframe: Option<json_ext::Flag>,
 
     /// integer array
@@ -109,10 +113,14 @@ pub struct Banner<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum VideoCompanionMode {
     Concurrent,
@@ -3,6 +3,8 @@
 /// The following table indicates the types of ads that can be accepted by the exchange unless
 /// restricted by publisher site settings.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum BannerAdType {
     /// XHTML Text Ad (usually mobile)
@@ -4,6 +4,8 @@
 /// impression in the bid request via the impid attribute and constitutes an offer to buy that
 /// impression for a given price.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Bid<'a> {
     /// string; required
     /// Bidder generated bid ID to assist with logging/tracking.
@@ -19,6 +21,7 @@ pub struct Bid<'a> {
     /// Bid price expressed as CPM although the actual transaction is for a unit impression only.
     /// Note that while the type indicates float, integer math is highly recommended when handling
     /// currencies (e.g., BigDecimal in Java).
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::price))]
     pub price: f64,
 
     /// string
@@ -96,6 +99,12 @@ pub struct Bid<'a> {
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub cat: Option<Vec<std::borrow::Cow<'a, str>>>,
 
+    /// integer; default 1
+    /// The taxonomy in use. If no cattax field is supplied IAB Content Category Taxonomy 1.0 is
+    /// assumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
     /// integer array
     /// Set of attributes describing the creative. Refer to List 5.3.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -157,6 +166,8 @@ pub struct Bid<'a> {
     /// object
     /// Placeholder for bidder-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
@@ -11,6 +11,8 @@
 /// request depending on whether the media is browser-based web content or a non-browser
 /// application, respectively.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BidRequest<'a> {
     /// string; required
     /// Unique ID of the bid request, provided by the exchange.
@@ -21,6 +23,7 @@ pub struct BidRequest<'a> {
     /// Array of Imp objects (Section 3.2.4) representing the impressions offered. At least 1 Imp
     /// object is required.
     #[serde(borrow)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::non_empty))]
     pub imp: Vec<crate::Imp<'a>>,
 
     /// object; recommended
@@ -43,6 +46,8 @@ pub struct BidRequest<'a> {
     /// Indicator of test mode in which auctions are not billable, where 0 = live mode, 1 = test
     /// mode.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub test: Option<json_ext::Flag>,
 
     /// integer; default 2
@@ -79,6 +84,8 @@ pub struct BidRequest<'a> {
     /// pre/mid/post roll) to support road-blocking. 0 = no or unknown, 1 = yes, the impressions
     /// offered represent all that are available.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub allimps: Option<json_ext::Flag>,
 
     /// string array
@@ -99,6 +106,11 @@ pub struct BidRequest<'a> {
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub bcat: Option<Vec<std::borrow::Cow<'a, str>>>,
 
+    /// integer; default 1
+    /// The taxonomy in use for bcat.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
     /// string array
     /// Block list of advertisers by their domains (e.g., "ford.com").
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
@@ -126,6 +138,8 @@ pub struct BidRequest<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
@@ -10,6 +10,8 @@
 /// the bidder wishes to convey to the exchange a reason for not bidding, just a BidResponse object
 /// is returned with a reason code in the nbr attribute.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct BidResponse<'a> {
     /// string; required
     /// ID of the bid request to which this is a response.
@@ -46,6 +48,8 @@ pub struct BidResponse<'a> {
     /// object
     /// Placeholder for bidder-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
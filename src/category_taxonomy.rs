@@ -0,0 +1,135 @@
+/// [`BidRequest#cattax`], [`Site#cattax`], [`App#cattax`], [`Publisher#cattax`],
+/// [`Content#cattax`], [`Producer#cattax`], [`Bid#cattax`]
+///
+/// The taxonomy of category codes, from the Category Taxonomies list of AdCOM 1.0. Vendor-specific
+/// taxonomies can be defined using values greater than or equal to 500.
+///
+/// [`BidRequest#cattax`]: ./struct.BidRequest.html#structfield.cattax
+/// [`Site#cattax`]: ./struct.Site.html#structfield.cattax
+/// [`App#cattax`]: ./struct.App.html#structfield.cattax
+/// [`Publisher#cattax`]: ./struct.Publisher.html#structfield.cattax
+/// [`Content#cattax`]: ./struct.Content.html#structfield.cattax
+/// [`Producer#cattax`]: ./struct.Producer.html#structfield.cattax
+/// [`Bid#cattax`]: ./struct.Bid.html#structfield.cattax
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum CategoryTaxonomy {
+    /// IAB Content Category Taxonomy 1.0, assumed when cattax is omitted.
+    #[default]
+    IabContent1_0,
+    /// IAB Content Category Taxonomy 2.0
+    IabContent2_0,
+    /// IAB Ad Product Taxonomy 1.0
+    IabAdProduct1_0,
+    /// IAB Audience Taxonomy 1.1
+    IabAudience1_1,
+    /// IAB Content Taxonomy 2.1
+    IabContent2_1,
+    /// IAB Content Taxonomy 2.2
+    IabContent2_2,
+    /// IAB Content Taxonomy 3.0
+    IabContent3_0,
+    /// IAB Ad Product Taxonomy 2.0
+    IabAdProduct2_0,
+    /// Vendor-specific taxonomy
+    VendorSpecific(i32),
+}
+
+impl serde::Serialize for CategoryTaxonomy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_i32(match self {
+            Self::IabContent1_0 => 1,
+            Self::IabContent2_0 => 2,
+            Self::IabAdProduct1_0 => 3,
+            Self::IabAudience1_1 => 4,
+            Self::IabContent2_1 => 5,
+            Self::IabContent2_2 => 6,
+            Self::IabContent3_0 => 7,
+            Self::IabAdProduct2_0 => 8,
+            Self::VendorSpecific(value) => *value,
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CategoryTaxonomy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match i32::deserialize(deserializer)? {
+            1 => Ok(Self::IabContent1_0),
+            2 => Ok(Self::IabContent2_0),
+            3 => Ok(Self::IabAdProduct1_0),
+            4 => Ok(Self::IabAudience1_1),
+            5 => Ok(Self::IabContent2_1),
+            6 => Ok(Self::IabContent2_2),
+            7 => Ok(Self::IabContent3_0),
+            8 => Ok(Self::IabAdProduct2_0),
+            value if value >= 500 => Ok(Self::VendorSpecific(value)),
+            value => Err(serde::de::Error::custom(format!(
+                "invalid value: {}, expected 1 to 8 or greater than or equal to 500",
+                value
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CategoryTaxonomy {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=8u8)? {
+            0 => Self::IabContent1_0,
+            1 => Self::IabContent2_0,
+            2 => Self::IabAdProduct1_0,
+            3 => Self::IabAudience1_1,
+            4 => Self::IabContent2_1,
+            5 => Self::IabContent2_2,
+            6 => Self::IabContent3_0,
+            7 => Self::IabAdProduct2_0,
+            _ => Self::VendorSpecific(u.int_in_range(500..=i32::MAX)?),
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CategoryTaxonomy {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "CategoryTaxonomy".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "The taxonomy of category codes, from the Category Taxonomies list of AdCOM 1.0. Vendor-specific taxonomies can be defined using values greater than or equal to 500.",
+            "type": "integer",
+            "anyOf": [{ "minimum": 1, "maximum": 8 }, { "minimum": 500 }]
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        assert!(serde_json::from_str::<CategoryTaxonomy>("0").is_err());
+        assert!(serde_json::from_str::<CategoryTaxonomy>("9").is_err());
+
+        let json = "[1,7,8,500]";
+        let e1: Vec<CategoryTaxonomy> = serde_json::from_str(json)?;
+        assert_eq!(serde_json::to_string(&e1)?, json);
+        assert_eq!(
+            e1,
+            vec![
+                CategoryTaxonomy::IabContent1_0,
+                CategoryTaxonomy::IabContent3_0,
+                CategoryTaxonomy::IabAdProduct2_0,
+                CategoryTaxonomy::VendorSpecific(500)
+            ]
+        );
+
+        Ok(())
+    }
+}
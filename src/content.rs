@@ -7,6 +7,8 @@
 /// syndication method. For example might be a video impression embedded in an iframe on an unknown
 /// web property or device.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Content<'a> {
     /// string
     /// ID uniquely identifying the content.
@@ -75,6 +77,12 @@ pub struct Content<'a> {
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub cat: Option<Vec<std::borrow::Cow<'a, str>>>,
 
+    /// integer; default 1
+    /// The taxonomy in use. If no cattax field is supplied IAB Content Category Taxonomy 1.0 is
+    /// assumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
     /// integer
     /// Production quality. Refer to List 5.13.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -114,6 +122,8 @@ pub struct Content<'a> {
     /// integer
     /// 0 = not live, 1 = content is live (e.g., stream, live blog).
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub livestream: Option<json_ext::Flag>,
 
     /// integer
@@ -135,6 +145,8 @@ pub struct Content<'a> {
     /// Indicator of whether or not the content is embeddable (e.g., an embeddable video player),
     /// where 0 = no, 1 = yes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub embeddable: Option<json_ext::Flag>,
 
     /// object array
@@ -146,10 +158,14 @@ pub struct Content<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum SourceRelationship {
     Indirect,
@@ -5,6 +5,8 @@
 /// from the Inventory Quality Guidelines (IQG). Practitioners should keep in sync with updates to
 /// the IQG values.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum ContentContext {
     /// Video (i.e., video file or stream such as Internet TV broadcasts)
@@ -4,6 +4,8 @@
 /// being served or serve as restrictions of thereof.
 #[allow(non_camel_case_types)]
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum CreativeAttribute {
     /// Audio Ad (Auto-Play)
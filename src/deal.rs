@@ -4,6 +4,8 @@
 /// Its presence with the Pmp collection indicates that this impression is available under the terms
 /// of that deal. Refer to Section 7.3 for more details.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Deal<'a> {
     /// string; required
     /// A unique identifier for the direct deal.
@@ -13,6 +15,7 @@ pub struct Deal<'a> {
     /// float; default 0
     /// Minimum bid for this impression expressed in CPM.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_price))]
     pub bidfloor: Option<f64>,
 
     /// string; default ”USD”
@@ -44,6 +47,8 @@ pub struct Deal<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
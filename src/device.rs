@@ -4,6 +4,8 @@
 /// Device information includes its hardware, platform, location, and carrier data. The device can
 /// refer to a mobile handset, a desktop computer, set top box, or other digital device.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Device<'a> {
     /// string; recommended
     /// Browser user agent string.
@@ -20,12 +22,16 @@ pub struct Device<'a> {
     /// Standard “Do Not Track” flag as set in the header by the browser, where 0 = tracking is
     /// unrestricted, 1 = do not track.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub dnt: Option<json_ext::Flag>,
 
     /// integer; recommended
     /// “Limit Ad Tracking” signal commercially endorsed (e.g., iOS, Android), where 0 = tracking
     /// is unrestricted, 1 = tracking must be limited per commercial guidelines.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub lmt: Option<json_ext::Flag>,
 
     /// string; recommended
@@ -91,12 +97,16 @@ pub struct Device<'a> {
     /// integer
     /// Support for JavaScript, where 0 = no, 1 = yes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub js: Option<json_ext::Flag>,
 
     /// integer
     /// Indicates if the geolocation API will be available to JavaScript code running in the
     /// banner, where 0 = no, 1 = yes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub geofetch: Option<json_ext::Flag>,
 
     /// string
@@ -166,9 +176,105 @@ pub struct Device<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
+impl Device<'_> {
+    /// Returns `ip` parsed as an IPv4 address. IPv4-mapped IPv6 addresses are converted.
+    ///
+    /// ```
+    /// # use std::net::Ipv4Addr;
+    /// let device = openrtb2::Device { ip: Some("::ffff:1.2.3.4".into()), ..Default::default() };
+    /// assert_eq!(device.ipv4_addr(), Some(Ipv4Addr::new(1, 2, 3, 4)));
+    /// ```
+    pub fn ipv4_addr(&self) -> Option<std::net::Ipv4Addr> {
+        match crate::ip::parse(self.ip.as_deref()?)? {
+            std::net::IpAddr::V4(ip) => Some(ip),
+            std::net::IpAddr::V6(ip) => ip.to_ipv4_mapped(),
+        }
+    }
+
+    /// Returns `ipv6` parsed as an IPv6 address.
+    pub fn ipv6_addr(&self) -> Option<std::net::Ipv6Addr> {
+        match crate::ip::parse(self.ipv6.as_deref()?)? {
+            std::net::IpAddr::V6(ip) => Some(ip),
+            std::net::IpAddr::V4(_) => None,
+        }
+    }
+
+    /// Returns true if `ip` holds an IPv6 address or `ipv6` an IPv4 address.
+    pub fn has_misplaced_ip(&self) -> bool {
+        self.v6_in_ip() || self.v4_in_ipv6()
+    }
+
+    fn v6_in_ip(&self) -> bool {
+        self.ip.as_deref().and_then(crate::ip::parse).is_some() && self.ipv4_addr().is_none()
+    }
+
+    fn v4_in_ipv6(&self) -> bool {
+        matches!(
+            self.ipv6.as_deref().and_then(crate::ip::parse),
+            Some(std::net::IpAddr::V4(_))
+        )
+    }
+
+    /// Moves an IPv6 address sent in `ip` to `ipv6` and an IPv4 address sent in `ipv6` to `ip`.
+    /// A misplaced address is dropped if the other field is already set and not misplaced itself;
+    /// correctly placed and unparsable values are left untouched. Returns true if a field changed.
+    ///
+    /// ```
+    /// let mut device = openrtb2::Device { ip: Some("2001:db8::1".into()), ..Default::default() };
+    /// assert!(device.has_misplaced_ip());
+    /// assert!(device.fix_misplaced_ips());
+    /// assert_eq!((device.ip, device.ipv6.as_deref()), (None, Some("2001:db8::1")));
+    /// ```
+    pub fn fix_misplaced_ips(&mut self) -> bool {
+        match (self.v6_in_ip(), self.v4_in_ipv6()) {
+            (false, false) => return false,
+            (true, true) => std::mem::swap(&mut self.ip, &mut self.ipv6),
+            (true, false) => {
+                let ip = self.ip.take();
+                if self.ipv6.is_none() {
+                    self.ipv6 = ip;
+                }
+            }
+            (false, true) => {
+                let ipv6 = self.ipv6.take();
+                if self.ip.is_none() {
+                    self.ip = ipv6;
+                }
+            }
+        }
+        true
+    }
+
+    /// Anonymizes `ip` and `ipv6` in place: the last octet of the IPv4 address is zeroed and the
+    /// IPv6 address is truncated to `prefix`. Values which are not addresses of the expected
+    /// family are removed.
+    ///
+    /// ```
+    /// # use openrtb2::ip::Ipv6Prefix;
+    /// let mut device = openrtb2::Device {
+    ///     ip: Some("192.168.1.23".into()),
+    ///     ipv6: Some("2001:db8:85a3:8d3:1319:8a2e:370:7348".into()),
+    ///     ..Default::default()
+    /// };
+    /// device.anonymize_ips(Ipv6Prefix::Bits64);
+    /// assert_eq!(device.ip.as_deref(), Some("192.168.1.0"));
+    /// assert_eq!(device.ipv6.as_deref(), Some("2001:db8:85a3:8d3::"));
+    /// ```
+    pub fn anonymize_ips(&mut self, prefix: crate::ip::Ipv6Prefix) {
+        self.ip = self
+            .ipv4_addr()
+            .map(|ip| crate::ip::truncate_ipv4(ip).to_string().into());
+        self.ipv6 = self
+            .ipv6_addr()
+            .map(|ip| crate::ip::truncate_ipv6(ip, prefix).to_string().into());
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -182,4 +288,38 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn ips() -> serde_json::Result<()> {
+        let mut o1: Device = serde_json::from_str(r#"{"ip":" 2001:db8::1","ipv6":"10.0.0.1"}"#)?;
+        assert_eq!(o1.ipv4_addr(), None);
+        assert_eq!(o1.ipv6_addr(), None);
+        assert!(o1.has_misplaced_ip());
+        assert!(o1.fix_misplaced_ips());
+        assert_eq!(
+            serde_json::to_string(&o1)?,
+            r#"{"ip":"10.0.0.1","ipv6":" 2001:db8::1"}"#
+        );
+        assert!(!o1.has_misplaced_ip());
+        assert!(!o1.fix_misplaced_ips());
+
+        let mut o1: Device = serde_json::from_str(r#"{"ip":"2001:db8::1","ipv6":"2001:db8::2"}"#)?;
+        assert!(o1.fix_misplaced_ips());
+        assert_eq!(serde_json::to_string(&o1)?, r#"{"ipv6":"2001:db8::2"}"#);
+
+        let mut o1: Device = serde_json::from_str(r#"{"ip":"10.0.0.1","ipv6":"10.0.0.2"}"#)?;
+        assert!(o1.fix_misplaced_ips());
+        assert_eq!(serde_json::to_string(&o1)?, r#"{"ip":"10.0.0.1"}"#);
+
+        let mut o1: Device = serde_json::from_str(r#"{"ip":"unknown","ipv6":"10.0.0.1"}"#)?;
+        assert!(o1.fix_misplaced_ips());
+        assert_eq!(serde_json::to_string(&o1)?, r#"{"ip":"unknown"}"#);
+
+        let mut o1: Device = serde_json::from_str(r#"{"ip":"localhost","ipv6":"2001:db8::1"}"#)?;
+        assert!(!o1.has_misplaced_ip());
+        o1.anonymize_ips(crate::ip::Ipv6Prefix::Bits48);
+        assert_eq!(serde_json::to_string(&o1)?, r#"{"ipv6":"2001:db8::"}"#);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,245 @@
+//! Structural diff of bid requests and responses.
+//!
+//! [`requests`] and [`responses`] compare two objects through their JSON representation and list
+//! the [`Change`]s with the path of each field, e.g. `imp[1].banner.format[0].w: 300 -> 320` or
+//! `user.ext.consent removed`. [`values`] does the same for arbitrary JSON, e.g. logged payloads.
+//!
+//! Arrays of objects which all carry a distinct string `id`, such as `imp`, `pmp.deals` or
+//! `seatbid[0].bid`, are compared by `id` rather than by position, so a reordered or filtered list
+//! only reports the elements which actually differ. Matched and added elements are reported at
+//! their index in the new array, removed elements at their index in the old one.
+//!
+//! ```
+//! # use openrtb2::{diff, BidRequest};
+//! let old: BidRequest = serde_json::from_str(
+//!     r#"{"id":"1","imp":[{"id":"a","bidfloor":1},{"id":"b"}],"user":{"ext":{"consent":"x"}}}"#,
+//! )?;
+//! let new: BidRequest = serde_json::from_str(
+//!     r#"{"id":"1","imp":[{"id":"b"},{"id":"a","bidfloor":1.5}],"user":{}}"#,
+//! )?;
+//! assert_eq!(
+//!     diff::to_text(&diff::requests(&old, &new)),
+//!     "imp[1].bidfloor: 1.0 -> 1.5\nuser.ext removed\n",
+//! );
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`requests`]: ./fn.requests.html
+//! [`responses`]: ./fn.responses.html
+//! [`values`]: ./fn.values.html
+//! [`Change`]: ./struct.Change.html
+
+use serde_json::Value;
+
+/// A difference between two objects.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Change {
+    /// Path of the field, e.g. `imp[1].banner.format[0].w`.
+    pub path: String,
+    /// How the field differs.
+    pub kind: Kind,
+}
+
+/// The kind of a change.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Kind {
+    /// The field is only present in the new object.
+    Added(Value),
+    /// The field is only present in the old object.
+    Removed(Value),
+    /// The field has a different value in the new object.
+    Changed { old: Value, new: Value },
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            Kind::Added(value) => write!(f, "{} added: {}", self.path, value),
+            Kind::Removed(_) => write!(f, "{} removed", self.path),
+            Kind::Changed { old, new } => write!(f, "{}: {} -> {}", self.path, old, new),
+        }
+    }
+}
+
+/// Returns the changes from `old` to `new`.
+pub fn requests(old: &crate::BidRequest, new: &crate::BidRequest) -> Vec<Change> {
+    values(&to_value(old), &to_value(new))
+}
+
+/// Returns the changes from `old` to `new`.
+pub fn responses(old: &crate::BidResponse, new: &crate::BidResponse) -> Vec<Change> {
+    values(&to_value(old), &to_value(new))
+}
+
+/// Returns the changes from `old` to `new`, in the order of their paths.
+pub fn values(old: &Value, new: &Value) -> Vec<Change> {
+    let mut changes = Vec::new();
+    walk(String::new(), old, new, &mut changes);
+    changes
+}
+
+/// Renders changes one per line, e.g. for a terminal.
+pub fn to_text(changes: &[Change]) -> String {
+    changes.iter().map(|change| format!("{}\n", change)).collect()
+}
+
+fn to_value<T: serde::Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).expect("openrtb objects serialize to JSON")
+}
+
+fn walk(path: String, old: &Value, new: &Value, changes: &mut Vec<Change>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            let keys: std::collections::BTreeSet<_> = old.keys().chain(new.keys()).collect();
+            for key in keys {
+                let path = match path.is_empty() {
+                    true => key.clone(),
+                    false => format!("{}.{}", path, key),
+                };
+                match (old.get(key), new.get(key)) {
+                    (Some(old), Some(new)) => walk(path, old, new, changes),
+                    (Some(old), None) => changes.push(Change {
+                        path,
+                        kind: Kind::Removed(old.clone()),
+                    }),
+                    (None, Some(new)) => changes.push(Change {
+                        path,
+                        kind: Kind::Added(new.clone()),
+                    }),
+                    (None, None) => unreachable!(),
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => match (ids(old), ids(new)) {
+            (Some(old_ids), Some(new_ids)) => {
+                for (i, (id, new)) in new_ids.iter().zip(new).enumerate() {
+                    let path = format!("{}[{}]", path, i);
+                    match old_ids.iter().position(|old_id| old_id == id) {
+                        Some(j) => walk(path, &old[j], new, changes),
+                        None => changes.push(Change {
+                            path,
+                            kind: Kind::Added(new.clone()),
+                        }),
+                    }
+                }
+                for (i, (id, old)) in old_ids.iter().zip(old).enumerate() {
+                    if !new_ids.contains(id) {
+                        let path = format!("{}[{}]", path, i);
+                        changes.push(Change {
+                            path,
+                            kind: Kind::Removed(old.clone()),
+                        });
+                    }
+                }
+            }
+            _ => {
+                for (i, (old, new)) in old.iter().zip(new).enumerate() {
+                    walk(format!("{}[{}]", path, i), old, new, changes);
+                }
+                for (i, new) in new.iter().enumerate().skip(old.len()) {
+                    let path = format!("{}[{}]", path, i);
+                    changes.push(Change {
+                        path,
+                        kind: Kind::Added(new.clone()),
+                    });
+                }
+                for (i, old) in old.iter().enumerate().skip(new.len()) {
+                    let path = format!("{}[{}]", path, i);
+                    changes.push(Change {
+                        path,
+                        kind: Kind::Removed(old.clone()),
+                    });
+                }
+            }
+        },
+        (old, new) if old != new => changes.push(Change {
+            path,
+            kind: Kind::Changed {
+                old: old.clone(),
+                new: new.clone(),
+            },
+        }),
+        _ => {}
+    }
+}
+
+/// Returns the ids of the elements if they are all objects with a distinct string `id`.
+fn ids(array: &[Value]) -> Option<Vec<&str>> {
+    let ids = array
+        .iter()
+        .map(|value| value.get("id")?.as_str())
+        .collect::<Option<Vec<_>>>()?;
+    let distinct: std::collections::HashSet<_> = ids.iter().collect();
+    (distinct.len() == ids.len()).then_some(ids)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn request() -> serde_json::Result<()> {
+        let old: crate::BidRequest =
+            serde_json::from_str(include_str!("../tests/json/6.3.5_pmp_with_direct_deal.json"))?;
+        assert!(requests(&old, &old).is_empty());
+
+        let mut new = old.clone();
+        let imp = &mut new.imp[0];
+        imp.banner.as_mut().unwrap().w = Some(320);
+        let pmp = imp.pmp.as_mut().unwrap();
+        let deals = pmp.deals.as_mut().unwrap();
+        deals.swap(0, 1);
+        deals[0].bidfloor = Some(3.0);
+        deals.pop();
+        new.user = None;
+        new.bcat = Some(vec!["IAB1".into()]);
+
+        assert_eq!(
+            to_text(&requests(&old, &new)),
+            [
+                "bcat added: [\"IAB1\"]",
+                "imp[0].banner.w: 300 -> 320",
+                "imp[0].pmp.deals[0].bidfloor: 2.0 -> 3.0",
+                "imp[0].pmp.deals[0] removed",
+                "user removed\n",
+            ]
+            .join("\n")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn response() -> serde_json::Result<()> {
+        let old: crate::BidResponse = serde_json::from_str(include_str!(
+            "../tests/json/6.4.3_direct_deal_ad_served_on_win_notice.json"
+        ))?;
+        let mut new = old.clone();
+        let bid = &mut new.seatbid.as_mut().unwrap()[0].bid;
+        bid.insert(
+            0,
+            crate::Bid {
+                id: "2".into(),
+                ..bid[0].clone()
+            },
+        );
+        bid[1].price = 1.0;
+
+        let changes = responses(&old, &new);
+        let paths: Vec<_> = changes.iter().map(|change| change.path.as_str()).collect();
+        assert_eq!(paths, ["seatbid[0].bid[0]", "seatbid[0].bid[1].price"]);
+        assert!(matches!(changes[0].kind, Kind::Added(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn json() {
+        let old = serde_json::json!({"format": [{"w": 300}, {"w": 728}], "id": "1"});
+        let new = serde_json::json!({"format": [{"w": 320}], "id": 1});
+        assert_eq!(
+            to_text(&values(&old, &new)),
+            "format[0].w: 300 -> 320\nformat[1] removed\nid: \"1\" -> 1\n"
+        );
+    }
+}
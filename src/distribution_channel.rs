@@ -3,6 +3,8 @@
 /// [`Site`]: ./struct.Site.html
 /// [`App`]: ./struct.App.html
 #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(rename_all = "snake_case")]
 pub enum DistributionChannel<'a> {
     /// object; recommended
@@ -3,6 +3,8 @@
 /// The following table lists the directions in which an expandable ad may expand, given the
 /// positioning of the ad unit on the page and constraints imposed by the content.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum ExpandableDirection {
     /// Left
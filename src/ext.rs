@@ -0,0 +1,106 @@
+//! Access to `json_ext` values through their JSON representation.
+//!
+//! The helpers only rely on the serde implementations of `json_ext::Flag` and `json_ext::Object`,
+//! which are fixed by the wire format (`0`/`1` and a JSON object).
+
+/// Returns true if `flag` is present and set to 1.
+pub(crate) fn is_set(flag: Option<json_ext::Flag>) -> bool {
+    flag.and_then(|flag| serde_json::to_value(flag).ok())
+        .is_some_and(|value| value.as_u64() == Some(1) || value.as_bool() == Some(true))
+}
+
+/// Returns a flag set to 1 if `set` is true, 0 otherwise.
+pub(crate) fn flag(set: bool) -> json_ext::Flag {
+    serde_json::from_value(u8::from(set).into()).expect("a flag deserializes from 0 or 1")
+}
+
+/// Returns the member `key` of an optional extension object.
+pub(crate) fn get(ext: Option<&json_ext::Object>, key: &str) -> Option<serde_json::Value> {
+    match serde_json::to_value(ext?) {
+        Ok(serde_json::Value::Object(mut map)) => map.remove(key),
+        _ => None,
+    }
+}
+
+/// Sets the member `key` of an optional extension object, creating the object if needed.
+pub(crate) fn set<'a>(
+    ext: &mut Option<json_ext::Object<'a>>,
+    key: &str,
+    value: serde_json::Value,
+) -> serde_json::Result<()> {
+    let mut map = match ext.as_ref().map(serde_json::to_value).transpose()? {
+        Some(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    map.insert(key.to_owned(), value);
+    *ext = Some(serde::Deserialize::deserialize(serde_json::Value::Object(map))?);
+    Ok(())
+}
+
+/// Removes the member `key` of an optional extension object and returns it, dropping the object if
+/// it becomes empty.
+pub(crate) fn remove(ext: &mut Option<json_ext::Object>, key: &str) -> Option<serde_json::Value> {
+    let mut map = match serde_json::to_value(ext.as_ref()?) {
+        Ok(serde_json::Value::Object(map)) => map,
+        _ => return None,
+    };
+    let value = map.remove(key)?;
+    *ext = match map.is_empty() {
+        true => None,
+        false => serde::Deserialize::deserialize(serde_json::Value::Object(map)).ok(),
+    };
+    Some(value)
+}
+
+/// Returns the member `key` of an optional extension object if it is a string.
+pub(crate) fn get_str(ext: Option<&json_ext::Object>, key: &str) -> Option<String> {
+    match get(ext, key)? {
+        serde_json::Value::String(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// Interprets a JSON flag, also accepting booleans and numeric strings sent by some partners.
+pub(crate) fn as_flag(value: &serde_json::Value) -> Option<bool> {
+    match value {
+        serde_json::Value::Bool(b) => Some(*b),
+        serde_json::Value::Number(n) => n.as_u64().map(|n| n == 1),
+        serde_json::Value::String(s) => s.trim().parse::<u8>().ok().map(|n| n == 1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        assert!(is_set(Some(serde_json::from_str("1")?)));
+        assert!(!is_set(Some(serde_json::from_str("0")?)));
+        assert!(!is_set(None));
+
+        let ext: json_ext::Object = serde_json::from_str(r#"{"gdpr":1,"consent":"abc"}"#)?;
+        assert_eq!(get(Some(&ext), "consent"), Some("abc".into()));
+        assert_eq!(get(Some(&ext), "gdpr").as_ref().and_then(as_flag), Some(true));
+        assert_eq!(get(None, "gdpr"), None);
+        assert_eq!(get_str(Some(&ext), "consent").as_deref(), Some("abc"));
+        assert_eq!(get_str(Some(&ext), "gdpr"), None);
+        assert_eq!(as_flag(&"0".into()), Some(false));
+        assert!(is_set(Some(flag(true))));
+        assert!(!is_set(Some(flag(false))));
+
+        let mut ext = Some(ext);
+        set(&mut ext, "gdpr", 0.into())?;
+        assert_eq!(get(ext.as_ref(), "gdpr"), Some(0.into()));
+        assert_eq!(get_str(ext.as_ref(), "consent").as_deref(), Some("abc"));
+        let mut ext = None;
+        set(&mut ext, "gdpr", 1.into())?;
+        assert_eq!(serde_json::to_string(&ext)?, r#"{"gdpr":1}"#);
+        assert_eq!(remove(&mut ext, "consent"), None);
+        assert_eq!(remove(&mut ext, "gdpr"), Some(1.into()));
+        assert_eq!(ext, None);
+
+        Ok(())
+    }
+}
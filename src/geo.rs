@@ -9,15 +9,19 @@
 /// type attribute. For example, the centroid of a geographic region such as postal code should not
 /// be passed.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Geo<'a> {
     /// float
     /// Latitude from -90.0 to +90.0, where negative is south.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_latitude))]
     pub lat: Option<f32>,
 
     /// float
     /// Longitude from -180.0 to +180.0, where negative is west.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_longitude))]
     pub lon: Option<f32>,
 
     /// integer
@@ -87,6 +91,8 @@ pub struct Geo<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
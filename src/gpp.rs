@@ -0,0 +1,352 @@
+//! IAB Global Privacy Platform strings.
+//!
+//! The exchange forwards the GPP string of the user in `regs.ext.gpp` and the sections applicable
+//! to the request in `regs.ext.gpp_sid`. A GPP string is a header listing the encoded sections
+//! followed by the sections themselves, separated by `~`. This module decodes the header and the
+//! TCF EU v2, US Privacy and US National sections; other sections are kept as raw strings.
+
+use crate::tcf::BitReader;
+
+/// Section ID of the TCF EU v2 section.
+pub const TCF_EU_V2: u16 = 2;
+/// Section ID of the US Privacy section.
+pub const US_PRIVACY: u16 = 6;
+/// Section ID of the US National section.
+pub const US_NATIONAL: u16 = 7;
+
+/// A decoded GPP string.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GppString {
+    /// Version of the GPP header, 1.
+    pub version: u8,
+    /// Sections in the order they are encoded.
+    pub sections: Vec<Section>,
+}
+
+/// A section of a GPP string.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Section {
+    /// Section 2, the IAB TCF EU v2 string.
+    TcfEuV2(crate::tcf::TcString),
+    /// Section 6, the IAB US Privacy string.
+    UsPrivacy(crate::us_privacy::UsPrivacy),
+    /// Section 7, the US National privacy section.
+    UsNational(UsNational),
+    /// Any other section, left encoded.
+    Other(u16, String),
+}
+
+impl Section {
+    /// Returns the ID of the section.
+    pub fn id(&self) -> u16 {
+        match self {
+            Self::TcfEuV2(_) => TCF_EU_V2,
+            Self::UsPrivacy(_) => US_PRIVACY,
+            Self::UsNational(_) => US_NATIONAL,
+            Self::Other(id, _) => *id,
+        }
+    }
+}
+
+/// A two-bit signal of the US sections: 0 = not applicable, 1 = yes, 2 = no.
+///
+/// The meaning of "yes" depends on the field: notice provided, opted out, transaction covered.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Signal {
+    /// The field does not apply, 0.
+    #[default]
+    NotApplicable,
+    /// 1, e.g. notice was provided or the user opted out.
+    Yes,
+    /// 2, e.g. notice was not provided or the user did not opt out.
+    No,
+}
+
+impl Signal {
+    fn read(r: &mut BitReader) -> Result<Self, Error> {
+        Ok(match r.read(2)? {
+            1 => Self::Yes,
+            2 => Self::No,
+            _ => Self::NotApplicable,
+        })
+    }
+}
+
+/// Section 7, the US National privacy section (version 1).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct UsNational {
+    /// Version of the section, 1.
+    pub version: u8,
+    /// `Yes` if notice of the sharing of personal data was provided.
+    pub sharing_notice: Signal,
+    /// `Yes` if notice of the opportunity to opt out of the sale was provided.
+    pub sale_opt_out_notice: Signal,
+    /// `Yes` if notice of the opportunity to opt out of the sharing was provided.
+    pub sharing_opt_out_notice: Signal,
+    /// `Yes` if notice of the opportunity to opt out of targeted advertising was provided.
+    pub targeted_advertising_opt_out_notice: Signal,
+    /// `Yes` if notice of the opportunity to opt out of sensitive data processing was provided.
+    pub sensitive_data_processing_opt_out_notice: Signal,
+    /// `Yes` if notice of the opportunity to limit the use of sensitive data was provided.
+    pub sensitive_data_limit_use_notice: Signal,
+    /// `Yes` if the user opted out of the sale of their personal data.
+    pub sale_opt_out: Signal,
+    /// `Yes` if the user opted out of the sharing of their personal data.
+    pub sharing_opt_out: Signal,
+    /// `Yes` if the user opted out of targeted advertising.
+    pub targeted_advertising_opt_out: Signal,
+    /// Consent per category of sensitive data: 0 = not applicable, 1 = no consent, 2 = consent.
+    pub sensitive_data_processing: Vec<u8>,
+    /// Consent for known children: 0 = not applicable, 1 = no consent, 2 = consent.
+    pub known_child_sensitive_data_consents: Vec<u8>,
+    /// `Yes` if the user did not consent to the processing of personal data.
+    pub personal_data_consents: Signal,
+    /// `Yes` if the transaction is covered by the Multi-State Privacy Agreement.
+    pub mspa_covered_transaction: Signal,
+    /// `Yes` if the MSPA opt-out option mode applies.
+    pub mspa_opt_out_option_mode: Signal,
+    /// `Yes` if the MSPA service provider mode applies.
+    pub mspa_service_provider_mode: Signal,
+    /// Global Privacy Control signal, if the GPC subsection is present.
+    pub gpc: Option<bool>,
+}
+
+impl UsNational {
+    /// Parses the encoded section.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let mut segments = s.split('.');
+        let bytes = crate::tcf::decode_base64(segments.next().unwrap_or_default())?;
+        let mut r = BitReader::new(&bytes);
+
+        let mut section = Self {
+            version: r.read(6)? as u8,
+            sharing_notice: Signal::read(&mut r)?,
+            sale_opt_out_notice: Signal::read(&mut r)?,
+            sharing_opt_out_notice: Signal::read(&mut r)?,
+            targeted_advertising_opt_out_notice: Signal::read(&mut r)?,
+            sensitive_data_processing_opt_out_notice: Signal::read(&mut r)?,
+            sensitive_data_limit_use_notice: Signal::read(&mut r)?,
+            sale_opt_out: Signal::read(&mut r)?,
+            sharing_opt_out: Signal::read(&mut r)?,
+            targeted_advertising_opt_out: Signal::read(&mut r)?,
+            ..Default::default()
+        };
+        for _ in 0..12 {
+            section.sensitive_data_processing.push(r.read(2)? as u8);
+        }
+        for _ in 0..2 {
+            section.known_child_sensitive_data_consents.push(r.read(2)? as u8);
+        }
+        section.personal_data_consents = Signal::read(&mut r)?;
+        section.mspa_covered_transaction = Signal::read(&mut r)?;
+        section.mspa_opt_out_option_mode = Signal::read(&mut r)?;
+        section.mspa_service_provider_mode = Signal::read(&mut r)?;
+
+        for segment in segments {
+            let bytes = crate::tcf::decode_base64(segment)?;
+            let mut r = BitReader::new(&bytes);
+            // subsection type 1 is the GPC subsection
+            if r.read(2)? == 1 {
+                section.gpc = Some(r.read_bool()?);
+            }
+        }
+
+        Ok(section)
+    }
+
+    /// Returns false if the user opted out of the sale or the sharing of their personal data, or
+    /// sent a Global Privacy Control signal.
+    pub fn may_sell_or_share(&self) -> bool {
+        self.sale_opt_out != Signal::Yes && self.sharing_opt_out != Signal::Yes && self.gpc != Some(true)
+    }
+}
+
+impl GppString {
+    /// Parses a GPP string.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let mut parts = s.trim().split('~');
+        let bytes = crate::tcf::decode_base64(parts.next().unwrap_or_default())?;
+        let mut r = BitReader::new(&bytes);
+
+        if r.read(6)? != 3 {
+            return Err(Error::Header);
+        }
+        let version = r.read(6)? as u8;
+        let parts: Vec<_> = parts.collect();
+        let ids = read_fibonacci_range(&mut r, parts.len())?;
+        if parts.len() != ids.len() {
+            return Err(Error::SectionCount);
+        }
+
+        let sections = ids
+            .into_iter()
+            .zip(parts)
+            .map(|(id, part)| {
+                Ok(match id {
+                    TCF_EU_V2 => Section::TcfEuV2(crate::tcf::TcString::parse(part).map_err(Error::Tcf)?),
+                    US_PRIVACY => Section::UsPrivacy(part.parse().map_err(Error::UsPrivacy)?),
+                    US_NATIONAL => Section::UsNational(UsNational::parse(part)?),
+                    id => Section::Other(id, part.to_owned()),
+                })
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self { version, sections })
+    }
+
+    /// Returns the section with the given ID.
+    pub fn section(&self, id: u16) -> Option<&Section> {
+        self.sections.iter().find(|s| s.id() == id)
+    }
+
+    /// Returns the TCF EU v2 section.
+    pub fn tcf_eu_v2(&self) -> Option<&crate::tcf::TcString> {
+        match self.section(TCF_EU_V2)? {
+            Section::TcfEuV2(tc) => Some(tc),
+            _ => None,
+        }
+    }
+
+    /// Returns the US Privacy section.
+    pub fn us_privacy(&self) -> Option<&crate::us_privacy::UsPrivacy> {
+        match self.section(US_PRIVACY)? {
+            Section::UsPrivacy(usp) => Some(usp),
+            _ => None,
+        }
+    }
+
+    /// Returns the US National section.
+    pub fn us_national(&self) -> Option<&UsNational> {
+        match self.section(US_NATIONAL)? {
+            Section::UsNational(usnat) => Some(usnat),
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for GppString {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+fn read_fibonacci(r: &mut BitReader) -> Result<u64, Error> {
+    let (mut value, mut fib, mut next, mut last) = (0u64, 1u64, 2u64, false);
+    loop {
+        let bit = r.read_bool()?;
+        if bit && last {
+            return Ok(value);
+        }
+        if bit {
+            value = value.checked_add(fib).ok_or(Error::Header)?;
+        }
+        (fib, next, last) = (next, fib.checked_add(next).ok_or(Error::Header)?, bit);
+    }
+}
+
+/// Reads the section IDs of the header, failing as soon as there are more than `max`.
+fn read_fibonacci_range(r: &mut BitReader, max: usize) -> Result<Vec<u16>, Error> {
+    let mut ids = Vec::new();
+    let mut offset = 0u64;
+    for _ in 0..r.read(12)? {
+        let is_range = r.read_bool()?;
+        let start = offset.checked_add(read_fibonacci(r)?).ok_or(Error::Header)?;
+        offset = start;
+        if is_range {
+            offset = start.checked_add(read_fibonacci(r)?).ok_or(Error::Header)?;
+        }
+        let start = u16::try_from(start).map_err(|_| Error::Header)?;
+        let end = u16::try_from(offset).map_err(|_| Error::Header)?;
+        if ids.len() + usize::from(end - start) + 1 > max {
+            return Err(Error::SectionCount);
+        }
+        ids.extend(start..=end);
+    }
+    Ok(ids)
+}
+
+/// The error type for GPP string decoding.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Error {
+    /// A segment is not valid base64url.
+    Base64,
+    /// A segment ends before all fields were read.
+    UnexpectedEnd,
+    /// The header is malformed.
+    Header,
+    /// The number of sections does not match the header.
+    SectionCount,
+    /// The TCF EU v2 section is malformed.
+    Tcf(crate::tcf::Error),
+    /// The US Privacy section is malformed.
+    UsPrivacy(crate::us_privacy::Error),
+}
+
+impl From<crate::tcf::Error> for Error {
+    fn from(e: crate::tcf::Error) -> Self {
+        match e {
+            crate::tcf::Error::Base64 => Self::Base64,
+            crate::tcf::Error::UnexpectedEnd => Self::UnexpectedEnd,
+            e => Self::Tcf(e),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Base64 => f.write_str("invalid base64url encoding"),
+            Self::UnexpectedEnd => f.write_str("unexpected end of gpp string"),
+            Self::Header => f.write_str("invalid gpp header"),
+            Self::SectionCount => f.write_str("number of sections does not match the gpp header"),
+            Self::Tcf(e) => write!(f, "invalid tcf eu v2 section: {}", e),
+            Self::UsPrivacy(e) => write!(f, "invalid us privacy section: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() -> Result<(), Error> {
+        // header of sections 7 and 8, followed by a US National section where the user opted out
+        // of sale, and a GPC subsection
+        let gpp: GppString = "DBACLY~BVQWAAAAAa.Y~BVQqAAAAAa".parse()?;
+        assert_eq!(gpp.version, 1);
+        assert_eq!(gpp.sections.iter().map(Section::id).collect::<Vec<_>>(), vec![7, 8]);
+
+        let usnat = gpp.us_national().unwrap();
+        assert_eq!(usnat.version, 1);
+        assert_eq!(usnat.sharing_notice, Signal::Yes);
+        assert_eq!(usnat.sale_opt_out, Signal::Yes);
+        assert_eq!(usnat.sharing_opt_out, Signal::Yes);
+        assert_eq!(usnat.sensitive_data_processing, vec![0; 12]);
+        assert_eq!(usnat.mspa_covered_transaction, Signal::Yes);
+        assert_eq!(usnat.mspa_service_provider_mode, Signal::No);
+        assert_eq!(usnat.gpc, Some(true));
+        assert!(!usnat.may_sell_or_share());
+        assert!(matches!(gpp.section(8), Some(Section::Other(8, _))));
+
+        // header of section 6 followed by a US Privacy string
+        let gpp: GppString = "DBABTA~1YNN".parse()?;
+        assert_eq!(gpp.us_privacy().unwrap().opt_out_sale, Some(false));
+        assert!(gpp.tcf_eu_v2().is_none());
+
+        assert_eq!(GppString::parse("DBABTA"), Err(Error::SectionCount));
+        assert_eq!(GppString::parse("ABABTA~1YNN"), Err(Error::Header));
+        assert!(matches!(GppString::parse("DBABTA~1YNX"), Err(Error::UsPrivacy(_))));
+
+        // a section ID made of zero bits overflows
+        assert_eq!(GppString::parse(&format!("DBAB{}", "A".repeat(24))), Err(Error::Header));
+        // a range of 60000 sections for a single one
+        assert_eq!(GppString::parse("DBAB4IgSY~1YNN"), Err(Error::SectionCount));
+
+        Ok(())
+    }
+}
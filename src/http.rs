@@ -0,0 +1,525 @@
+//! 2 OpenRTB Transport
+//!
+//! Helpers implementing the transport conventions of the specification: bid requests are sent as
+//! HTTP POST with a JSON body (2.3), optionally gzip compressed (2.4), carrying the
+//! `x-openrtb-version` header (2.5). A no-bid is signalled by an empty HTTP 204 response or by a
+//! `BidResponse` that only carries a reason in `nbr`.
+//!
+//! The functions in this module work on plain [`http`] types so they can be used with any HTTP
+//! stack. A [`reqwest`] based client is available with the `http-client` feature and an [`axum`]
+//! extractor with the `axum` feature.
+//!
+//! [`http`]: https://docs.rs/http
+//! [`reqwest`]: https://docs.rs/reqwest
+//! [`axum`]: https://docs.rs/axum
+
+use std::io::{Read, Write};
+
+#[cfg(feature = "http-client")]
+mod client;
+#[cfg(feature = "http-client")]
+pub use client::*;
+
+#[cfg(feature = "http-client")]
+mod fan_out;
+#[cfg(feature = "http-client")]
+pub use fan_out::*;
+
+#[cfg(feature = "axum")]
+mod server;
+#[cfg(feature = "axum")]
+pub use server::*;
+
+/// Name of the HTTP header carrying the OpenRTB version of the payload.
+pub const VERSION_HEADER: &str = "x-openrtb-version";
+
+/// OpenRTB version implemented by this crate.
+pub const VERSION: &str = "2.5";
+
+/// Media type of OpenRTB request and response bodies.
+pub const CONTENT_TYPE: &str = "application/json";
+
+/// Default limit, in bytes, of a decompressed request or response body.
+pub const DEFAULT_BODY_LIMIT: usize = 8 * 1024 * 1024;
+
+/// 2.4 Data Encoding
+///
+/// Compression applied to a request or response body.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Encoding {
+    /// No compression.
+    #[default]
+    Identity,
+    /// gzip compression.
+    Gzip,
+}
+
+impl Encoding {
+    /// Parses a `Content-Encoding` header value. A missing header means `Identity`.
+    pub fn from_content_encoding(value: Option<&str>) -> Result<Self, Error> {
+        match value.map(str::trim) {
+            None | Some("") => Ok(Self::Identity),
+            Some(v) if v.eq_ignore_ascii_case("identity") => Ok(Self::Identity),
+            Some(v) if v.eq_ignore_ascii_case("gzip") || v.eq_ignore_ascii_case("x-gzip") => Ok(Self::Gzip),
+            Some(v) => Err(Error::ContentEncoding(v.to_owned())),
+        }
+    }
+
+    /// Returns the preferred encoding given an `Accept-Encoding` header value.
+    ///
+    /// ```
+    /// # use openrtb2::http::Encoding;
+    /// assert_eq!(Encoding::from_accept_encoding(Some("gzip, deflate")), Encoding::Gzip);
+    /// assert_eq!(Encoding::from_accept_encoding(Some("gzip;q=0")), Encoding::Identity);
+    /// assert_eq!(Encoding::from_accept_encoding(None), Encoding::Identity);
+    /// ```
+    pub fn from_accept_encoding(value: Option<&str>) -> Self {
+        let accepted = value.unwrap_or_default().split(',').any(|item| {
+            let mut params = item.split(';').map(str::trim);
+            let coding = params.next().unwrap_or_default();
+            let rejected = params.any(|p| {
+                p.strip_prefix("q=")
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .is_some_and(|q| q <= 0.0)
+            });
+            (coding.eq_ignore_ascii_case("gzip") || coding.eq_ignore_ascii_case("x-gzip")) && !rejected
+        });
+        if accepted {
+            Self::Gzip
+        } else {
+            Self::Identity
+        }
+    }
+
+    /// Returns the `Content-Encoding` header value, or None for `Identity`.
+    pub fn as_header(&self) -> Option<&'static str> {
+        match self {
+            Self::Identity => None,
+            Self::Gzip => Some("gzip"),
+        }
+    }
+
+    /// Compresses `body` with this encoding.
+    pub fn encode(&self, body: Vec<u8>) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::Identity => Ok(body),
+            Self::Gzip => {
+                let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+                encoder.write_all(&body)?;
+                Ok(encoder.finish()?)
+            }
+        }
+    }
+
+    /// Decompresses `body` with this encoding, failing with `Error::BodyTooLarge` if the result
+    /// exceeds `limit` bytes.
+    ///
+    /// ```
+    /// # use openrtb2::http::{Encoding, Error};
+    /// let body = Encoding::Gzip.encode(vec![b' '; 1000])?;
+    /// assert_eq!(Encoding::Gzip.decode(&body, 1000)?.len(), 1000);
+    /// assert!(matches!(Encoding::Gzip.decode(&body, 999), Err(Error::BodyTooLarge)));
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn decode<'b>(&self, body: &'b [u8], limit: usize) -> Result<std::borrow::Cow<'b, [u8]>, Error> {
+        let body = match self {
+            Self::Identity => std::borrow::Cow::Borrowed(body),
+            Self::Gzip => {
+                // One byte past the limit tells a body of exactly `limit` bytes from a larger one.
+                let mut buf = Vec::new();
+                flate2::read::GzDecoder::new(body)
+                    .take(limit as u64 + 1)
+                    .read_to_end(&mut buf)?;
+                std::borrow::Cow::Owned(buf)
+            }
+        };
+        if body.len() > limit {
+            return Err(Error::BodyTooLarge);
+        }
+        Ok(body)
+    }
+}
+
+/// Validates a `Content-Type` header value.
+///
+/// The media type must be `application/json`; a charset parameter, if present, must be UTF-8 which
+/// is the only encoding JSON may be exchanged in. A missing header is tolerated.
+///
+/// ```
+/// # use openrtb2::http::check_content_type;
+/// assert!(check_content_type(None).is_ok());
+/// assert!(check_content_type(Some("application/json")).is_ok());
+/// assert!(check_content_type(Some("application/json; charset=\"UTF-8\"")).is_ok());
+/// assert!(check_content_type(Some("application/json; charset=iso-8859-1")).is_err());
+/// assert!(check_content_type(Some("text/plain")).is_err());
+/// ```
+pub fn check_content_type(value: Option<&str>) -> Result<(), Error> {
+    let value = match value {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+
+    let mut params = value.split(';').map(str::trim);
+    if !params.next().unwrap_or_default().eq_ignore_ascii_case(CONTENT_TYPE) {
+        return Err(Error::ContentType(value.to_owned()));
+    }
+    for param in params {
+        if let Some((name, charset)) = param.split_once('=') {
+            let charset = charset.trim().trim_matches('"');
+            if name.trim().eq_ignore_ascii_case("charset")
+                && !charset.eq_ignore_ascii_case("utf-8")
+                && !charset.eq_ignore_ascii_case("utf8")
+            {
+                return Err(Error::ContentType(value.to_owned()));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the time a bidder has to respond to a request: `tmax` milliseconds when given and
+/// positive, `default` otherwise.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use openrtb2::http::timeout;
+/// assert_eq!(timeout(Some(120), Duration::from_secs(1)), Duration::from_millis(120));
+/// assert_eq!(timeout(None, Duration::from_secs(1)), Duration::from_secs(1));
+/// ```
+pub fn timeout(tmax: Option<i32>, default: std::time::Duration) -> std::time::Duration {
+    match tmax {
+        Some(tmax) if tmax > 0 => std::time::Duration::from_millis(tmax as u64),
+        _ => default,
+    }
+}
+
+/// The answer of a bidder to a bid request.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Reply<'a> {
+    /// A response carrying at least one bid.
+    Bid(crate::BidResponse<'a>),
+    /// No bid, with the reason if the bidder supplied one.
+    NoBid(Option<crate::NoBidReason>),
+}
+
+impl<'a> Reply<'a> {
+    /// Classifies a bid response: a response without any bid is a no-bid.
+    pub fn from_response(res: crate::BidResponse<'a>) -> Self {
+        let has_bid = res
+            .seatbid
+            .as_ref()
+            .is_some_and(|seatbid| seatbid.iter().any(|s| !s.bid.is_empty()));
+        if has_bid {
+            Self::Bid(res)
+        } else {
+            Self::NoBid(res.nbr)
+        }
+    }
+
+    /// Returns true if the `Reply` is a no-bid. Returns false otherwise.
+    pub fn is_no_bid(&self) -> bool {
+        matches!(self, Self::NoBid(_))
+    }
+}
+
+/// Builds the HTTP request an exchange sends to a bidder.
+pub fn encode_request(
+    uri: &str,
+    req: &crate::BidRequest,
+    encoding: Encoding,
+) -> Result<::http::Request<Vec<u8>>, Error> {
+    build_request(uri, encoding.encode(serde_json::to_vec(req)?)?, encoding)
+}
+
+/// Builds a bid request HTTP message around an already encoded body.
+pub(crate) fn build_request(uri: &str, body: Vec<u8>, encoding: Encoding) -> Result<::http::Request<Vec<u8>>, Error> {
+    let mut builder = ::http::Request::post(uri)
+        .header(::http::header::CONTENT_TYPE, CONTENT_TYPE)
+        .header(::http::header::ACCEPT_ENCODING, "gzip")
+        .header(VERSION_HEADER, VERSION);
+    if let Some(value) = encoding.as_header() {
+        builder = builder.header(::http::header::CONTENT_ENCODING, value);
+    }
+    Ok(builder.body(body)?)
+}
+
+/// Parses the body of an HTTP request received by a bidder, decompressed up to
+/// [`DEFAULT_BODY_LIMIT`] bytes.
+///
+/// Requests announced as OpenRTB 2.2 to 2.4 by the `x-openrtb-version` header are upgraded to 2.5,
+/// see [`legacy`](../legacy/index.html).
+///
+/// [`DEFAULT_BODY_LIMIT`]: ./constant.DEFAULT_BODY_LIMIT.html
+pub fn decode_request(headers: &::http::HeaderMap, body: &[u8]) -> Result<crate::BidRequest<'static>, Error> {
+    decode_request_with_limit(headers, body, DEFAULT_BODY_LIMIT)
+}
+
+/// Parses the body of an HTTP request received by a bidder like [`decode_request`], decompressed
+/// up to `limit` bytes.
+///
+/// [`decode_request`]: ./fn.decode_request.html
+pub fn decode_request_with_limit(
+    headers: &::http::HeaderMap,
+    body: &[u8],
+    limit: usize,
+) -> Result<crate::BidRequest<'static>, Error> {
+    check_content_type(header(headers, ::http::header::CONTENT_TYPE))?;
+    let encoding = Encoding::from_content_encoding(header(headers, ::http::header::CONTENT_ENCODING))?;
+    let body = encoding.decode(body, limit)?;
+    let version = headers.get(VERSION_HEADER).and_then(|v| v.to_str().ok());
+    match version.map(crate::legacy::Version::parse) {
+        Some(Ok(version)) if version.is_legacy() => Ok(crate::legacy::from_slice(&body, version)?),
+        _ => from_slice(&body),
+    }
+}
+
+/// Builds the HTTP response a bidder returns, HTTP 204 if there is no response.
+pub fn encode_response(
+    res: Option<&crate::BidResponse>,
+    encoding: Encoding,
+) -> Result<::http::Response<Vec<u8>>, Error> {
+    let builder = ::http::Response::builder().header(VERSION_HEADER, VERSION);
+    let res = match res {
+        Some(res) => res,
+        None => return Ok(builder.status(::http::StatusCode::NO_CONTENT).body(Vec::new())?),
+    };
+
+    let body = encoding.encode(serde_json::to_vec(res)?)?;
+    let mut builder = builder
+        .status(::http::StatusCode::OK)
+        .header(::http::header::CONTENT_TYPE, CONTENT_TYPE);
+    if let Some(value) = encoding.as_header() {
+        builder = builder.header(::http::header::CONTENT_ENCODING, value);
+    }
+    Ok(builder.body(body)?)
+}
+
+/// Interprets the HTTP response of a bidder, decompressed up to [`DEFAULT_BODY_LIMIT`] bytes.
+///
+/// HTTP 204, an empty body and a `BidResponse` without bids are all reported as a no-bid. Responses
+/// announced as OpenRTB 2.2 to 2.4 by the `x-openrtb-version` header are upgraded to 2.5, see
+/// [`legacy`](../legacy/index.html).
+///
+/// [`DEFAULT_BODY_LIMIT`]: ./constant.DEFAULT_BODY_LIMIT.html
+pub fn decode_response(
+    status: ::http::StatusCode,
+    headers: &::http::HeaderMap,
+    body: &[u8],
+) -> Result<Reply<'static>, Error> {
+    decode_response_with_limit(status, headers, body, DEFAULT_BODY_LIMIT)
+}
+
+/// Interprets the HTTP response of a bidder like [`decode_response`], decompressed up to `limit`
+/// bytes.
+///
+/// [`decode_response`]: ./fn.decode_response.html
+pub fn decode_response_with_limit(
+    status: ::http::StatusCode,
+    headers: &::http::HeaderMap,
+    body: &[u8],
+    limit: usize,
+) -> Result<Reply<'static>, Error> {
+    if status == ::http::StatusCode::NO_CONTENT {
+        return Ok(Reply::NoBid(None));
+    }
+    if !status.is_success() {
+        return Err(Error::Status(status));
+    }
+
+    let encoding = Encoding::from_content_encoding(header(headers, ::http::header::CONTENT_ENCODING))?;
+    let body = encoding.decode(body, limit)?;
+    if body.iter().all(u8::is_ascii_whitespace) {
+        return Ok(Reply::NoBid(None));
+    }
+    check_content_type(header(headers, ::http::header::CONTENT_TYPE))?;
+    let version = headers.get(VERSION_HEADER).and_then(|v| v.to_str().ok());
+    let res = match version.map(crate::legacy::Version::parse) {
+        Some(Ok(version)) if version.is_legacy() => crate::legacy::response_from_slice(&body, version)?,
+        _ => from_slice(&body)?,
+    };
+    Ok(Reply::from_response(res))
+}
+
+fn header(headers: &::http::HeaderMap, name: ::http::header::HeaderName) -> Option<&str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+/// Deserializes a value that owns all of its data.
+///
+/// Reading through `io::Read` never hands out borrowed strings, so every `Cow` ends up owned.
+pub(crate) fn from_slice<T>(body: &[u8]) -> Result<T, Error>
+where
+    T: serde::Deserialize<'static>,
+{
+    let mut de = serde_json::Deserializer::from_reader(body);
+    let value = T::deserialize(&mut de)?;
+    de.end()?;
+    Ok(value)
+}
+
+/// The error type for transport operations.
+#[derive(Debug)]
+pub enum Error {
+    /// The body is not valid OpenRTB JSON.
+    Json(serde_json::Error),
+    /// The body could not be compressed or decompressed.
+    Io(std::io::Error),
+    /// The HTTP message could not be built.
+    Http(::http::Error),
+    /// The peer answered with an unexpected HTTP status.
+    Status(::http::StatusCode),
+    /// Unsupported `Content-Type`.
+    ContentType(String),
+    /// Unsupported `Content-Encoding`.
+    ContentEncoding(String),
+    /// The body exceeds the size limit once decompressed.
+    BodyTooLarge,
+    /// The peer did not answer in time.
+    Timeout,
+    /// The HTTP client failed.
+    #[cfg(feature = "http-client")]
+    Client(reqwest::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json(e) => write!(f, "invalid json: {}", e),
+            Self::Io(e) => write!(f, "invalid body: {}", e),
+            Self::Http(e) => write!(f, "invalid http message: {}", e),
+            Self::Status(status) => write!(f, "unexpected status: {}", status),
+            Self::ContentType(value) => write!(f, "unsupported content-type: {}", value),
+            Self::ContentEncoding(value) => write!(f, "unsupported content-encoding: {}", value),
+            Self::BodyTooLarge => f.write_str("body too large"),
+            Self::Timeout => f.write_str("timed out"),
+            #[cfg(feature = "http-client")]
+            Self::Client(e) => write!(f, "http client error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(e) => Some(e),
+            Self::Io(e) => Some(e),
+            Self::Http(e) => Some(e),
+            #[cfg(feature = "http-client")]
+            Self::Client(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<::http::Error> for Error {
+    fn from(e: ::http::Error) -> Self {
+        Self::Http(e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn request() -> Result<(), Error> {
+        let req = crate::BidRequest {
+            id: "1".into(),
+            imp: vec![Default::default()],
+            tmax: Some(120),
+            ..Default::default()
+        };
+
+        for encoding in [Encoding::Identity, Encoding::Gzip] {
+            let http = encode_request("http://127.0.0.1/bid", &req, encoding)?;
+            assert_eq!(http.headers()[VERSION_HEADER], VERSION);
+            assert_eq!(decode_request(http.headers(), http.body())?, req);
+        }
+
+        let mut headers = ::http::HeaderMap::new();
+        headers.insert(::http::header::CONTENT_TYPE, "text/xml".parse().unwrap());
+        assert!(matches!(decode_request(&headers, b"{}"), Err(Error::ContentType(_))));
+
+        let body = br#"{"id":"1","imp":[{"id":"1","video":{"protocol":3}}]}"#;
+        let mut headers = ::http::HeaderMap::new();
+        assert!(decode_request(&headers, body).is_err());
+        headers.insert(VERSION_HEADER, "2.3".parse().unwrap());
+        let video = decode_request(&headers, body)?.imp[0].video.clone().unwrap();
+        assert_eq!(video.protocols, Some(vec![crate::Protocol::Vast3]));
+
+        let mut headers = ::http::HeaderMap::new();
+        headers.insert(::http::header::CONTENT_ENCODING, "gzip".parse().unwrap());
+        let bomb = Encoding::Gzip.encode(vec![b' '; 1024 * 1024])?;
+        assert!(matches!(
+            decode_request_with_limit(&headers, &bomb, 1024),
+            Err(Error::BodyTooLarge)
+        ));
+        let body = Encoding::Gzip.encode(br#"{"id":"1","imp":[]}"#.to_vec())?;
+        assert_eq!(decode_request_with_limit(&headers, &body, 19)?.id, "1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn response() -> Result<(), Error> {
+        let headers = ::http::HeaderMap::new();
+        assert_eq!(
+            decode_response(::http::StatusCode::NO_CONTENT, &headers, b"")?,
+            Reply::NoBid(None)
+        );
+        assert_eq!(
+            decode_response(::http::StatusCode::OK, &headers, b" \n")?,
+            Reply::NoBid(None)
+        );
+        assert_eq!(
+            decode_response(::http::StatusCode::OK, &headers, br#"{"id":"1","nbr":2}"#)?,
+            Reply::NoBid(Some(crate::NoBidReason::InvalidRequest))
+        );
+        assert!(matches!(
+            decode_response(::http::StatusCode::BAD_REQUEST, &headers, b""),
+            Err(Error::Status(_))
+        ));
+
+        let body = br#"{"id":"1","seatbid":[{"bid":[{"id":"1","impid":"1","price":1,"ext":{"burl":"b"}}]}]}"#;
+        let mut legacy = ::http::HeaderMap::new();
+        legacy.insert(VERSION_HEADER, "2.4".parse().unwrap());
+        match decode_response(::http::StatusCode::OK, &legacy, body)? {
+            Reply::Bid(res) => assert_eq!(res.seatbid.unwrap()[0].bid[0].burl.as_deref(), Some("b")),
+            reply => panic!("unexpected reply: {:?}", reply),
+        }
+
+        assert_eq!(
+            encode_response(None, Encoding::Gzip)?.status(),
+            ::http::StatusCode::NO_CONTENT
+        );
+
+        let res = crate::BidResponse {
+            id: "1".into(),
+            seatbid: Some(vec![crate::SeatBid {
+                bid: vec![Default::default()],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let http = encode_response(Some(&res), Encoding::Gzip)?;
+        assert_eq!(http.headers()[::http::header::CONTENT_ENCODING], "gzip");
+        assert_eq!(
+            decode_response(http.status(), http.headers(), http.body())?,
+            Reply::Bid(res)
+        );
+
+        Ok(())
+    }
+}
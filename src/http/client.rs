@@ -0,0 +1,95 @@
+/// HTTP client used by an exchange to send bid requests to bidders.
+///
+/// The client compresses request bodies with the configured [`Encoding`], advertises gzip support
+/// for responses and bounds every call by the `tmax` of the request.
+///
+/// [`Encoding`]: ./enum.Encoding.html
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: reqwest::Client,
+    pub(super) encoding: super::Encoding,
+    pub(super) default_timeout: std::time::Duration,
+    pub(super) body_limit: usize,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new(reqwest::Client::new())
+    }
+}
+
+impl Client {
+    /// Timeout applied to requests without `tmax`.
+    pub const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(1000);
+
+    /// Wraps a `reqwest::Client`, which keeps connection pooling and TLS configuration up to the
+    /// caller.
+    pub fn new(inner: reqwest::Client) -> Self {
+        Self {
+            inner,
+            encoding: super::Encoding::Identity,
+            default_timeout: Self::DEFAULT_TIMEOUT,
+            body_limit: super::DEFAULT_BODY_LIMIT,
+        }
+    }
+
+    /// Sets the encoding of request bodies.
+    pub fn encoding(mut self, encoding: super::Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Sets the timeout applied to requests without `tmax`.
+    pub fn default_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.default_timeout = timeout;
+        self
+    }
+
+    /// Sets the limit, in bytes, of decompressed response bodies.
+    pub fn body_limit(mut self, limit: usize) -> Self {
+        self.body_limit = limit;
+        self
+    }
+
+    /// Sends `req` to the bidder at `url`, waiting at most `tmax` for the reply.
+    pub async fn send(&self, url: &str, req: &crate::BidRequest<'_>) -> Result<super::Reply<'static>, super::Error> {
+        let timeout = super::timeout(req.tmax, self.default_timeout);
+        self.send_with_timeout(url, req, timeout).await
+    }
+
+    /// Sends `req` to the bidder at `url`, waiting at most `timeout` for the reply.
+    pub async fn send_with_timeout(
+        &self,
+        url: &str,
+        req: &crate::BidRequest<'_>,
+        timeout: std::time::Duration,
+    ) -> Result<super::Reply<'static>, super::Error> {
+        self.execute(super::encode_request(url, req, self.encoding)?, timeout)
+            .await
+    }
+
+    pub(crate) async fn execute(
+        &self,
+        request: ::http::Request<Vec<u8>>,
+        timeout: std::time::Duration,
+    ) -> Result<super::Reply<'static>, super::Error> {
+        let mut request = reqwest::Request::try_from(request)?;
+        *request.timeout_mut() = Some(timeout);
+
+        let response = self.inner.execute(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        super::decode_response_with_limit(status, &headers, &body, self.body_limit)
+    }
+}
+
+impl From<reqwest::Error> for super::Error {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            Self::Timeout
+        } else {
+            Self::Client(e)
+        }
+    }
+}
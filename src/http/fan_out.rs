@@ -0,0 +1,127 @@
+/// A bidder endpoint the exchange sends bid requests to.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Bidder {
+    /// Name used to identify the bidder in results.
+    pub name: String,
+    /// URL the bid requests are posted to.
+    pub url: String,
+}
+
+impl Bidder {
+    /// Creates a bidder named `name` listening at `url`.
+    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            url: url.into(),
+        }
+    }
+}
+
+/// Sends one bid request to many bidders concurrently.
+///
+/// Every bidder gets the same deadline: the `tmax` of the request minus a network allowance which
+/// reserves time for the exchange to run the auction and answer upstream. Bidders that do not
+/// answer within the deadline are reported as timed out; the others are collected with their
+/// latency.
+#[derive(Debug, Clone)]
+pub struct FanOut {
+    client: super::Client,
+    bidders: Vec<Bidder>,
+    allowance: std::time::Duration,
+}
+
+impl FanOut {
+    /// Time reserved by default for network latency and the auction.
+    pub const DEFAULT_ALLOWANCE: std::time::Duration = std::time::Duration::from_millis(20);
+
+    /// Creates a fan-out sending requests to `bidders` through `client`.
+    pub fn new(client: super::Client, bidders: Vec<Bidder>) -> Self {
+        Self {
+            client,
+            bidders,
+            allowance: Self::DEFAULT_ALLOWANCE,
+        }
+    }
+
+    /// Sets the time subtracted from `tmax` to get the bidder deadline.
+    pub fn allowance(mut self, allowance: std::time::Duration) -> Self {
+        self.allowance = allowance;
+        self
+    }
+
+    /// Returns the bidders requests are sent to.
+    pub fn bidders(&self) -> &[Bidder] {
+        &self.bidders
+    }
+
+    /// Returns the time bidders have to answer `req`.
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use openrtb2::http::{Client, FanOut};
+    /// let fan_out = FanOut::new(Client::default(), vec![]).allowance(Duration::from_millis(30));
+    /// let req = openrtb2::BidRequest { tmax: Some(100), ..Default::default() };
+    /// assert_eq!(fan_out.deadline(&req), Duration::from_millis(70));
+    /// ```
+    pub fn deadline(&self, req: &crate::BidRequest) -> std::time::Duration {
+        super::timeout(req.tmax, self.client.default_timeout).saturating_sub(self.allowance)
+    }
+
+    /// Sends `req` to every bidder and waits until all of them answered or the deadline passed.
+    ///
+    /// Results are in the order of the bidders.
+    pub async fn send(&self, req: &crate::BidRequest<'_>) -> Result<Vec<BidderResult>, super::Error> {
+        let deadline = self.deadline(req);
+        let encoding = self.client.encoding;
+        let body = encoding.encode(serde_json::to_vec(req)?)?;
+
+        let calls = self.bidders.iter().map(|bidder| {
+            let request = super::build_request(&bidder.url, body.clone(), encoding);
+            async move {
+                let start = std::time::Instant::now();
+                let outcome = match request {
+                    Ok(_) if deadline.is_zero() => Err(super::Error::Timeout),
+                    Ok(request) => self.client.execute(request, deadline).await,
+                    Err(e) => Err(e),
+                };
+                BidderResult {
+                    bidder: bidder.name.clone(),
+                    latency: start.elapsed(),
+                    outcome,
+                }
+            }
+        });
+        Ok(futures_util::future::join_all(calls).await)
+    }
+}
+
+/// What happened when sending a bid request to one bidder.
+#[derive(Debug)]
+pub struct BidderResult {
+    /// Name of the bidder.
+    pub bidder: String,
+    /// Time between sending the request and receiving the reply or giving up.
+    pub latency: std::time::Duration,
+    /// The reply of the bidder, or why there is none.
+    pub outcome: Result<super::Reply<'static>, super::Error>,
+}
+
+impl BidderResult {
+    /// Returns the bid response if the bidder bid.
+    pub fn response(&self) -> Option<&crate::BidResponse<'static>> {
+        match &self.outcome {
+            Ok(super::Reply::Bid(res)) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the bidder did not answer before the deadline.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.outcome, Err(super::Error::Timeout))
+    }
+
+    /// Returns true if the call failed for another reason than a timeout.
+    pub fn is_error(&self) -> bool {
+        matches!(self.outcome, Err(ref e) if !matches!(e, super::Error::Timeout))
+    }
+}
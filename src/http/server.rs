@@ -0,0 +1,119 @@
+/// Axum extractor for a bid request received by a bidder.
+///
+/// Validates the `Content-Type`, decompresses gzip bodies and deserializes the `BidRequest`. The
+/// `Accept-Encoding` of the request is kept so the response can be compressed accordingly.
+///
+/// Axum's `DefaultBodyLimit` bounds the body as received. Once decompressed, the body is bounded by
+/// the [`BodyLimit`] extension of the request, [`DEFAULT_BODY_LIMIT`] if absent, and rejected with
+/// HTTP 413 beyond it.
+///
+/// ```no_run
+/// use openrtb2::http::{OpenRtbRequest, OpenRtbResponse};
+///
+/// async fn bid(req: OpenRtbRequest) -> OpenRtbResponse<'static> {
+///     // no bid
+///     req.respond(None)
+/// }
+///
+/// let app: axum::Router = axum::Router::new()
+///     .route("/bid", axum::routing::post(bid))
+///     .layer(axum::Extension(openrtb2::http::BodyLimit(1024 * 1024)));
+/// ```
+///
+/// [`BodyLimit`]: ./struct.BodyLimit.html
+/// [`DEFAULT_BODY_LIMIT`]: ./constant.DEFAULT_BODY_LIMIT.html
+#[derive(Debug, PartialEq, Clone)]
+pub struct OpenRtbRequest {
+    /// The bid request.
+    pub request: crate::BidRequest<'static>,
+    /// Value of the `x-openrtb-version` header, if present.
+    pub version: Option<String>,
+    /// Encoding accepted by the exchange for the response.
+    pub accept: super::Encoding,
+}
+
+impl OpenRtbRequest {
+    /// Builds the response to this request, compressed if the exchange accepts it.
+    pub fn respond<'a>(&self, response: Option<crate::BidResponse<'a>>) -> OpenRtbResponse<'a> {
+        OpenRtbResponse {
+            response,
+            encoding: self.accept,
+        }
+    }
+}
+
+/// Limit, in bytes, of the decompressed body of the requests extracted as [`OpenRtbRequest`], set
+/// as a request extension.
+///
+/// [`OpenRtbRequest`]: ./struct.OpenRtbRequest.html
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BodyLimit(pub usize);
+
+impl<S> axum::extract::FromRequest<S> for OpenRtbRequest
+where
+    S: Send + Sync,
+{
+    type Rejection = super::Error;
+
+    async fn from_request(req: axum::extract::Request, state: &S) -> Result<Self, Self::Rejection> {
+        let headers = req.headers().clone();
+        let limit = req
+            .extensions()
+            .get::<BodyLimit>()
+            .map_or(super::DEFAULT_BODY_LIMIT, |limit| limit.0);
+        let body = <axum::body::Bytes as axum::extract::FromRequest<S>>::from_request(req, state)
+            .await
+            .map_err(|e| match e.status() {
+                axum::http::StatusCode::PAYLOAD_TOO_LARGE => super::Error::BodyTooLarge,
+                _ => std::io::Error::other(e.body_text()).into(),
+            })?;
+
+        let version = headers
+            .get(super::VERSION_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(ToOwned::to_owned);
+        let accept = super::Encoding::from_accept_encoding(
+            headers
+                .get(axum::http::header::ACCEPT_ENCODING)
+                .and_then(|v| v.to_str().ok()),
+        );
+        let request = super::decode_request_with_limit(&headers, &body, limit)?;
+
+        Ok(Self {
+            request,
+            version,
+            accept,
+        })
+    }
+}
+
+/// Axum response for a bid request: HTTP 204 when `response` is None, the JSON `BidResponse`
+/// otherwise.
+#[derive(Debug, PartialEq, Clone)]
+pub struct OpenRtbResponse<'a> {
+    /// The bid response, None for no-bid.
+    pub response: Option<crate::BidResponse<'a>>,
+    /// Encoding of the response body.
+    pub encoding: super::Encoding,
+}
+
+impl axum::response::IntoResponse for OpenRtbResponse<'_> {
+    fn into_response(self) -> axum::response::Response {
+        match super::encode_response(self.response.as_ref(), self.encoding) {
+            Ok(res) => res.map(axum::body::Body::from),
+            Err(e) => e.into_response(),
+        }
+    }
+}
+
+impl axum::response::IntoResponse for super::Error {
+    fn into_response(self) -> axum::response::Response {
+        let status = match self {
+            Self::ContentType(_) | Self::ContentEncoding(_) => axum::http::StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            Self::Json(_) | Self::Io(_) => axum::http::StatusCode::BAD_REQUEST,
+            Self::BodyTooLarge => axum::http::StatusCode::PAYLOAD_TOO_LARGE,
+            _ => axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
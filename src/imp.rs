@@ -10,6 +10,8 @@
 /// publisher can choose one such type which is the typical case or mix them at their discretion.
 /// However, any given bid for the impression must conform to one of the offered types.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Imp<'a> {
     /// string; required
     /// A unique identifier for this impression within the context of the bid request (typically,
@@ -69,6 +71,8 @@ pub struct Imp<'a> {
     /// integer; default 0
     /// 1 = the ad is interstitial or full screen, 0 = not interstitial.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub instl: Option<json_ext::Flag>,
 
     /// string
@@ -80,6 +84,7 @@ pub struct Imp<'a> {
     /// float; default 0
     /// Minimum bid for this impression expressed in CPM.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_price))]
     pub bidfloor: Option<f64>,
 
     /// string; default “USD”
@@ -93,6 +98,8 @@ pub struct Imp<'a> {
     /// embedded, 1 = native. Note that the Safari View Controller in iOS 9.x devices is considered
     /// a native browser for purposes of this attribute.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub clickbrowser: Option<json_ext::Flag>,
 
     /// integer
@@ -100,6 +107,8 @@ pub struct Imp<'a> {
     /// where 0 = non-secure, 1 = secure. If omitted, the secure state is unknown, but non-secure
     /// HTTP support can be assumed.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub secure: Option<json_ext::Flag>,
 
     /// string array
@@ -116,6 +125,8 @@ pub struct Imp<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
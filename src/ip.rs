@@ -0,0 +1,246 @@
+//! IP address helpers.
+//!
+//! `Device.ip` and `Device.ipv6` are strings on the wire. The typed accessors of [`Device`] parse
+//! them, [`TypedDevice`] deserializes a whole device with typed addresses, and the `serde` helpers
+//! of this module let other structs hold them as typed fields:
+//!
+//! ```
+//! #[derive(serde::Deserialize)]
+//! struct Addresses {
+//!     #[serde(default, with = "openrtb2::ip::option_ipv4")]
+//!     ip: Option<std::net::Ipv4Addr>,
+//!     #[serde(default, with = "openrtb2::ip::option_ipv6")]
+//!     ipv6: Option<std::net::Ipv6Addr>,
+//! }
+//!
+//! let addresses: Addresses = serde_json::from_str(r#"{"ip":"192.168.1.23","ipv6":"2001:db8::1"}"#)?;
+//! assert_eq!(addresses.ip, Some(std::net::Ipv4Addr::new(192, 168, 1, 23)));
+//! assert!(serde_json::from_str::<Addresses>(r#"{"ip":"2001:db8::1"}"#).is_err());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`Device`]: ../struct.Device.html
+//! [`TypedDevice`]: ./struct.TypedDevice.html
+
+/// Number of leading bits kept when anonymizing an IPv6 address.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum Ipv6Prefix {
+    /// Keep the routing prefix of the site.
+    #[default]
+    Bits48,
+    /// Keep the subnet.
+    Bits64,
+}
+
+/// Zeroes the last octet of `ip`.
+///
+/// ```
+/// # use std::net::Ipv4Addr;
+/// assert_eq!(openrtb2::ip::truncate_ipv4(Ipv4Addr::new(1, 2, 3, 4)), Ipv4Addr::new(1, 2, 3, 0));
+/// ```
+pub fn truncate_ipv4(ip: std::net::Ipv4Addr) -> std::net::Ipv4Addr {
+    let [a, b, c, _] = ip.octets();
+    std::net::Ipv4Addr::new(a, b, c, 0)
+}
+
+/// Zeroes the bits of `ip` following `prefix`.
+///
+/// ```
+/// # use std::net::Ipv6Addr;
+/// # use openrtb2::ip::{truncate_ipv6, Ipv6Prefix};
+/// let ip: Ipv6Addr = "2001:db8:85a3:8d3:1319:8a2e:370:7348".parse().unwrap();
+/// assert_eq!(truncate_ipv6(ip, Ipv6Prefix::Bits48).to_string(), "2001:db8:85a3::");
+/// assert_eq!(truncate_ipv6(ip, Ipv6Prefix::Bits64).to_string(), "2001:db8:85a3:8d3::");
+/// ```
+pub fn truncate_ipv6(ip: std::net::Ipv6Addr, prefix: Ipv6Prefix) -> std::net::Ipv6Addr {
+    let [a, b, c, d, ..] = ip.segments();
+    match prefix {
+        Ipv6Prefix::Bits48 => std::net::Ipv6Addr::new(a, b, c, 0, 0, 0, 0, 0),
+        Ipv6Prefix::Bits64 => std::net::Ipv6Addr::new(a, b, c, d, 0, 0, 0, 0),
+    }
+}
+
+/// Parses an address, tolerating surrounding whitespace.
+pub(crate) fn parse(ip: &str) -> Option<std::net::IpAddr> {
+    ip.trim().parse().ok()
+}
+
+/// A `Device` whose `ip` and `ipv6` are deserialized as typed addresses: a value which is not an
+/// address of the expected family fails deserialization instead of being kept as a string.
+///
+/// ```
+/// # use openrtb2::ip::TypedDevice;
+/// let device: TypedDevice = serde_json::from_str(r#"{"ip":"192.168.1.23","ua":"Mozilla/5.0"}"#)?;
+/// assert_eq!(device.ip, Some(std::net::Ipv4Addr::new(192, 168, 1, 23)));
+/// assert_eq!(device.device.ua.as_deref(), Some("Mozilla/5.0"));
+/// assert!(serde_json::from_str::<TypedDevice>(r#"{"ip":"2001:db8::1"}"#).is_err());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct TypedDevice<'a> {
+    /// The device, without `ip` and `ipv6`.
+    pub device: crate::Device<'a>,
+    /// `ip`, IPv4-mapped IPv6 addresses converted.
+    pub ip: Option<std::net::Ipv4Addr>,
+    /// `ipv6`.
+    pub ipv6: Option<std::net::Ipv6Addr>,
+}
+
+impl<'a> From<TypedDevice<'a>> for crate::Device<'a> {
+    fn from(typed: TypedDevice<'a>) -> Self {
+        Self {
+            ip: typed.ip.map(|ip| ip.to_string().into()),
+            ipv6: typed.ipv6.map(|ip| ip.to_string().into()),
+            ..typed.device
+        }
+    }
+}
+
+impl serde::Serialize for TypedDevice<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::Device::from(self.clone()).serialize(serializer)
+    }
+}
+
+impl<'de: 'a, 'a> serde::Deserialize<'de> for TypedDevice<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut device = crate::Device::deserialize(deserializer)?;
+        let ip = to_ipv4(parse_str(device.ip.take().as_deref())?)?;
+        let ipv6 = to_ipv6(parse_str(device.ipv6.take().as_deref())?)?;
+        Ok(Self { device, ip, ipv6 })
+    }
+}
+
+/// Deserializes an optional address string, tolerating surrounding whitespace.
+fn deserialize_str<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<std::net::IpAddr>, D::Error> {
+    let ip: Option<std::borrow::Cow<str>> = serde::Deserialize::deserialize(deserializer)?;
+    parse_str(ip.as_deref())
+}
+
+/// Parses an optional address string, tolerating surrounding whitespace. Empty strings are read as
+/// None.
+fn parse_str<E: serde::de::Error>(ip: Option<&str>) -> Result<Option<std::net::IpAddr>, E> {
+    match ip.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(ip) => ip
+            .parse()
+            .map(Some)
+            .map_err(|_| E::custom(format!("invalid IP address: {:?}", ip))),
+    }
+}
+
+/// Converts an address to IPv4, failing for IPv6 addresses which are not IPv4-mapped.
+fn to_ipv4<E: serde::de::Error>(ip: Option<std::net::IpAddr>) -> Result<Option<std::net::Ipv4Addr>, E> {
+    match ip {
+        None => Ok(None),
+        Some(std::net::IpAddr::V4(ip)) => Ok(Some(ip)),
+        Some(std::net::IpAddr::V6(ip)) => ip
+            .to_ipv4_mapped()
+            .map(Some)
+            .ok_or_else(|| E::custom(format!("not an IPv4 address: {}", ip))),
+    }
+}
+
+/// Converts an address to IPv6, failing for IPv4 addresses.
+fn to_ipv6<E: serde::de::Error>(ip: Option<std::net::IpAddr>) -> Result<Option<std::net::Ipv6Addr>, E> {
+    match ip {
+        None => Ok(None),
+        Some(std::net::IpAddr::V6(ip)) => Ok(Some(ip)),
+        Some(std::net::IpAddr::V4(ip)) => Err(E::custom(format!("not an IPv6 address: {}", ip))),
+    }
+}
+
+/// Serializes an `Option<Ipv4Addr>` as a string, for `#[serde(with = "openrtb2::ip::option_ipv4")]`.
+pub mod option_ipv4 {
+    /// Serializes the address as a string.
+    pub fn serialize<S: serde::Serializer>(ip: &Option<std::net::Ipv4Addr>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(ip, serializer)
+    }
+
+    /// Deserializes a string holding an IPv4 or IPv4-mapped IPv6 address. Empty strings are read
+    /// as None.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<std::net::Ipv4Addr>, D::Error> {
+        super::to_ipv4(super::deserialize_str(deserializer)?)
+    }
+}
+
+/// Serializes an `Option<Ipv6Addr>` as a string, for `#[serde(with = "openrtb2::ip::option_ipv6")]`.
+pub mod option_ipv6 {
+    /// Serializes the address as a string.
+    pub fn serialize<S: serde::Serializer>(ip: &Option<std::net::Ipv6Addr>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(ip, serializer)
+    }
+
+    /// Deserializes a string holding an IPv6 address. Empty strings are read as None.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<std::net::Ipv6Addr>, D::Error> {
+        super::to_ipv6(super::deserialize_str(deserializer)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Addresses {
+        #[serde(default, with = "option_ipv4", skip_serializing_if = "Option::is_none")]
+        ip: Option<std::net::Ipv4Addr>,
+        #[serde(default, with = "option_ipv6", skip_serializing_if = "Option::is_none")]
+        ipv6: Option<std::net::Ipv6Addr>,
+    }
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        let json = r#"{"ip":"192.168.1.23","ipv6":"2001:db8::1"}"#;
+        let o1 = Addresses {
+            ip: Some(std::net::Ipv4Addr::new(192, 168, 1, 23)),
+            ipv6: Some("2001:db8::1".parse().unwrap()),
+        };
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<Addresses>(json)?);
+
+        assert_eq!(
+            serde_json::from_str::<Addresses>("{}")?,
+            Addresses { ip: None, ipv6: None }
+        );
+        assert_eq!(
+            serde_json::from_str::<Addresses>(r#"{"ip":" ::ffff:1.2.3.4 ","ipv6":""}"#)?,
+            Addresses {
+                ip: Some(std::net::Ipv4Addr::new(1, 2, 3, 4)),
+                ipv6: None
+            }
+        );
+        assert!(serde_json::from_str::<Addresses>(r#"{"ipv6":"1.2.3.4"}"#).is_err());
+        assert!(serde_json::from_str::<Addresses>(r#"{"ip":"localhost"}"#).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_device() -> serde_json::Result<()> {
+        let json = r#"{"ua":"ua","ip":"192.168.1.23","ipv6":"2001:db8::1","make":"Apple"}"#;
+        let typed: TypedDevice = serde_json::from_str(json)?;
+        assert_eq!(typed.ip, Some(std::net::Ipv4Addr::new(192, 168, 1, 23)));
+        assert_eq!(typed.ipv6, Some("2001:db8::1".parse().unwrap()));
+        assert_eq!(
+            (typed.device.ip.as_ref(), typed.device.make.as_deref()),
+            (None, Some("Apple"))
+        );
+        assert_eq!(serde_json::to_string(&typed)?, json);
+        assert_eq!(crate::Device::from(typed), serde_json::from_str::<crate::Device>(json)?);
+
+        let typed: TypedDevice = serde_json::from_str(r#"{"ip":" ::ffff:1.2.3.4 ","ipv6":""}"#)?;
+        assert_eq!(
+            (typed.ip, typed.ipv6),
+            (Some(std::net::Ipv4Addr::new(1, 2, 3, 4)), None)
+        );
+        assert!(serde_json::from_str::<TypedDevice>(r#"{"ipv6":"1.2.3.4"}"#).is_err());
+        assert!(serde_json::from_str::<TypedDevice>(r#"{"ip":"localhost"}"#).is_err());
+
+        Ok(())
+    }
+}
@@ -3,6 +3,8 @@
 /// The following table lists the services and/or vendors used for resolving IP addresses to
 /// geolocations.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum IpLocationService {
     /// ip2location
@@ -3,6 +3,8 @@
 /// The following table lists the media ratings used in describing content based on the IQG 2.1
 /// categorization. Refer to www.iab.com/guidelines/digital-video-suite for more information.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum IqgMediaRating {
     /// All Audiences
@@ -0,0 +1,526 @@
+//! ISO country, region, language and currency codes.
+//!
+//! OpenRTB stores these codes as plain strings:
+//!
+//! | Field                                                                       | Standard           |
+//! |-----------------------------------------------------------------------------|--------------------|
+//! | `Geo.country`                                                               | ISO-3166-1 alpha-3 |
+//! | `Geo.region`                                                                | ISO-3166-2         |
+//! | `Device.language`, `Content.language`, `Bid.language`, `BidRequest.wlang`   | ISO-639-1          |
+//! | `BidRequest.cur`, `Imp.bidfloorcur`, `Deal.bidfloorcur`, `BidResponse.cur`  | ISO-4217           |
+//!
+//! [`Country`], [`Language`] and [`Currency`] are validated against embedded code tables.
+//! [`validate`] and [`validate_response`] report the fields of a request or response which are not
+//! in the canonical form, and [`normalize`] and [`normalize_response`] rewrite the ones they can,
+//! e.g. alpha-2 countries often sent instead of alpha-3.
+//!
+//! No ISO-3166-2 subdivision table is embedded: regions are only checked for their shape, one to
+//! three letters or digits after an optional country prefix, so that a well-formed code which does
+//! not exist, e.g. `ZZ`, is not reported.
+//!
+//! [`Country`]: ./struct.Country.html
+//! [`Language`]: ./struct.Language.html
+//! [`Currency`]: ./struct.Currency.html
+//! [`validate`]: ./fn.validate.html
+//! [`validate_response`]: ./fn.validate_response.html
+//! [`normalize`]: ./fn.normalize.html
+//! [`normalize_response`]: ./fn.normalize_response.html
+
+mod tables;
+
+/// An ISO-3166-1 country.
+///
+/// ```
+/// # use openrtb2::iso::Country;
+/// let country: Country = "us".parse()?;
+/// assert_eq!(country.alpha3(), "USA");
+/// assert_eq!(country.alpha2(), "US");
+/// assert_eq!(country, Country::from_alpha3("USA").unwrap());
+/// assert!(Country::parse("XYZ").is_err());
+/// # Ok::<(), openrtb2::iso::Error>(())
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Country(u8);
+
+/// An ISO-639-1 language.
+///
+/// ```
+/// # use openrtb2::iso::Language;
+/// assert_eq!(Language::parse("en-US")?.code(), "en");
+/// assert!(Language::parse("xx").is_err());
+/// # Ok::<(), openrtb2::iso::Error>(())
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Language(u8);
+
+/// An ISO-4217 currency.
+///
+/// ```
+/// # use openrtb2::iso::Currency;
+/// assert_eq!(Currency::parse("eur")?.code(), "EUR");
+/// assert_eq!(Currency::default().code(), "USD");
+/// # Ok::<(), openrtb2::iso::Error>(())
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Currency(u8);
+
+impl Country {
+    /// Returns the country of an uppercase alpha-2 code.
+    pub fn from_alpha2(code: &str) -> Option<Self> {
+        tables::COUNTRIES
+            .binary_search_by(|(alpha2, _)| (*alpha2).cmp(code))
+            .ok()
+            .map(|i| Self(i as u8))
+    }
+
+    /// Returns the country of an uppercase alpha-3 code.
+    pub fn from_alpha3(code: &str) -> Option<Self> {
+        tables::COUNTRIES
+            .iter()
+            .position(|(_, alpha3)| *alpha3 == code)
+            .map(|i| Self(i as u8))
+    }
+
+    /// Parses an alpha-2 or alpha-3 code, case-insensitively. `UK` is read as `GB`.
+    pub fn parse(code: &str) -> Result<Self, Error> {
+        let upper = code.trim().to_ascii_uppercase();
+        let country = match upper.len() {
+            2 if upper == "UK" => Self::from_alpha2("GB"),
+            2 => Self::from_alpha2(&upper),
+            3 => Self::from_alpha3(&upper),
+            _ => None,
+        };
+        country.ok_or_else(|| Error::new(Kind::Country, code))
+    }
+
+    /// Returns the alpha-2 code.
+    pub fn alpha2(&self) -> &'static str {
+        tables::COUNTRIES[self.0 as usize].0
+    }
+
+    /// Returns the alpha-3 code, used by OpenRTB.
+    pub fn alpha3(&self) -> &'static str {
+        tables::COUNTRIES[self.0 as usize].1
+    }
+}
+
+impl Language {
+    /// Parses a code, case-insensitively. The region or script of a language tag (`en-US`,
+    /// `zh_Hant`) is ignored.
+    pub fn parse(code: &str) -> Result<Self, Error> {
+        let primary = code
+            .trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        tables::LANGUAGES
+            .binary_search(&primary.as_str())
+            .map(|i| Self(i as u8))
+            .map_err(|_| Error::new(Kind::Language, code))
+    }
+
+    /// Returns the 2-letter code.
+    pub fn code(&self) -> &'static str {
+        tables::LANGUAGES[self.0 as usize]
+    }
+}
+
+impl Currency {
+    /// Parses a code, case-insensitively.
+    pub fn parse(code: &str) -> Result<Self, Error> {
+        tables::CURRENCIES
+            .binary_search(&code.trim().to_ascii_uppercase().as_str())
+            .map(|i| Self(i as u8))
+            .map_err(|_| Error::new(Kind::Currency, code))
+    }
+
+    /// Returns the 3-letter code.
+    pub fn code(&self) -> &'static str {
+        tables::CURRENCIES[self.0 as usize]
+    }
+}
+
+impl Default for Currency {
+    /// USD, the default currency of OpenRTB.
+    fn default() -> Self {
+        Self::parse("USD").expect("USD is in the table")
+    }
+}
+
+macro_rules! code_impls {
+    ($ty:ident, $code:ident) => {
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.$code())
+            }
+        }
+
+        impl std::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_tuple(stringify!($ty)).field(&self.$code()).finish()
+            }
+        }
+
+        impl std::str::FromStr for $ty {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::parse(s)
+            }
+        }
+
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.$code())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let code: std::borrow::Cow<str> = serde::Deserialize::deserialize(deserializer)?;
+                Self::parse(&code).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+code_impls!(Country, alpha3);
+code_impls!(Language, code);
+code_impls!(Currency, code);
+
+/// Normalizes an ISO-3166-2 region: uppercase, without the country prefix (`US-CA` is `CA`).
+/// Only the shape of the code is checked, not that the subdivision exists.
+///
+/// ```
+/// # use openrtb2::iso::normalize_region;
+/// assert_eq!(normalize_region("us-ca")?, "CA");
+/// assert!(normalize_region("California").is_err());
+/// # Ok::<(), openrtb2::iso::Error>(())
+/// ```
+pub fn normalize_region(region: &str) -> Result<String, Error> {
+    let upper = region.trim().to_ascii_uppercase();
+    let subdivision = match upper.split_once('-') {
+        Some((country, subdivision)) if Country::from_alpha2(country).is_some() => subdivision,
+        Some(_) => return Err(Error::new(Kind::Region, region)),
+        None => upper.as_str(),
+    };
+    if (1..=3).contains(&subdivision.len()) && subdivision.chars().all(|c| c.is_ascii_alphanumeric()) {
+        Ok(subdivision.to_owned())
+    } else {
+        Err(Error::new(Kind::Region, region))
+    }
+}
+
+/// A field of a request or response which is not in the canonical form.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Issue {
+    /// Path of the field, e.g. `imp[0].bidfloorcur`.
+    pub path: String,
+    /// Why the value is invalid.
+    pub error: Error,
+}
+
+/// Returns the code fields of `req` which are not in their canonical form: alpha-3 countries,
+/// uppercase regions without country prefix, lowercase 2-letter languages, and uppercase
+/// currencies from the code tables.
+///
+/// ```
+/// let req: openrtb2::BidRequest = serde_json::from_str(
+///     r#"{"id":"1","imp":[{"id":"1","bidfloorcur":"usd"}],"device":{"geo":{"country":"US"}}}"#,
+/// )?;
+/// let paths: Vec<_> = openrtb2::iso::validate(&req).into_iter().map(|issue| issue.path).collect();
+/// assert_eq!(paths, vec!["imp[0].bidfloorcur", "device.geo.country"]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn validate(req: &crate::BidRequest) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    walk_request(req, &mut |path, kind, value| check(&mut issues, path, kind, value));
+    issues
+}
+
+/// Returns the code fields of `res`, `cur` and `seatbid[].bid[].language`, which are not in their
+/// canonical form.
+///
+/// ```
+/// let res: openrtb2::BidResponse = serde_json::from_str(
+///     r#"{"id":"1","cur":"usd","seatbid":[{"bid":[{"id":"1","impid":"1","price":1,"language":"en"}]}]}"#,
+/// )?;
+/// let paths: Vec<_> = openrtb2::iso::validate_response(&res).into_iter().map(|issue| issue.path).collect();
+/// assert_eq!(paths, vec!["cur"]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn validate_response(res: &crate::BidResponse) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    walk_response(res, &mut |path, kind, value| check(&mut issues, path, kind, value));
+    issues
+}
+
+fn check(issues: &mut Vec<Issue>, path: String, kind: Kind, value: &str) {
+    let result = kind.normalize(value).and_then(|canonical| {
+        if canonical == value {
+            Ok(())
+        } else {
+            Err(Error::new(kind, value))
+        }
+    });
+    if let Err(error) = result {
+        issues.push(Issue { path, error });
+    }
+}
+
+/// Rewrites the code fields of `req` in their canonical form and returns the fields which could
+/// not be normalized; they are left untouched.
+///
+/// ```
+/// let mut req: openrtb2::BidRequest = serde_json::from_str(
+///     r#"{"id":"1","imp":[],"cur":["usd"],"wlang":["EN-us","klingon"],"device":{"geo":{"country":"us","region":"US-ca"}}}"#,
+/// )?;
+/// let issues = openrtb2::iso::normalize(&mut req);
+/// assert_eq!(issues.len(), 1);
+/// assert_eq!(issues[0].path, "wlang[1]");
+/// let geo = req.device.unwrap().geo.unwrap();
+/// assert_eq!((geo.country.as_deref(), geo.region.as_deref()), (Some("USA"), Some("CA")));
+/// assert_eq!(req.cur.unwrap(), vec!["USD"]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn normalize(req: &mut crate::BidRequest) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    walk_request_mut(req, &mut |path, kind, value| match kind.normalize(value) {
+        Ok(canonical) if canonical != **value => *value = canonical.into(),
+        Ok(_) => {}
+        Err(error) => issues.push(Issue { path, error }),
+    });
+    issues
+}
+
+/// Rewrites `cur` and `seatbid[].bid[].language` of `res` in their canonical form and returns
+/// the fields which could not be normalized.
+pub fn normalize_response(res: &mut crate::BidResponse) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    walk_response_mut(res, &mut |path, kind, value| match kind.normalize(value) {
+        Ok(canonical) if canonical != **value => *value = canonical.into(),
+        Ok(_) => {}
+        Err(error) => issues.push(Issue { path, error }),
+    });
+    issues
+}
+
+/// Defines walkers calling `f` with the path, kind and value of every code field of a request or
+/// response, through shared or mutable references.
+macro_rules! walkers {
+    ($visitor:ident, $walk_request:ident, $walk_geo:ident, $walk_response:ident $(, $mut:tt)?) => {
+        type $visitor<'f, 'a> = dyn FnMut(String, Kind, &$($mut)? std::borrow::Cow<'a, str>) + 'f;
+
+        fn $walk_request<'a>(req: &$($mut)? crate::BidRequest<'a>, f: &mut $visitor<'_, 'a>) {
+            for (i, imp) in (&$($mut)? req.imp).into_iter().enumerate() {
+                if let Some(cur) = &$($mut)? imp.bidfloorcur {
+                    f(format!("imp[{}].bidfloorcur", i), Kind::Currency, cur);
+                }
+                if let Some(pmp) = &$($mut)? imp.pmp {
+                    for (j, deal) in (&$($mut)? pmp.deals).into_iter().flatten().enumerate() {
+                        if let Some(cur) = &$($mut)? deal.bidfloorcur {
+                            f(format!("imp[{}].pmp.deals[{}].bidfloorcur", i, j), Kind::Currency, cur);
+                        }
+                    }
+                }
+            }
+            let content = match &$($mut)? req.channel {
+                Some(crate::DistributionChannel::Site(site)) => Some(("site", &$($mut)? site.content)),
+                Some(crate::DistributionChannel::App(app)) => Some(("app", &$($mut)? app.content)),
+                None => None,
+            };
+            if let Some((channel, Some(content))) = content {
+                if let Some(language) = &$($mut)? content.language {
+                    f(format!("{}.content.language", channel), Kind::Language, language);
+                }
+            }
+            if let Some(device) = &$($mut)? req.device {
+                if let Some(language) = &$($mut)? device.language {
+                    f("device.language".into(), Kind::Language, language);
+                }
+                if let Some(geo) = &$($mut)? device.geo {
+                    $walk_geo("device.geo", geo, f);
+                }
+            }
+            if let Some(user) = &$($mut)? req.user {
+                if let Some(geo) = &$($mut)? user.geo {
+                    $walk_geo("user.geo", geo, f);
+                }
+            }
+            for (i, cur) in (&$($mut)? req.cur).into_iter().flatten().enumerate() {
+                f(format!("cur[{}]", i), Kind::Currency, cur);
+            }
+            for (i, language) in (&$($mut)? req.wlang).into_iter().flatten().enumerate() {
+                f(format!("wlang[{}]", i), Kind::Language, language);
+            }
+        }
+
+        fn $walk_geo<'a>(path: &str, geo: &$($mut)? crate::Geo<'a>, f: &mut $visitor<'_, 'a>) {
+            if let Some(country) = &$($mut)? geo.country {
+                f(format!("{}.country", path), Kind::Country, country);
+            }
+            if let Some(region) = &$($mut)? geo.region {
+                f(format!("{}.region", path), Kind::Region, region);
+            }
+        }
+
+        fn $walk_response<'a>(res: &$($mut)? crate::BidResponse<'a>, f: &mut $visitor<'_, 'a>) {
+            if let Some(cur) = &$($mut)? res.cur {
+                f("cur".into(), Kind::Currency, cur);
+            }
+            for (i, seatbid) in (&$($mut)? res.seatbid).into_iter().flatten().enumerate() {
+                for (j, bid) in (&$($mut)? seatbid.bid).into_iter().enumerate() {
+                    if let Some(language) = &$($mut)? bid.language {
+                        f(format!("seatbid[{}].bid[{}].language", i, j), Kind::Language, language);
+                    }
+                }
+            }
+        }
+    };
+}
+
+walkers!(Visitor, walk_request, walk_geo, walk_response);
+walkers!(VisitorMut, walk_request_mut, walk_geo_mut, walk_response_mut, mut);
+
+/// The kind of code.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Kind {
+    /// ISO-3166-1 country.
+    Country,
+    /// ISO-3166-2 region.
+    Region,
+    /// ISO-639-1 language.
+    Language,
+    /// ISO-4217 currency.
+    Currency,
+}
+
+impl Kind {
+    fn normalize(&self, value: &str) -> Result<String, Error> {
+        match self {
+            Self::Country => Country::parse(value).map(|c| c.alpha3().to_owned()),
+            Self::Region => normalize_region(value),
+            Self::Language => Language::parse(value).map(|l| l.code().to_owned()),
+            Self::Currency => Currency::parse(value).map(|c| c.code().to_owned()),
+        }
+    }
+}
+
+/// The error returned for an invalid or non-canonical code.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Error {
+    /// The kind of code expected.
+    pub kind: Kind,
+    /// The invalid value.
+    pub value: String,
+}
+
+impl Error {
+    fn new(kind: Kind, value: &str) -> Self {
+        Self {
+            kind,
+            value: value.to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let standard = match self.kind {
+            Kind::Country => "ISO-3166-1 alpha-3 country",
+            Kind::Region => "ISO-3166-2 region",
+            Kind::Language => "ISO-639-1 language",
+            Kind::Currency => "ISO-4217 currency",
+        };
+        write!(f, "invalid {} code: {:?}", standard, self.value)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tables() {
+        for (i, (alpha2, alpha3)) in tables::COUNTRIES.iter().enumerate() {
+            assert_eq!(Country::from_alpha2(alpha2), Some(Country(i as u8)));
+            assert_eq!(Country::from_alpha3(alpha3), Some(Country(i as u8)));
+        }
+        assert!(tables::COUNTRIES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(tables::LANGUAGES.windows(2).all(|w| w[0] < w[1]));
+        assert!(tables::CURRENCIES.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        let json = r#"["USA","en","JPY"]"#;
+        let o1: (Country, Language, Currency) = serde_json::from_str(r#"["us","EN","jpy"]"#)?;
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(format!("{:?}", o1.0), r#"Country("USA")"#);
+        assert!(serde_json::from_str::<Country>(r#""XX""#).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Country::parse(" gbr ").unwrap().alpha2(), "GB");
+        assert_eq!(Country::parse("UK").unwrap().alpha3(), "GBR");
+        assert_eq!(
+            Country::parse("America").unwrap_err(),
+            Error {
+                kind: Kind::Country,
+                value: "America".into()
+            }
+        );
+        assert_eq!(Language::parse("zh_Hant").unwrap().code(), "zh");
+        assert!(Language::parse("").is_err());
+        assert!(Currency::parse("ZZZ").is_err());
+        assert_eq!(normalize_region("NY").unwrap(), "NY");
+        assert!(normalize_region("XX-NY").is_err());
+        assert!(normalize_region("").is_err());
+    }
+
+    #[test]
+    fn normalize_request() -> serde_json::Result<()> {
+        let mut req: crate::BidRequest = serde_json::from_str(
+            r#"{
+                "id": "1",
+                "imp": [{"id": "1", "bidfloorcur": "eur", "pmp": {"deals": [{"id": "1", "bidfloorcur": "XXY"}]}}],
+                "site": {"content": {"language": "FR"}},
+                "device": {"language": "de-AT"},
+                "user": {"geo": {"country": "JPN", "region": "13"}}
+            }"#,
+        )?;
+        assert_eq!(validate(&req).len(), 4);
+        let issues = normalize(&mut req);
+        assert_eq!(
+            issues,
+            vec![Issue {
+                path: "imp[0].pmp.deals[0].bidfloorcur".into(),
+                error: Error {
+                    kind: Kind::Currency,
+                    value: "XXY".into()
+                }
+            }]
+        );
+        assert_eq!(validate(&req), issues);
+        assert_eq!(req.imp[0].bidfloorcur.as_deref(), Some("EUR"));
+        assert_eq!(req.device.unwrap().language.as_deref(), Some("de"));
+
+        let mut res: crate::BidResponse = serde_json::from_str(
+            r#"{"id":"1","cur":"usd","seatbid":[{"bid":[{"id":"1","impid":"1","price":1,"language":"EN"}]}]}"#,
+        )?;
+        let paths: Vec<_> = validate_response(&res).into_iter().map(|issue| issue.path).collect();
+        assert_eq!(paths, vec!["cur", "seatbid[0].bid[0].language"]);
+        assert!(normalize_response(&mut res).is_empty());
+        assert!(validate_response(&res).is_empty());
+        assert_eq!(res.cur.as_deref(), Some("USD"));
+        assert_eq!(res.seatbid.unwrap()[0].bid[0].language.as_deref(), Some("en"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,660 @@
+//! OpenRTB 2.2–2.4 ingestion.
+//!
+//! Exchanges running older versions of the specification send shapes that 2.5 deprecated or
+//! removed. [`from_slice`] accepts these payloads given the [`Version`] announced in the
+//! `x-openrtb-version` header, and [`upgrade`] normalizes them to idiomatic 2.5:
+//!
+//! | Legacy shape                                   | 2.5                                         |
+//! |------------------------------------------------|---------------------------------------------|
+//! | `Video.protocol` (single, 2.2)                 | merged into `Video.protocols`               |
+//! | `Video` without `mimes`                        | empty `mimes`                               |
+//! | `Banner.w/h`                                   | `Banner.format`, the min/max fields kept    |
+//! | `Content.videoquality`                         | `Content.prodq`                             |
+//!
+//! Fields which 2.5 still carries, e.g. the hashed `Device.didsha1` or `Device.macmd5`, are kept
+//! as they are. Payloads announced as 2.5, or without a version, are left untouched.
+//!
+//! The other way around, [`downgrade`] prepares a request for a bidder speaking an older version
+//! and reports the lossy [`Step`]s, and [`to_vec`] serializes it in the shapes of that version:
+//!
+//! | 2.5                                                | Before | Legacy shape                          |
+//! |----------------------------------------------------|--------|---------------------------------------|
+//! | `BidRequest.bseat`, `Imp.clickbrowser`, `Banner.vcm`, `Video.playbackend`, `Device.mccmnc`, `Device.geofetch` | 2.5 | dropped |
+//! | `BidRequest.source`, `Imp.metric`, `Video.placement` | 2.5  | moved into the `ext` of their parent  |
+//! | `Banner.format`                                    | 2.4    | `Banner.w/h`, `wmin/hmin`, `wmax/hmax` |
+//! | `Imp.audio`, `Imp.exp`                             | 2.4    | dropped                               |
+//! | `Content.prodq`                                    | 2.4    | `Content.videoquality`                |
+//! | `Imp.native`                                       | 2.3    | dropped                               |
+//! | `Video.protocols`                                  | 2.3    | first protocol in `Video.protocol`    |
+//!
+//! Responses of legacy bidders are read with [`response_from_slice`], which accepts native markup
+//! returned as a JSON object in `Bid.adm`, and [`upgrade_response`], which lifts `burl` and `lurl`
+//! out of `Bid.ext`.
+//!
+//! ```
+//! # use openrtb2::legacy::{self, Version};
+//! let json = br#"{"id":"1","imp":[{"id":"1","video":{"protocol":2,"w":640,"h":480}}]}"#;
+//! let req = legacy::from_slice(json, Version::parse("2.2")?)?;
+//! let video = req.imp[0].video.as_ref().unwrap();
+//! assert!(video.mimes.is_empty());
+//! assert_eq!(video.protocols, Some(vec![openrtb2::Protocol::Vast2]));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`from_slice`]: ./fn.from_slice.html
+//! [`Version`]: ./enum.Version.html
+//! [`upgrade`]: ./fn.upgrade.html
+//! [`downgrade`]: ./fn.downgrade.html
+//! [`Step`]: ./struct.Step.html
+//! [`to_vec`]: ./fn.to_vec.html
+//! [`response_from_slice`]: ./fn.response_from_slice.html
+//! [`upgrade_response`]: ./fn.upgrade_response.html
+
+/// A version of OpenRTB 2.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Version {
+    /// OpenRTB 2.2
+    V2_2,
+    /// OpenRTB 2.3
+    V2_3,
+    /// OpenRTB 2.4
+    V2_4,
+    /// OpenRTB 2.5
+    V2_5,
+}
+
+impl Version {
+    /// Parses a `x-openrtb-version` value such as `2.3` or `2.3.1`.
+    ///
+    /// ```
+    /// # use openrtb2::legacy::Version;
+    /// assert_eq!(Version::parse("2.3.1"), Ok(Version::V2_3));
+    /// assert!(Version::parse("3.0").is_err());
+    /// ```
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let mut parts = value.trim().splitn(3, '.');
+        let version = match (parts.next(), parts.next()) {
+            (Some("2"), Some("2")) => Self::V2_2,
+            (Some("2"), Some("3")) => Self::V2_3,
+            (Some("2"), Some("4")) => Self::V2_4,
+            (Some("2"), Some("5")) => Self::V2_5,
+            _ => return Err(Error::Version(value.to_owned())),
+        };
+        match parts.next() {
+            Some(patch) if patch.is_empty() || !patch.bytes().all(|b| b.is_ascii_digit()) => {
+                Err(Error::Version(value.to_owned()))
+            }
+            _ => Ok(version),
+        }
+    }
+
+    /// Returns the version of a `x-openrtb-version` header, 2.5 if the header is missing.
+    pub fn from_header(value: Option<&str>) -> Result<Self, Error> {
+        value.map_or(Ok(Self::V2_5), Self::parse)
+    }
+
+    /// Returns true for the versions older than 2.5.
+    pub fn is_legacy(&self) -> bool {
+        *self < Self::V2_5
+    }
+
+    /// Returns the version in the `major.minor` format.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::V2_2 => "2.2",
+            Self::V2_3 => "2.3",
+            Self::V2_4 => "2.4",
+            Self::V2_5 => "2.5",
+        }
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Deserializes a bid request sent in `version` and upgrades it to idiomatic 2.5.
+///
+/// The returned request owns all of its data.
+pub fn from_slice(body: &[u8], version: Version) -> serde_json::Result<crate::BidRequest<'static>> {
+    let mut value: serde_json::Value = serde_json::from_slice(body)?;
+    if version.is_legacy() {
+        migrate(&mut value);
+    }
+    let mut req: crate::BidRequest<'static> = serde::Deserialize::deserialize(value)?;
+    upgrade(&mut req, version);
+    Ok(req)
+}
+
+/// Rewrites the legacy shapes of a bid request sent in `version` into their 2.5 equivalent.
+///
+/// Does nothing for 2.5 requests.
+pub fn upgrade(req: &mut crate::BidRequest, version: Version) {
+    if !version.is_legacy() {
+        return;
+    }
+
+    for imp in &mut req.imp {
+        if let Some(banner) = &mut imp.banner {
+            upgrade_banner(banner);
+        }
+        let companions = [
+            imp.video.as_mut().and_then(|video| video.companionad.as_mut()),
+            imp.audio.as_mut().and_then(|audio| audio.companionad.as_mut()),
+        ];
+        for banner in companions.into_iter().flatten().flatten() {
+            upgrade_banner(banner);
+        }
+    }
+
+    let content = match &mut req.channel {
+        Some(crate::DistributionChannel::Site(site)) => site.content.as_mut(),
+        Some(crate::DistributionChannel::App(app)) => app.content.as_mut(),
+        None => None,
+    };
+    if let Some(content) = content {
+        upgrade_content(content);
+    }
+}
+
+/// Builds `format` from the exact size of a banner which has none. The `wmin..wmax` and
+/// `hmin..hmax` ranges are kept next to it, as no list of sizes holds every size they allow.
+#[allow(deprecated)]
+fn upgrade_banner(banner: &mut crate::Banner) {
+    if banner.format.is_some() {
+        return;
+    }
+    if let (Some(w), Some(h)) = (banner.w, banner.h) {
+        banner.format = Some(vec![crate::Format {
+            w: Some(w),
+            h: Some(h),
+            ..Default::default()
+        }]);
+    }
+}
+
+#[allow(deprecated)]
+fn upgrade_content(content: &mut crate::Content) {
+    if let Some(videoquality) = content.videoquality.take() {
+        content.prodq.get_or_insert(videoquality);
+    }
+}
+
+/// Rewrites the legacy shapes which do not deserialize into 2.5 types.
+fn migrate(req: &mut serde_json::Value) {
+    for video in videos(req) {
+        if let Some(protocol) = video.remove("protocol") {
+            let protocols = video
+                .entry("protocols")
+                .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+            if let Some(protocols) = protocols.as_array_mut() {
+                if !protocols.contains(&protocol) {
+                    protocols.insert(0, protocol);
+                }
+            }
+        }
+        video
+            .entry("mimes")
+            .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+    }
+}
+
+/// A lossy step of a downgrade.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Step {
+    /// Path of the field in the request, e.g. `imp[0].banner.format`.
+    pub path: String,
+    /// What happened to the field.
+    pub action: Action,
+}
+
+/// The kind of a lossy step.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Action {
+    /// The field was removed.
+    Dropped,
+    /// The field was moved, under the same name, into the `ext` of its parent object.
+    MovedToExt,
+    /// The field was mapped to an older equivalent which does not carry all of its information.
+    Approximated,
+}
+
+/// Removes or rewrites the fields of a 2.5 bid request unknown to `target`.
+///
+/// Returns the steps which lose information, in the order of the request.
+///
+/// ```
+/// # use openrtb2::legacy::{self, Action, Step, Version};
+/// let mut req: openrtb2::BidRequest = serde_json::from_str(
+///     r#"{"id":"1","imp":[{"id":"1","banner":{"format":[{"w":300,"h":250},{"w":320,"h":50}]}}]}"#,
+/// )?;
+/// let steps = legacy::downgrade(&mut req, Version::V2_3);
+/// let banner = req.imp[0].banner.as_ref().unwrap();
+/// assert_eq!((banner.w, banner.h), (Some(300), Some(250)));
+/// assert_eq!(steps, [Step { path: "imp[0].banner.format".into(), action: Action::Approximated }]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn downgrade(req: &mut crate::BidRequest, target: Version) -> Vec<Step> {
+    let mut steps = Vec::new();
+    let mut step = |path: String, action| steps.push(Step { path, action });
+
+    if target < Version::V2_5 {
+        if req.bseat.take().is_some() {
+            step("bseat".into(), Action::Dropped);
+        }
+        if let Some(source) = req.source.take() {
+            step("source".into(), to_ext(&mut req.ext, "source", &source));
+        }
+    }
+
+    for (i, imp) in req.imp.iter_mut().enumerate() {
+        let path = format!("imp[{}]", i);
+        if target < Version::V2_5 {
+            if let Some(metric) = imp.metric.take() {
+                step(format!("{}.metric", path), to_ext(&mut imp.ext, "metric", &metric));
+            }
+            if imp.clickbrowser.take().is_some() {
+                step(format!("{}.clickbrowser", path), Action::Dropped);
+            }
+            if let Some(video) = &mut imp.video {
+                if let Some(placement) = video.placement.take() {
+                    let action = to_ext(&mut video.ext, "placement", &placement);
+                    step(format!("{}.video.placement", path), action);
+                }
+                if video.playbackend.take().is_some() {
+                    step(format!("{}.video.playbackend", path), Action::Dropped);
+                }
+            }
+        }
+        if target < Version::V2_4 {
+            if imp.audio.take().is_some() {
+                step(format!("{}.audio", path), Action::Dropped);
+            }
+            if imp.exp.take().is_some() {
+                step(format!("{}.exp", path), Action::Dropped);
+            }
+        }
+        if target < Version::V2_3 {
+            if imp.native.take().is_some() {
+                step(format!("{}.native", path), Action::Dropped);
+            }
+            let protocols = imp.video.as_ref().and_then(|video| video.protocols.as_ref());
+            if protocols.is_some_and(|protocols| protocols.len() > 1) {
+                step(format!("{}.video.protocols", path), Action::Approximated);
+            }
+        }
+
+        let banners = [(format!("{}.banner", path), imp.banner.as_mut())].into_iter().chain(
+            imp.video
+                .iter_mut()
+                .flat_map(|video| video.companionad.iter_mut().flatten())
+                .enumerate()
+                .map(|(j, banner)| (format!("{}.video.companionad[{}]", path, j), Some(banner))),
+        );
+        for (path, banner) in banners {
+            if let Some(banner) = banner {
+                downgrade_banner(banner, target, &path, &mut step);
+            }
+        }
+    }
+
+    if let Some(device) = &mut req.device {
+        if target < Version::V2_5 {
+            if device.mccmnc.take().is_some() {
+                step("device.mccmnc".into(), Action::Dropped);
+            }
+            if device.geofetch.take().is_some() {
+                step("device.geofetch".into(), Action::Dropped);
+            }
+        }
+    }
+
+    let content = match &mut req.channel {
+        Some(crate::DistributionChannel::Site(site)) => site.content.as_mut(),
+        Some(crate::DistributionChannel::App(app)) => app.content.as_mut(),
+        None => None,
+    };
+    if let Some(content) = content {
+        if target < Version::V2_4 {
+            downgrade_content(content);
+        }
+    }
+
+    steps
+}
+
+#[allow(deprecated)]
+fn downgrade_banner(banner: &mut crate::Banner, target: Version, path: &str, step: &mut impl FnMut(String, Action)) {
+    if target < Version::V2_5 && banner.vcm.take().is_some() {
+        step(format!("{}.vcm", path), Action::Dropped);
+    }
+    if target >= Version::V2_4 {
+        return;
+    }
+    let format = match banner.format.take() {
+        Some(format) => format,
+        None => return,
+    };
+
+    let sizes: Vec<(i32, i32)> = format.iter().filter_map(|f| Some((f.w?, f.h?))).collect();
+    if let (None, None, Some(&(w, h))) = (banner.w, banner.h, sizes.first()) {
+        banner.w = Some(w);
+        banner.h = Some(h);
+    }
+    if sizes.len() > 1 {
+        banner.wmin = sizes.iter().map(|size| size.0).min();
+        banner.hmin = sizes.iter().map(|size| size.1).min();
+        banner.wmax = sizes.iter().map(|size| size.0).max();
+        banner.hmax = sizes.iter().map(|size| size.1).max();
+    }
+
+    let exact = match (format.as_slice(), sizes.as_slice()) {
+        ([format], [size]) => {
+            format.wmin.is_none() && format.ext.is_none() && (banner.w, banner.h) == (Some(size.0), Some(size.1))
+        }
+        ([], []) => true,
+        _ => false,
+    };
+    if !exact {
+        step(format!("{}.format", path), Action::Approximated);
+    }
+}
+
+#[allow(deprecated)]
+fn downgrade_content(content: &mut crate::Content) {
+    if let Some(prodq) = content.prodq.take() {
+        content.videoquality.get_or_insert(prodq);
+    }
+}
+
+/// Moves `value` into the member `key` of `ext`.
+fn to_ext<T>(ext: &mut Option<json_ext::Object>, key: &str, value: &T) -> Action
+where
+    T: serde::Serialize,
+{
+    match serde_json::to_value(value).and_then(|value| crate::ext::set(ext, key, value)) {
+        Ok(()) => Action::MovedToExt,
+        Err(_) => Action::Dropped,
+    }
+}
+
+/// Serializes a bid request in the shapes of `version`.
+///
+/// The request is expected to be [`downgrade`]d first; this only rewrites the fields which 2.5
+/// types cannot hold, i.e. `Video.protocol` before 2.3.
+///
+/// [`downgrade`]: ./fn.downgrade.html
+pub fn to_vec(req: &crate::BidRequest, version: Version) -> serde_json::Result<Vec<u8>> {
+    let mut value = serde_json::to_value(req)?;
+    if version < Version::V2_3 {
+        for video in videos(&mut value) {
+            if let Some(serde_json::Value::Array(protocols)) = video.remove("protocols") {
+                if let Some(protocol) = protocols.into_iter().next() {
+                    video.insert("protocol".into(), protocol);
+                }
+            }
+        }
+    }
+    serde_json::to_vec(&value)
+}
+
+/// Deserializes a bid response of a bidder speaking `version` and upgrades it to 2.5.
+///
+/// The returned response owns all of its data.
+pub fn response_from_slice(body: &[u8], version: Version) -> serde_json::Result<crate::BidResponse<'static>> {
+    let mut value: serde_json::Value = serde_json::from_slice(body)?;
+    if version.is_legacy() {
+        let bids = value
+            .get_mut("seatbid")
+            .and_then(serde_json::Value::as_array_mut)
+            .into_iter()
+            .flatten()
+            .filter_map(|seatbid| seatbid.get_mut("bid"))
+            .filter_map(serde_json::Value::as_array_mut)
+            .flatten();
+        for adm in bids.filter_map(|bid| bid.get_mut("adm")) {
+            // Early native implementations returned the markup as an object rather than a string.
+            if adm.is_object() {
+                *adm = adm.to_string().into();
+            }
+        }
+    }
+    let mut res: crate::BidResponse<'static> = serde::Deserialize::deserialize(value)?;
+    upgrade_response(&mut res, version);
+    Ok(res)
+}
+
+/// Moves the `burl` and `lurl` notice URLs, which bidders older than 2.5 can only return in
+/// `Bid.ext`, to their 2.5 fields.
+///
+/// Does nothing for 2.5 responses.
+pub fn upgrade_response(res: &mut crate::BidResponse, version: Version) {
+    if !version.is_legacy() {
+        return;
+    }
+    let bids = res.seatbid.iter_mut().flatten().flat_map(|seatbid| &mut seatbid.bid);
+    for bid in bids {
+        for (key, url) in [("burl", &mut bid.burl), ("lurl", &mut bid.lurl)] {
+            if url.is_none() {
+                if let Some(value) = crate::ext::get_str(bid.ext.as_ref(), key) {
+                    crate::ext::remove(&mut bid.ext, key);
+                    *url = Some(value.into());
+                }
+            }
+        }
+    }
+}
+
+/// Returns the video objects of a bid request.
+fn videos(req: &mut serde_json::Value) -> impl Iterator<Item = &mut serde_json::Map<String, serde_json::Value>> {
+    req.get_mut("imp")
+        .and_then(serde_json::Value::as_array_mut)
+        .into_iter()
+        .flatten()
+        .filter_map(|imp| imp.get_mut("video"))
+        .filter_map(serde_json::Value::as_object_mut)
+}
+
+/// The error type for legacy versions.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Error {
+    /// The version is not a supported version of OpenRTB 2.
+    Version(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Version(version) => write!(f, "unsupported openrtb version: {}", version),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn version() {
+        assert_eq!(Version::parse("2.2"), Ok(Version::V2_2));
+        assert_eq!(Version::parse(" 2.4 "), Ok(Version::V2_4));
+        assert_eq!(Version::parse("2.5.0"), Ok(Version::V2_5));
+        assert_eq!(Version::from_header(None), Ok(Version::V2_5));
+        assert!(Version::parse("2.1").is_err());
+        assert!(Version::parse("2").is_err());
+        assert!(Version::parse("2.3.x").is_err());
+        assert!(Version::V2_4.is_legacy());
+        assert!(!Version::V2_5.is_legacy());
+        assert_eq!(Version::V2_3.to_string(), "2.3");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn upgrade() -> serde_json::Result<()> {
+        let json = br#"{
+            "id": "1",
+            "imp": [
+                {"id": "1", "banner": {"w": 300, "h": 250, "wmax": 320, "hmax": 250, "wmin": 300, "hmin": 250}},
+                {"id": "2", "video": {"protocol": 2, "protocols": [3], "companionad": [{"wmax": 300, "hmax": 250}]}},
+                {"id": "3", "banner": {"wmax": 728}}
+            ],
+            "site": {"content": {"videoquality": 2}}
+        }"#;
+
+        assert!(serde_json::from_slice::<crate::BidRequest>(json).is_err());
+        let req = from_slice(json, Version::V2_2)?;
+
+        let banner = req.imp[0].banner.as_ref().unwrap();
+        let sizes: Vec<_> = banner.format.iter().flatten().map(|f| (f.w, f.h)).collect();
+        assert_eq!(sizes, [(Some(300), Some(250))]);
+        assert_eq!((banner.w, banner.h), (Some(300), Some(250)));
+        assert_eq!(
+            (banner.wmin, banner.wmax, banner.hmin, banner.hmax),
+            (Some(300), Some(320), Some(250), Some(250))
+        );
+
+        let video = req.imp[1].video.as_ref().unwrap();
+        assert!(video.mimes.is_empty());
+        assert_eq!(
+            video.protocols,
+            Some(vec![crate::Protocol::Vast2, crate::Protocol::Vast3])
+        );
+        let companion = &video.companionad.as_ref().unwrap()[0];
+        assert_eq!((companion.format.as_ref(), companion.wmax), (None, Some(300)));
+
+        let banner = req.imp[2].banner.as_ref().unwrap();
+        assert_eq!((banner.format.as_ref(), banner.wmax), (None, Some(728)));
+
+        let content = match &req.channel {
+            Some(crate::DistributionChannel::Site(site)) => site.content.as_ref().unwrap(),
+            _ => unreachable!(),
+        };
+        assert_eq!(content.prodq, Some(crate::ProductionQuality::Prosumer));
+        assert_eq!(content.videoquality, None);
+
+        let json = br#"{"id":"1","imp":[{"id":"1","banner":{"wmax":320,"hmax":250}}]}"#;
+        let req = from_slice(json, Version::V2_5)?;
+        assert_eq!(req, serde_json::from_slice(json)?);
+
+        Ok(())
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn downgrade() -> serde_json::Result<()> {
+        let json = r#"{
+            "id": "1",
+            "imp": [
+                {"id": "1", "banner": {"format": [{"w": 300, "h": 250}, {"w": 728, "h": 90}], "vcm": 1}, "metric": [{"type": "viewability", "value": 0.8}]},
+                {"id": "2", "video": {"mimes": ["video/mp4"], "protocols": [2, 3], "placement": 1, "companionad": [{"format": [{"w": 300, "h": 250}]}]}},
+                {"id": "3", "native": {"request": "{}"}, "audio": {"mimes": ["audio/mp4"]}}
+            ],
+            "site": {"content": {"prodq": 1}},
+            "source": {"fd": 1},
+            "bseat": ["a"]
+        }"#;
+        let req: crate::BidRequest = serde_json::from_str(json)?;
+
+        let mut v2_4 = req.clone();
+        let steps = super::downgrade(&mut v2_4, Version::V2_4);
+        let paths: Vec<_> = steps.iter().map(|step| (step.path.as_str(), step.action)).collect();
+        assert_eq!(
+            paths,
+            [
+                ("bseat", Action::Dropped),
+                ("source", Action::MovedToExt),
+                ("imp[0].metric", Action::MovedToExt),
+                ("imp[0].banner.vcm", Action::Dropped),
+                ("imp[1].video.placement", Action::MovedToExt),
+            ]
+        );
+        assert!(crate::ext::get(v2_4.ext.as_ref(), "source").is_some());
+        assert!(v2_4.imp[0].banner.as_ref().unwrap().format.is_some());
+
+        let mut v2_2 = req;
+        let steps = super::downgrade(&mut v2_2, Version::V2_2);
+        let paths: Vec<_> = steps.iter().map(|step| step.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "bseat",
+                "source",
+                "imp[0].metric",
+                "imp[0].banner.vcm",
+                "imp[0].banner.format",
+                "imp[1].video.placement",
+                "imp[1].video.protocols",
+                "imp[2].audio",
+                "imp[2].native",
+            ]
+        );
+        let banner = v2_2.imp[0].banner.as_ref().unwrap();
+        assert_eq!(
+            (banner.w, banner.h, banner.wmax, banner.hmin),
+            (Some(300), Some(250), Some(728), Some(90))
+        );
+        let companion = &v2_2.imp[1].video.as_ref().unwrap().companionad.as_ref().unwrap()[0];
+        assert_eq!(
+            (companion.w, companion.h, companion.format.as_ref()),
+            (Some(300), Some(250), None)
+        );
+        let content = match &v2_2.channel {
+            Some(crate::DistributionChannel::Site(site)) => site.content.as_ref().unwrap(),
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            (content.prodq, content.videoquality),
+            (None, Some(crate::ProductionQuality::Professional))
+        );
+
+        let body = to_vec(&v2_2, Version::V2_2)?;
+        let value: serde_json::Value = serde_json::from_slice(&body)?;
+        assert_eq!(value["imp"][1]["video"]["protocol"], 2);
+        assert!(value["imp"][1]["video"].get("protocols").is_none());
+        assert_eq!(
+            from_slice(&body, Version::V2_2)?.imp[1]
+                .video
+                .as_ref()
+                .unwrap()
+                .protocols
+                .as_ref()
+                .map(Vec::len),
+            Some(1)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn response() -> serde_json::Result<()> {
+        let json = br#"{
+            "id": "1",
+            "seatbid": [{"bid": [
+                {"id": "1", "impid": "1", "price": 1, "adm": {"native": {"assets": []}}, "ext": {"burl": "b", "lurl": "l"}},
+                {"id": "2", "impid": "2", "price": 1, "burl": "x", "ext": {"burl": "b", "dsp": 1}}
+            ]}]
+        }"#;
+
+        assert!(serde_json::from_slice::<crate::BidResponse>(json).is_err());
+        let res = response_from_slice(json, Version::V2_3)?;
+        let bids = &res.seatbid.as_ref().unwrap()[0].bid;
+        assert_eq!(bids[0].adm.as_deref(), Some(r#"{"native":{"assets":[]}}"#));
+        assert_eq!(
+            (bids[0].burl.as_deref(), bids[0].lurl.as_deref()),
+            (Some("b"), Some("l"))
+        );
+        assert_eq!(bids[0].ext, None);
+        assert_eq!(bids[1].burl.as_deref(), Some("x"));
+        assert_eq!(crate::ext::get_str(bids[1].ext.as_ref(), "burl").as_deref(), Some("b"));
+
+        let json = br#"{"id":"1","seatbid":[{"bid":[{"id":"1","impid":"1","price":1,"ext":{"burl":"b"}}]}]}"#;
+        let res = response_from_slice(json, Version::V2_5)?;
+        assert_eq!(res, serde_json::from_slice(json)?);
+
+        Ok(())
+    }
+}
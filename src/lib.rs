@@ -217,3 +217,69 @@ pub use gender::*;
 
 mod max_extended_ad_duration;
 pub use max_extended_ad_duration::*;
+
+mod category_taxonomy;
+pub use category_taxonomy::*;
+
+// ===== utilities =====
+
+mod ext;
+
+pub mod ads_txt;
+
+pub mod authorized_sellers;
+
+pub mod diff;
+
+pub mod ip;
+
+pub mod iso;
+
+pub mod legacy;
+
+pub mod merge;
+
+pub mod openrtb3;
+
+pub mod path;
+
+pub mod privacy;
+
+#[cfg(feature = "redact")]
+pub mod redact;
+
+pub mod sellers_json;
+
+pub mod shaper;
+
+pub mod supply_chain;
+
+pub mod taxonomy;
+
+#[cfg(feature = "tcf")]
+pub mod tcf;
+
+pub mod us_privacy;
+
+pub mod visit;
+
+#[cfg(feature = "user-agent")]
+pub mod user_agent;
+
+#[cfg(feature = "gpp")]
+pub mod gpp;
+
+#[cfg(feature = "schemars")]
+pub mod schema;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+// ===== transport =====
+
+// 2
+#[cfg(feature = "http")]
+pub mod http;
@@ -4,6 +4,8 @@
 /// they did not win an impression.
 #[allow(non_camel_case_types)]
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i32)]
 pub enum LossReason {
     /// Bid Won
@@ -43,6 +43,32 @@ impl<'de> serde::Deserialize<'de> for MaxExtendedAdDuration {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for MaxExtendedAdDuration {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=2u8)? {
+            0 => Self::NoLimit,
+            1 => Self::NotAllowed,
+            _ => Self::Specific(u.int_in_range(1..=i32::MAX)?),
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for MaxExtendedAdDuration {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "MaxExtendedAdDuration".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Maximum extended ad duration, where -1 = no time limit, 0 = extension not allowed, and a value greater than 0 is the number of seconds of extended play supported.",
+            "type": "integer",
+            "minimum": -1
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,316 @@
+//! Overlays for request enrichment.
+//!
+//! Enrichment adds data from several sources (publisher configuration, geo lookup, data partners)
+//! to a bid request. Each source provides a partial object which [`Merge::merge`] applies with a
+//! [`Precedence`]:
+//!
+//! - objects, including `ext`, are merged member by member at any depth;
+//! - scalars present on both sides are kept or replaced depending on the precedence;
+//! - arrays of objects which all carry a distinct string `id`, such as `imp`, `pmp.deals` or
+//!   `user.data`, are merged element by element by `id`, new elements being appended;
+//! - other arrays, such as `bcat`, `badv` or `cur`, are concatenated without duplicates.
+//!
+//! A request on a site is not merged with an overlay on an app, or the other way around: the
+//! overlay's channel replaces the request's one with [`Precedence::Overwrite`] and is ignored with
+//! [`Precedence::FillMissing`].
+//!
+//! Required fields of a typed overlay, such as `BidRequest.id` or `Native.request`, only hold
+//! placeholders and are ignored; `imp[].id` and `pmp.deals[].id` are only used to match elements.
+//! [`Merge::merge_json`] applies a partial JSON overlay instead, which only sets the fields it
+//! contains, required ones included.
+//!
+//! Merging goes through the JSON representation of the objects, so the strings of the result are
+//! owned even when the inputs borrowed them.
+//!
+//! ```
+//! # use openrtb2::merge::{Merge, Precedence};
+//! let mut req: openrtb2::BidRequest = serde_json::from_str(
+//!     r#"{"id":"1","imp":[{"id":"1"}],"bcat":["IAB25"],"device":{"geo":{"country":"USA"}}}"#,
+//! )?;
+//! let overlay = serde_json::from_str(
+//!     r#"{"id":"","imp":[],"bcat":["IAB25","IAB26"],"device":{"geo":{"country":"CAN","region":"ON"}}}"#,
+//! )?;
+//! req.merge(&overlay, Precedence::FillMissing)?;
+//! assert_eq!(req.id, "1");
+//! assert_eq!(req.bcat, Some(vec!["IAB25".into(), "IAB26".into()]));
+//! let geo = req.device.as_ref().and_then(|device| device.geo.as_ref()).unwrap();
+//! assert_eq!((geo.country.as_deref(), geo.region.as_deref()), (Some("USA"), Some("ON")));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`Merge::merge`]: ./trait.Merge.html#tymethod.merge
+//! [`Merge::merge_json`]: ./trait.Merge.html#tymethod.merge_json
+//! [`Precedence`]: ./enum.Precedence.html
+//! [`Precedence::Overwrite`]: ./enum.Precedence.html#variant.Overwrite
+//! [`Precedence::FillMissing`]: ./enum.Precedence.html#variant.FillMissing
+
+use serde_json::Value;
+
+/// Which side wins when both the object and the overlay set a scalar.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Precedence {
+    /// The overlay only fills the fields the object does not set.
+    FillMissing,
+    /// The overlay replaces the fields the object sets.
+    Overwrite,
+}
+
+/// Objects an overlay can be merged into.
+pub trait Merge {
+    /// Merges `overlay` into `self`, ignoring the required fields of `overlay`.
+    ///
+    /// Fails if the merged JSON does not deserialize back, which the rules of this module only
+    /// allow for custom `ext` contents.
+    fn merge(&mut self, overlay: &Self, precedence: Precedence) -> serde_json::Result<()>;
+
+    /// Merges the partial JSON object `overlay` into `self`.
+    ///
+    /// Fails if the merged JSON does not deserialize back, e.g. when `overlay` sets a field to a
+    /// value of the wrong type.
+    ///
+    /// ```
+    /// # use openrtb2::merge::{Merge, Precedence};
+    /// let mut req: openrtb2::BidRequest = serde_json::from_str(r#"{"id":"1","imp":[],"tmax":100}"#)?;
+    /// req.merge_json(&serde_json::json!({"tmax": 120, "user": {"id": "u"}}), Precedence::Overwrite)?;
+    /// assert_eq!((req.id.as_ref(), req.tmax), ("1", Some(120)));
+    /// assert_eq!(req.user.unwrap().id.as_deref(), Some("u"));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    fn merge_json(&mut self, overlay: &Value, precedence: Precedence) -> serde_json::Result<()>;
+}
+
+impl<'a> Merge for crate::BidRequest<'a> {
+    fn merge(&mut self, overlay: &Self, precedence: Precedence) -> serde_json::Result<()> {
+        let mut overlay = serde_json::to_value(overlay)?;
+        remove_required(&mut overlay, &["id", "imp.native.request"]);
+        self.merge_json(&overlay, precedence)
+    }
+
+    fn merge_json(&mut self, overlay: &Value, precedence: Precedence) -> serde_json::Result<()> {
+        let mut overlay = std::borrow::Cow::Borrowed(overlay);
+        let conflict = match &self.channel {
+            Some(crate::DistributionChannel::Site(_)) => overlay.get("app").is_some(),
+            Some(crate::DistributionChannel::App(_)) => overlay.get("site").is_some(),
+            None => false,
+        };
+        if conflict {
+            match precedence {
+                Precedence::FillMissing => {
+                    if let Value::Object(overlay) = overlay.to_mut() {
+                        overlay.remove("site");
+                        overlay.remove("app");
+                    }
+                }
+                Precedence::Overwrite => self.channel = None,
+            }
+        }
+        merge_value(self, &overlay, precedence)
+    }
+}
+
+macro_rules! impl_merge {
+    ($($ty:ident $([$($required:literal),*])?),*) => {
+        $(
+            impl<'a> Merge for crate::$ty<'a> {
+                fn merge(&mut self, overlay: &Self, precedence: Precedence) -> serde_json::Result<()> {
+                    #[allow(unused_mut)]
+                    let mut overlay = serde_json::to_value(overlay)?;
+                    $(remove_required(&mut overlay, &[$($required),*]);)?
+                    merge_value(self, &overlay, precedence)
+                }
+
+                fn merge_json(&mut self, overlay: &Value, precedence: Precedence) -> serde_json::Result<()> {
+                    merge_value(self, overlay, precedence)
+                }
+            }
+        )*
+    };
+}
+
+impl_merge!(
+    Imp["id", "native.request"],
+    Site,
+    App,
+    Publisher,
+    Content,
+    Device,
+    Geo,
+    User,
+    Data,
+    Regs,
+    Source
+);
+
+/// Removes the fields at the dotted `paths` of `value`, descending into the elements of arrays.
+fn remove_required(value: &mut Value, paths: &[&str]) {
+    fn remove(value: &mut Value, path: &str) {
+        match value {
+            Value::Array(array) => array.iter_mut().for_each(|value| remove(value, path)),
+            Value::Object(object) => match path.split_once('.') {
+                Some((key, rest)) => {
+                    if let Some(value) = object.get_mut(key) {
+                        remove(value, rest);
+                    }
+                }
+                None => {
+                    object.remove(path);
+                }
+            },
+            _ => {}
+        }
+    }
+    for path in paths {
+        remove(value, path);
+    }
+}
+
+/// Merges the JSON `overlay` into `base`.
+pub fn values(base: &mut Value, overlay: &Value, precedence: Precedence) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, overlay) in overlay {
+                match base.get_mut(key) {
+                    Some(base) => values(base, overlay, precedence),
+                    None => {
+                        base.insert(key.clone(), overlay.clone());
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(overlay)) => match (ids(base), ids(overlay)) {
+            (Some(base_ids), Some(_)) => {
+                let base_ids: Vec<String> = base_ids.into_iter().map(str::to_owned).collect();
+                for overlay in overlay {
+                    let id = overlay.get("id").and_then(Value::as_str);
+                    match base_ids.iter().position(|base_id| Some(base_id.as_str()) == id) {
+                        Some(i) => values(&mut base[i], overlay, precedence),
+                        None => base.push(overlay.clone()),
+                    }
+                }
+            }
+            _ => {
+                for overlay in overlay {
+                    if !base.contains(overlay) {
+                        base.push(overlay.clone());
+                    }
+                }
+            }
+        },
+        (base, overlay) => {
+            if !overlay.is_null() && (base.is_null() || precedence == Precedence::Overwrite) {
+                *base = overlay.clone();
+            }
+        }
+    }
+}
+
+fn merge_value<'a, T>(base: &mut T, overlay: &Value, precedence: Precedence) -> serde_json::Result<()>
+where
+    T: serde::Serialize + serde::Deserialize<'a>,
+{
+    let mut value = serde_json::to_value(&*base)?;
+    values(&mut value, overlay, precedence);
+    *base = T::deserialize(value)?;
+    Ok(())
+}
+
+/// Returns the ids of the elements if they are all objects with a distinct string `id`.
+fn ids(array: &[Value]) -> Option<Vec<&str>> {
+    let ids = array
+        .iter()
+        .map(|value| value.get("id")?.as_str())
+        .collect::<Option<Vec<_>>>()?;
+    let distinct: std::collections::HashSet<_> = ids.iter().collect();
+    (distinct.len() == ids.len()).then_some(ids)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn request() -> serde_json::Result<()> {
+        let mut req: crate::BidRequest = serde_json::from_str(
+            r#"{
+                "id": "1",
+                "imp": [{"id": "1", "bidfloor": 1, "ext": {"a": {"b": 1}}}],
+                "site": {"id": "s", "page": "https://example.com"},
+                "user": {"data": [{"id": "d1", "segment": [{"id": "1"}]}]},
+                "badv": ["a.com"]
+            }"#,
+        )?;
+        let overlay: crate::BidRequest = serde_json::from_str(
+            r#"{
+                "id": "2",
+                "imp": [{"id": "1", "bidfloor": 2, "ext": {"a": {"c": 2}}}, {"id": "2"}],
+                "app": {"id": "a"},
+                "user": {"data": [{"id": "d1", "segment": [{"id": "2"}]}, {"id": "d2"}]},
+                "badv": ["b.com", "a.com"]
+            }"#,
+        )?;
+
+        let mut filled = req.clone();
+        filled.merge(&overlay, Precedence::FillMissing)?;
+        assert_eq!(filled.id, "1");
+        assert_eq!(filled.imp.len(), 2);
+        assert_eq!(filled.imp[0].bidfloor, Some(1.0));
+        assert_eq!(serde_json::to_string(&filled.imp[0].ext)?, r#"{"a":{"b":1,"c":2}}"#);
+        assert!(
+            matches!(&filled.channel, Some(crate::DistributionChannel::Site(site)) if site.id.as_deref() == Some("s"))
+        );
+        let data = filled.user.as_ref().unwrap().data.as_ref().unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0].segment.as_ref().map(Vec::len), Some(2));
+        assert_eq!(filled.badv, Some(vec!["a.com".into(), "b.com".into()]));
+
+        req.merge(&overlay, Precedence::Overwrite)?;
+        assert_eq!(req.id, "1");
+        assert_eq!(req.imp[0].bidfloor, Some(2.0));
+        assert!(matches!(&req.channel, Some(crate::DistributionChannel::App(app)) if app.id.as_deref() == Some("a")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn required() -> serde_json::Result<()> {
+        let mut req: crate::BidRequest =
+            serde_json::from_str(r#"{"id":"1","imp":[{"id":"1","native":{"request":"{}"}}],"tmax":100}"#)?;
+        let overlay: crate::BidRequest =
+            serde_json::from_str(r#"{"id":"","imp":[{"id":"1","native":{"request":""},"tagid":"t"}],"tmax":120}"#)?;
+        req.merge(&overlay, Precedence::Overwrite)?;
+        assert_eq!((req.id.as_ref(), req.tmax), ("1", Some(120)));
+        assert_eq!(req.imp[0].native.as_ref().unwrap().request, "{}");
+        assert_eq!(req.imp[0].tagid.as_deref(), Some("t"));
+
+        let mut imp = req.imp[0].clone();
+        imp.merge(&crate::Imp::default(), Precedence::Overwrite)?;
+        assert_eq!(imp.id, "1");
+
+        req.merge_json(
+            &serde_json::json!({"id": "2", "imp": [{"id": "1", "bidfloor": 1.5}]}),
+            Precedence::Overwrite,
+        )?;
+        assert_eq!((req.id.as_ref(), req.imp[0].bidfloor), ("2", Some(1.5)));
+        assert!(req
+            .merge_json(&serde_json::json!({"tmax": "soon"}), Precedence::Overwrite)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn json() {
+        let mut base = serde_json::json!({"a": 1, "b": [1, 2], "c": {"d": null}});
+        let overlay = serde_json::json!({"a": 2, "b": [2, 3], "c": {"d": 4, "e": 5}});
+        values(&mut base, &overlay, Precedence::FillMissing);
+        assert_eq!(base, serde_json::json!({"a": 1, "b": [1, 2, 3], "c": {"d": 4, "e": 5}}));
+        values(
+            &mut base,
+            &serde_json::json!({"a": 3, "c": null}),
+            Precedence::Overwrite,
+        );
+        assert_eq!(base["a"], 3);
+        assert_eq!(base["c"]["e"], 5);
+    }
+}
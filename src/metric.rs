@@ -5,6 +5,8 @@
 /// click-through rate, etc. Each metric is identified by its type, reports the value of the metric,
 /// and optionally identifies the source or vendor measuring the value.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Metric<'a> {
     /// string; required
     /// Type of metric being presented using exchange curated string names which should be
@@ -14,6 +16,7 @@ pub struct Metric<'a> {
 
     /// float; required
     /// Number representing the value of the metric. Probabilities must be in the range 0.0 – 1.0.
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::probability))]
     pub value: f32,
 
     /// string; recommended
@@ -26,6 +29,8 @@ pub struct Metric<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
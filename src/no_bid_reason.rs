@@ -3,6 +3,8 @@
 /// The following table lists the options for a bidder to signal the exchange as to why it did not
 /// offer a bid for the impression.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum NoBidReason {
     /// Unknown Error
@@ -0,0 +1,100 @@
+//! OpenRTB 3.0 and AdCOM 1.0.
+//!
+//! OpenRTB 3.0 splits the protocol in two layers: the transaction layer (an [`Openrtb`] envelope
+//! carrying a [`Request`] or a [`Response`]) and the domain layer, AdCOM, describing the
+//! placements, the context and the ads. Only the AdCOM domain specification is supported.
+//!
+//! The converters translate between 2.5 and 3.0 so a 2.5 bidder can serve 3.0 traffic:
+//!
+//! | 2.5                | 3.0                                   | Function                |
+//! |--------------------|---------------------------------------|-------------------------|
+//! | [`BidRequest`]     | `openrtb.request`                     | [`from_bid_request`]    |
+//! | `openrtb.request`  | [`BidRequest`]                        | [`to_bid_request`]      |
+//! | [`BidResponse`]    | `openrtb.response`                    | [`from_bid_response`]   |
+//! | `openrtb.response` | [`BidResponse`]                       | [`to_bid_response`]     |
+//!
+//! The two versions do not carry the same information. Each converter returns the [`Unmapped`]
+//! fields of its input which have no counterpart in the output, e.g. `imp[0].banner.btype` or
+//! `request.item[0].spec.placement.ssai`, so callers can decide whether the loss is acceptable.
+//!
+//! ```
+//! # use openrtb2::{openrtb3, BidRequest};
+//! let json = include_str!("../tests/json/6.3.1_simple_banner.json");
+//! let req: BidRequest = serde_json::from_str(json)?;
+//! let (openrtb, unmapped) = openrtb3::from_bid_request(&req);
+//! assert!(unmapped.is_empty());
+//! assert_eq!(openrtb.request.as_ref().unwrap().item[0].flr, Some(0.03));
+//!
+//! let (back, unmapped) = openrtb3::to_bid_request(&openrtb)?;
+//! assert!(unmapped.is_empty());
+//! assert_eq!(back, req);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`Openrtb`]: ./struct.Openrtb.html
+//! [`Request`]: ./struct.Request.html
+//! [`Response`]: ./struct.Response.html
+//! [`BidRequest`]: ../struct.BidRequest.html
+//! [`BidResponse`]: ../struct.BidResponse.html
+//! [`from_bid_request`]: ./fn.from_bid_request.html
+//! [`to_bid_request`]: ./fn.to_bid_request.html
+//! [`from_bid_response`]: ./fn.from_bid_response.html
+//! [`to_bid_response`]: ./fn.to_bid_response.html
+//! [`Unmapped`]: ./struct.Unmapped.html
+
+mod ad;
+pub use ad::*;
+
+mod context;
+pub use context::*;
+
+mod convert;
+pub use convert::*;
+
+mod placement;
+pub use placement::*;
+
+mod request;
+pub use request::*;
+
+mod response;
+pub use response::*;
+
+/// The version of OpenRTB written by the converters.
+pub const VERSION: &str = "3.0";
+
+/// The domain specification written by the converters.
+pub const DOMAIN_SPEC: &str = "adcom";
+
+/// The version of the domain specification written by the converters.
+pub const DOMAIN_VERSION: &str = "1.0";
+
+/// A field of the converted object which has no counterpart in the other version.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Unmapped {
+    /// Path of the field in the converted object, e.g. `imp[0].banner.btype`.
+    pub path: String,
+}
+
+/// The error type for conversions from 3.0.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Error {
+    /// The envelope has no request.
+    MissingRequest,
+    /// The envelope has no response.
+    MissingResponse,
+    /// The domain specification is not AdCOM.
+    DomainSpec(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingRequest => write!(f, "openrtb envelope has no request"),
+            Self::MissingResponse => write!(f, "openrtb envelope has no response"),
+            Self::DomainSpec(spec) => write!(f, "unsupported domain specification: {}", spec),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,267 @@
+/// Object: Ad
+///
+/// This object is the root of a structure that defines an instance of advertising media. It
+/// includes metadata about the ad overall and sub-objects that provide additional detail specific
+/// to the type of media comprising the creative.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Ad<'a> {
+    /// string; recommended
+    /// ID of the creative; unique only to the extent of the buyer.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+
+    /// string array
+    /// Advertiser domain; top two levels only (e.g., “ford.com”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub adomain: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// string array
+    /// When the product of the ad is an app, the unique ID of that app as a bundle or package name
+    /// (e.g., “com.foo.mygame”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub bundle: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// string
+    /// URL without cache-busting to an image that is representative of the ad content for cases
+    /// where exchanges are unable to scan markup.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub iurl: Option<std::borrow::Cow<'a, str>>,
+
+    /// string array
+    /// Array of content categories describing the ad using IDs from the taxonomy indicated in
+    /// cattax.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub cat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer; default 2
+    /// The taxonomy in use for the cat attribute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
+    /// string
+    /// Language of the creative using ISO-639-1-alpha-2.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer array
+    /// Set of attributes describing the creative.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attr: Option<Vec<crate::CreativeAttribute>>,
+
+    /// integer
+    /// Flag to indicate if the creative is secure, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secure: Option<json_ext::Flag>,
+
+    /// integer
+    /// Media rating per IQG guidelines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mrating: Option<crate::IqgMediaRating>,
+
+    /// integer
+    /// Timestamp of the original instantiation of this ad in Unix format (i.e., milliseconds
+    /// since the epoch).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init: Option<i64>,
+
+    /// integer
+    /// Timestamp of most recent modification to this ad in Unix format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lastmod: Option<i64>,
+
+    /// object
+    /// Media Subtype Object that indicates this is a display ad.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<crate::openrtb3::Display<'a>>,
+
+    /// object
+    /// Media Subtype Object that indicates this is a video ad.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub video: Option<crate::openrtb3::Video<'a>>,
+
+    /// object
+    /// Media Subtype Object that indicates this is an audio ad.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<crate::openrtb3::Audio<'a>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Display
+///
+/// This object provides additional detail about an ad specifically for display ads, including
+/// native ads whose markup is carried in adm.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Display<'a> {
+    /// string
+    /// Mime type of the ad (e.g., “image/jpeg”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer array
+    /// API required by the ad if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<crate::ApiFramework>>,
+
+    /// integer
+    /// Subtype of display creative, where 1 = HTML, 2 = AMPHTML, 3 = structured image object, 4 =
+    /// structured native object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<i32>,
+
+    /// integer
+    /// Absolute width of the creative in device independent pixels (DIPS).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub w: Option<i32>,
+
+    /// integer
+    /// Absolute height of the creative in device independent pixels (DIPS).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub h: Option<i32>,
+
+    /// integer
+    /// Relative width of the creative when expressing size as a ratio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wratio: Option<i32>,
+
+    /// integer
+    /// Relative height of the creative when expressing size as a ratio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hratio: Option<i32>,
+
+    /// string
+    /// URL of a page informing the user about a buyer’s targeting activity.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub r#priv: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// General display markup (e.g., HTML, AMPHTML) if not using a structured alternative.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub adm: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Optional means of retrieving display markup by reference; a URL that can return HTML,
+    /// AMPHTML, or a Native object.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub curl: Option<std::borrow::Cow<'a, str>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Video
+///
+/// This object provides additional detail about an ad specifically for video ads.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Video<'a> {
+    /// string array
+    /// Mime type(s) of the ad creative(s) (e.g., “video/mp4”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer array
+    /// API required by the ad if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<crate::ApiFramework>>,
+
+    /// integer; recommended
+    /// Subtype of video creative, e.g. the VAST version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<crate::Protocol>,
+
+    /// integer
+    /// Duration of the video creative in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dur: Option<i32>,
+
+    /// string
+    /// Video markup (e.g., VAST document).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub adm: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Optional means of retrieving markup by reference; a URL that can return video markup
+    /// (e.g., a VAST document).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub curl: Option<std::borrow::Cow<'a, str>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Audio
+///
+/// This object provides additional detail about an ad specifically for audio ads.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Audio<'a> {
+    /// string array
+    /// Mime type(s) of the ad creative(s) (e.g., “audio/mp4”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer array
+    /// API required by the ad if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<crate::ApiFramework>>,
+
+    /// integer; recommended
+    /// Subtype of audio creative, e.g. the DAAST version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<crate::Protocol>,
+
+    /// integer
+    /// Duration of the audio creative in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dur: Option<i32>,
+
+    /// string
+    /// Audio markup (e.g., DAAST document).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub adm: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Optional means of retrieving markup by reference; a URL that can return audio markup
+    /// (e.g., a DAAST document).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub curl: Option<std::borrow::Cow<'a, str>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        let json = "{}";
+        let o1 = Ad::default();
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<Ad>(json)?);
+
+        let json = r#"{"id":"1","video":{"ctype":3,"adm":"<VAST/>"}}"#;
+        let o1 = Ad {
+            id: Some("1".into()),
+            video: Some(Video {
+                ctype: Some(crate::Protocol::Vast3),
+                adm: Some("<VAST/>".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<Ad>(json)?);
+
+        Ok(())
+    }
+}
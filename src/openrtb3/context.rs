@@ -0,0 +1,739 @@
+/// Object: Context
+///
+/// The AdCOM context of the items of a request: the distribution channel, the user and the device
+/// through which the user interacts, the regulations in force and the restrictions on the ads.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Context<'a> {
+    /// object
+    /// Details about the website through which the ad will be shown.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub site: Option<crate::openrtb3::Site<'a>>,
+
+    /// object
+    /// Details about the application through which the ad will be shown.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<crate::openrtb3::App<'a>>,
+
+    /// object
+    /// Details about the human user of the device; the advertising audience.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::openrtb3::User<'a>>,
+
+    /// object
+    /// Details about the device through which the ad will be shown.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub device: Option<crate::openrtb3::Device<'a>>,
+
+    /// object
+    /// Industry, legal or governmental regulations in force.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub regs: Option<crate::openrtb3::Regs<'a>>,
+
+    /// object
+    /// Restrictions on the ads that may be served.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub restrictions: Option<crate::openrtb3::Restrictions<'a>>,
+}
+
+/// Object: Site
+///
+/// This object is used to define an ad supported website, in contrast to a non-browser
+/// application, for example.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Site<'a> {
+    /// string; recommended
+    /// Vendor-specific unique identifier of the distribution channel.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Displayable name of the distribution channel.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<std::borrow::Cow<'a, str>>,
+
+    /// object
+    /// Details about the publisher of the distribution channel.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub r#pub: Option<crate::Publisher<'a>>,
+
+    /// object
+    /// Details about the content within the distribution channel.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<crate::openrtb3::Content<'a>>,
+
+    /// string
+    /// Domain of the site (e.g., “mysite.foo.com”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<std::borrow::Cow<'a, str>>,
+
+    /// string array
+    /// Array of content categories describing the site using IDs from the taxonomy indicated in
+    /// cattax.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub cat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// string array
+    /// Array of content categories describing the current section of the site using IDs from the
+    /// taxonomy indicated in cattax.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub sectcat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// string array
+    /// Array of content categories describing the current page or view of the site using IDs
+    /// from the taxonomy indicated in cattax.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub pagecat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer; default 2
+    /// The taxonomy in use for the cat, sectcat and pagecat attributes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
+    /// integer
+    /// Indicates if the site has a privacy policy, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privpolicy: Option<json_ext::Flag>,
+
+    /// string
+    /// Comma separated list of keywords about the site.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// URL of the page within the site.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Referrer URL that caused navigation to the current page.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub r#ref: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Search string that caused navigation to the current page.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Indicates if the site has been programmed to optimize layout when viewed on mobile
+    /// devices, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<json_ext::Flag>,
+
+    /// integer
+    /// Indicates if the page is built with AMP HTML, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amp: Option<json_ext::Flag>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: App
+///
+/// This object is used to define an ad supported non-browser application, in contrast to a
+/// typical website.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct App<'a> {
+    /// string; recommended
+    /// Vendor-specific unique identifier of the distribution channel.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Displayable name of the distribution channel.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<std::borrow::Cow<'a, str>>,
+
+    /// object
+    /// Details about the publisher of the distribution channel.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub r#pub: Option<crate::Publisher<'a>>,
+
+    /// object
+    /// Details about the content within the distribution channel.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<crate::openrtb3::Content<'a>>,
+
+    /// string
+    /// Domain of the application (e.g., “mygame.foo.com”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<std::borrow::Cow<'a, str>>,
+
+    /// string array
+    /// Array of content categories describing the app using IDs from the taxonomy indicated in
+    /// cattax.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub cat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// string array
+    /// Array of content categories describing the current section of the app using IDs from the
+    /// taxonomy indicated in cattax.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub sectcat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// string array
+    /// Array of content categories describing the current page or view of the app using IDs from
+    /// the taxonomy indicated in cattax.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub pagecat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer; default 2
+    /// The taxonomy in use for the cat, sectcat and pagecat attributes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
+    /// integer
+    /// Indicates if the app has a privacy policy, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub privpolicy: Option<json_ext::Flag>,
+
+    /// string
+    /// Comma separated list of keywords about the app.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// A platform-specific application identifier intended to be unique to the app and
+    /// independent of the exchange. On Android, this should be a bundle or package name (e.g.,
+    /// com.foo.mygame). On iOS, it is a numeric ID.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub bundle: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// App store ID of the application.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub storeid: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// App store URL for an installed app; for IQG 2.1 compliance.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub storeurl: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Application version.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ver: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Indicates if the app is free or paid, where 0 = free, 1 = paid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paid: Option<json_ext::Flag>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Content
+///
+/// This object describes the content in which an ad will appear, which may be syndicated or
+/// non-syndicated content.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Content<'a> {
+    /// string
+    /// ID uniquely identifying the content.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Episode number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub episode: Option<i32>,
+
+    /// string
+    /// Content title.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Content series.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub series: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Content season (e.g., “Season 3”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub season: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Artist credited with the content.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub artist: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Genre that best describes the content (e.g., rock, pop, etc).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub genre: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Album to which the content belongs; typically for audio.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub album: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// International Standard Recording Code conforming to ISO-3901.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub isrc: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// URL of the content, for buy-side contextualization or review.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<std::borrow::Cow<'a, str>>,
+
+    /// string array
+    /// Array of categories describing the content using IDs from the taxonomy indicated in
+    /// cattax.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub cat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer; default 2
+    /// The taxonomy in use for the cat attribute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
+    /// integer
+    /// Production quality.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prodq: Option<crate::ProductionQuality>,
+
+    /// integer
+    /// Type of content (game, video, text, etc.).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<crate::ContentContext>,
+
+    /// string
+    /// Content rating (e.g., MPAA).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// User rating of the content (e.g., number of stars, likes, etc.).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub urating: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Media rating per IQG guidelines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mrating: Option<crate::IqgMediaRating>,
+
+    /// string
+    /// Comma separated list of keywords describing the content.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Indicator of whether or not the content is live, where 0 = not live, 1 = live.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub live: Option<json_ext::Flag>,
+
+    /// integer
+    /// Source relationship, where 0 = indirect, 1 = direct.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub srcrel: Option<crate::SourceRelationship>,
+
+    /// integer
+    /// Length of content in seconds; typically used for video or audio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub len: Option<i32>,
+
+    /// string
+    /// Content language using ISO-639-1-alpha-2.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Indicator of whether or not the content is embeddable (e.g., an embeddable video player),
+    /// where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<json_ext::Flag>,
+
+    /// object
+    /// Details about the content producer.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub producer: Option<crate::Producer<'a>>,
+
+    /// object array
+    /// Additional user data. Each Data object represents a different data source.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<crate::Data<'a>>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Device
+///
+/// This object provides information pertaining to the device through which the user is
+/// interacting. Device information includes its hardware, platform, location, and carrier data.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Device<'a> {
+    /// integer
+    /// The general type of device.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<crate::DeviceType>,
+
+    /// string
+    /// Browser user agent string.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ua: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// ID sanctioned for advertiser use in the clear (i.e., not hashed).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ifa: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Standard “Do Not Track” option as set in the header by the browser, where 0 = tracking is
+    /// unrestricted, 1 = do not track.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dnt: Option<json_ext::Flag>,
+
+    /// integer
+    /// “Limit Ad Tracking” signal commercially endorsed (e.g., iOS, Android), where 0 = tracking
+    /// is unrestricted, 1 = tracking must be limited per commercial guidelines.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lmt: Option<json_ext::Flag>,
+
+    /// string
+    /// Device make (e.g., “Apple”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub make: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Device model (e.g., “iPhone10,1” when the specific version of the iPhone is known).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Device operating system, e.g. 2 = Android, 13 = iOS, 28 = Windows. Refer to the Operating
+    /// Systems list of AdCOM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub os: Option<i32>,
+
+    /// string
+    /// Device operating system version (e.g., “3.1.2”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub osv: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Hardware version of the device (e.g., “5S” for iPhone 5S).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub hwv: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Physical height of the screen in pixels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub h: Option<i32>,
+
+    /// integer
+    /// Physical width of the screen in pixels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub w: Option<i32>,
+
+    /// integer
+    /// Screen size as pixels per linear inch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ppi: Option<i32>,
+
+    /// float
+    /// The ratio of physical pixels to device independent pixels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pxratio: Option<f32>,
+
+    /// integer
+    /// Support for JavaScript, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub js: Option<json_ext::Flag>,
+
+    /// string
+    /// Browser language using ISO-639-1-alpha-2.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// IPv4 address closest to device.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// IP address closest to device as IPv6.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ipv6: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// The value of the “x-forwarded-for” header.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub xff: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Indicator of truncation of any of the IP attributes (i.e., ip, ipv6, xff), where 0 = no, 1
+    /// = yes (e.g., from 1.2.3.4 to 1.2.3.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iptr: Option<json_ext::Flag>,
+
+    /// string
+    /// Carrier or ISP (e.g., “VERIZON”) using exchange curated string names which should be
+    /// published to bidders a priori.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Mobile carrier as the concatenated MCC-MNC code (e.g., “310-005” identifies Verizon
+    /// Wireless CDMA in the USA).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub mccmnc: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// MCC and MNC of the SIM card using the same format as mccmnc.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub mccmncsim: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Network connection type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contype: Option<crate::ConnectionType>,
+
+    /// integer
+    /// Indicates if the geolocation API will be available to JavaScript code running in display
+    /// ad, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geofetch: Option<json_ext::Flag>,
+
+    /// object
+    /// Location of the device (i.e., typically the user’s current location).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub geo: Option<crate::openrtb3::Geo<'a>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Geo
+///
+/// This object encapsulates various methods for specifying a geographic location.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Geo<'a> {
+    /// integer
+    /// Source of location data; recommended when passing lat/lon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<crate::LocationType>,
+
+    /// float
+    /// Latitude from -90.0 to +90.0, where negative is south.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lat: Option<f32>,
+
+    /// float
+    /// Longitude from -180.0 to +180.0, where negative is west.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lon: Option<f32>,
+
+    /// integer
+    /// Estimated location accuracy in meters; recommended when lat/lon are specified and derived
+    /// from a device’s location services (i.e., type = 1).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accur: Option<i32>,
+
+    /// integer
+    /// Number of seconds since this geolocation fix was established.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lastfix: Option<i32>,
+
+    /// integer
+    /// Service or provider used to determine geolocation from IP address if applicable (i.e.,
+    /// type = 2).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ipserv: Option<crate::IpLocationService>,
+
+    /// string
+    /// Country code using ISO-3166-1-alpha-2.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Region code using ISO-3166-2; 2-letter state code if USA.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Regional marketing areas such as Nielsen’s DMA codes or other similar taxonomy to be agreed
+    /// among vendors prior to use.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub metro: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// City using United Nations Code for Trade & Transport Locations “UN/LOCODE” with the space
+    /// between country and city suppressed (e.g., Boston MA, USA = “USBOS”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Zip or postal code.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub zip: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Local time as the number +/- of minutes from UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utcoffset: Option<i32>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: User
+///
+/// This object contains information known or derived about the human user of the device (i.e.,
+/// the audience for advertising).
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct User<'a> {
+    /// string; recommended
+    /// Vendor-specific ID for the user.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; recommended
+    /// Buyer-specific ID for the user as mapped by an exchange for the buyer.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub buyeruid: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Year of birth as a 4-digit integer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yob: Option<i32>,
+
+    /// string
+    /// Gender, where “M” = male, “F” = female, “O” = known to be other.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gender: Option<crate::Gender>,
+
+    /// string
+    /// Comma separated list of keywords, interests, or intent.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// GDPR consent string if applicable, complying with the IAB standard Consent
+    /// String Format in the Transparency and Consent Framework technical specifications.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub consent: Option<std::borrow::Cow<'a, str>>,
+
+    /// object
+    /// Location of the user’s home base (i.e., not necessarily their current location).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub geo: Option<crate::openrtb3::Geo<'a>>,
+
+    /// object array
+    /// Additional user data. Each Data object represents a different data source.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<crate::Data<'a>>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Regs
+///
+/// This object contains any legal, governmental, or industry regulations that the sender deems
+/// applicable to the request.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Regs<'a> {
+    /// integer
+    /// Flag indicating if this request is subject to the COPPA regulations established by the USA
+    /// FTC, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coppa: Option<json_ext::Flag>,
+
+    /// integer
+    /// Flag that indicates whether or not the request is subject to GDPR regulations, where 0 =
+    /// no, 1 = yes, and omission indicates unknown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gdpr: Option<json_ext::Flag>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Restrictions
+///
+/// This object allows lists of restrictions on the ads that may be shown to be specified.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Restrictions<'a> {
+    /// string array
+    /// Block list of content categories using IDs from the taxonomy indicated in cattax.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub bcat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer; default 2
+    /// The taxonomy in use for the bcat attribute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
+    /// string array
+    /// Block list of advertisers by their domains (e.g., “ford.com”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub badv: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// string array
+    /// Block list of apps for which ads are disallowed.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub bapp: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer array
+    /// Block list of creative attributes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub battr: Option<Vec<crate::CreativeAttribute>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        let json = "{}";
+        let o1 = Context::default();
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<Context>(json)?);
+
+        let json = r#"{"site":{"pub":{"id":"1"}},"device":{"type":4,"os":3}}"#;
+        let o1 = Context {
+            site: Some(Site {
+                r#pub: Some(crate::Publisher {
+                    id: Some("1".into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            device: Some(Device {
+                r#type: Some(crate::DeviceType::Phone),
+                os: Some(3),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<Context>(json)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2026 @@
+use crate::openrtb3 as v3;
+use crate::openrtb3::{Error, Unmapped};
+
+/// Converts a 2.5 bid request into a 3.0 envelope carrying the request.
+///
+/// The unmapped paths are relative to the `BidRequest`, e.g. `imp[0].banner.btype`.
+pub fn from_bid_request<'a>(req: &crate::BidRequest<'a>) -> (v3::Openrtb<'a>, Vec<Unmapped>) {
+    let mut report = Report::default();
+
+    let (seat, wseat) = match (&req.wseat, &req.bseat) {
+        (Some(wseat), bseat) => {
+            report.check("", "bseat", bseat);
+            (Some(wseat.clone()), None)
+        }
+        (None, Some(bseat)) => (Some(bseat.clone()), Some(crate::ext::flag(false))),
+        (None, None) => (None, None),
+    };
+
+    let item = req
+        .imp
+        .iter()
+        .enumerate()
+        .map(|(i, imp)| upgrade_imp(imp, req.wlang.as_ref(), &format!("imp[{}]", i), &mut report))
+        .collect();
+
+    let mut context = v3::Context {
+        device: req
+            .device
+            .as_ref()
+            .map(|device| upgrade_device(device, "device", &mut report)),
+        user: req.user.as_ref().map(|user| upgrade_user(user, "user", &mut report)),
+        regs: req.regs.as_ref().map(upgrade_regs),
+        ..Default::default()
+    };
+    match &req.channel {
+        Some(crate::DistributionChannel::Site(site)) => context.site = Some(upgrade_site(site, "site", &mut report)),
+        Some(crate::DistributionChannel::App(app)) => context.app = Some(upgrade_app(app, "app", &mut report)),
+        None => {}
+    }
+    let battr = report.blocked_attributes();
+    if req.bcat.is_some() || req.badv.is_some() || req.bapp.is_some() || battr.is_some() {
+        context.restrictions = Some(v3::Restrictions {
+            bcat: req.bcat.clone(),
+            cattax: upgrade_cattax(req.cattax, req.bcat.is_some()),
+            badv: req.badv.clone(),
+            bapp: req.bapp.clone(),
+            battr,
+            ext: None,
+        });
+    } else {
+        report.check("", "cattax", &req.cattax);
+    }
+
+    let request = v3::Request {
+        id: req.id.clone(),
+        test: req.test,
+        tmax: req.tmax,
+        at: req.at,
+        cur: req.cur.clone(),
+        seat,
+        wseat,
+        cdata: req.user.as_ref().and_then(|user| user.customdata.clone()),
+        source: req
+            .source
+            .as_ref()
+            .map(|source| upgrade_source(source, "source", &mut report)),
+        item,
+        package: req.allimps,
+        context: (context != v3::Context::default()).then_some(context),
+        ext: req.ext.clone(),
+    };
+
+    (envelope(Some(request), None), report.unmapped)
+}
+
+/// Converts the request of a 3.0 envelope into a 2.5 bid request.
+///
+/// The unmapped paths are relative to the envelope, e.g. `request.item[0].spec.placement.ssai`.
+pub fn to_bid_request<'a>(openrtb: &v3::Openrtb<'a>) -> Result<(crate::BidRequest<'a>, Vec<Unmapped>), Error> {
+    check_domain(openrtb)?;
+    let req = openrtb.request.as_ref().ok_or(Error::MissingRequest)?;
+    let mut report = Report::default();
+    let context = req.context.clone().unwrap_or_default();
+    let restrictions = context.restrictions.unwrap_or_default();
+    report.check("request.context.restrictions", "ext", &restrictions.ext);
+
+    let (wseat, bseat) = match req.wseat {
+        Some(flag) if !crate::ext::is_set(Some(flag)) => (None, req.seat.clone()),
+        _ => (req.seat.clone(), None),
+    };
+
+    let wlang = req.item.first().and_then(|item| item.spec.placement.wlang.clone());
+    let imp = req
+        .item
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let path = format!("request.item[{}]", i);
+            if item.spec.placement.wlang != wlang {
+                report.push(&path, "spec.placement.wlang");
+            }
+            downgrade_item(item, restrictions.battr.as_ref(), &path, &mut report)
+        })
+        .collect();
+
+    let channel = match (&context.site, &context.app) {
+        (Some(site), app) => {
+            report.check("request.context", "app", app);
+            Some(crate::DistributionChannel::Site(downgrade_site(
+                site,
+                "request.context.site",
+                &mut report,
+            )))
+        }
+        (None, Some(app)) => Some(crate::DistributionChannel::App(downgrade_app(
+            app,
+            "request.context.app",
+            &mut report,
+        ))),
+        (None, None) => None,
+    };
+
+    let mut user = context
+        .user
+        .as_ref()
+        .map(|user| downgrade_user(user, "request.context.user", &mut report));
+    if let Some(cdata) = &req.cdata {
+        user.get_or_insert_with(Default::default).customdata = Some(cdata.clone());
+    }
+
+    let bid_request = crate::BidRequest {
+        id: req.id.clone(),
+        imp,
+        channel,
+        device: context
+            .device
+            .as_ref()
+            .map(|device| downgrade_device(device, "request.context.device", &mut report)),
+        user,
+        test: req.test,
+        at: req.at,
+        tmax: req.tmax,
+        wseat,
+        bseat,
+        allimps: req.package,
+        cur: req.cur.clone(),
+        wlang,
+        bcat: restrictions.bcat.clone(),
+        cattax: downgrade_cattax(restrictions.cattax, restrictions.bcat.is_some()),
+        badv: restrictions.badv.clone(),
+        bapp: restrictions.bapp.clone(),
+        source: req
+            .source
+            .as_ref()
+            .map(|source| downgrade_source(source, "request.source", &mut report)),
+        regs: context
+            .regs
+            .as_ref()
+            .map(|regs| downgrade_regs(regs, "request.context.regs", &mut report)),
+        ext: req.ext.clone(),
+    };
+
+    Ok((bid_request, report.unmapped))
+}
+
+/// Converts a 2.5 bid response into a 3.0 envelope carrying the response.
+///
+/// The kind of media of each bid is guessed from its markup, or from its protocol if the markup
+/// is not returned inline. The unmapped paths are relative to the `BidResponse`, e.g.
+/// `seatbid[0].bid[0].w` for a video bid.
+pub fn from_bid_response<'a>(res: &crate::BidResponse<'a>) -> (v3::Openrtb<'a>, Vec<Unmapped>) {
+    let mut report = Report::default();
+
+    let seatbid = res.seatbid.as_ref().map(|seatbids| {
+        seatbids
+            .iter()
+            .enumerate()
+            .map(|(i, seatbid)| v3::Seatbid {
+                seat: seatbid.seat.clone(),
+                package: seatbid.group,
+                bid: seatbid
+                    .bid
+                    .iter()
+                    .enumerate()
+                    .map(|(j, bid)| upgrade_bid(bid, &format!("seatbid[{}].bid[{}]", i, j), &mut report))
+                    .collect(),
+                ext: seatbid.ext.clone(),
+            })
+            .collect()
+    });
+
+    let response = v3::Response {
+        id: res.id.clone(),
+        bidid: res.bidid.clone(),
+        nbr: res.nbr,
+        cur: res.cur.clone(),
+        cdata: res.customdata.clone(),
+        seatbid,
+        ext: res.ext.clone(),
+    };
+
+    (envelope(None, Some(response)), report.unmapped)
+}
+
+/// Converts the response of a 3.0 envelope into a 2.5 bid response.
+///
+/// The unmapped paths are relative to the envelope, e.g. `response.seatbid[0].bid[0].macro`.
+pub fn to_bid_response<'a>(openrtb: &v3::Openrtb<'a>) -> Result<(crate::BidResponse<'a>, Vec<Unmapped>), Error> {
+    check_domain(openrtb)?;
+    let res = openrtb.response.as_ref().ok_or(Error::MissingResponse)?;
+    let mut report = Report::default();
+
+    let seatbid = res.seatbid.as_ref().map(|seatbids| {
+        seatbids
+            .iter()
+            .enumerate()
+            .map(|(i, seatbid)| crate::SeatBid {
+                bid: seatbid
+                    .bid
+                    .iter()
+                    .enumerate()
+                    .map(|(j, bid)| {
+                        let path = format!("response.seatbid[{}].bid[{}]", i, j);
+                        downgrade_bid(bid, &path, &mut report)
+                    })
+                    .collect(),
+                seat: seatbid.seat.clone(),
+                group: seatbid.package,
+                ext: seatbid.ext.clone(),
+            })
+            .collect()
+    });
+
+    let bid_response = crate::BidResponse {
+        id: res.id.clone(),
+        seatbid,
+        bidid: res.bidid.clone(),
+        cur: res.cur.clone(),
+        customdata: res.cdata.clone(),
+        nbr: res.nbr,
+        ext: res.ext.clone(),
+    };
+
+    Ok((bid_response, report.unmapped))
+}
+
+/// Collects the unmapped fields of a conversion.
+#[derive(Default)]
+struct Report {
+    unmapped: Vec<Unmapped>,
+    battr: Vec<(String, Vec<crate::CreativeAttribute>)>,
+}
+
+impl Report {
+    fn push(&mut self, path: &str, field: &str) {
+        self.unmapped.push(Unmapped {
+            path: join(path, field),
+        });
+    }
+
+    fn check<T>(&mut self, path: &str, field: &str, value: &Option<T>) {
+        if value.is_some() {
+            self.push(path, field);
+        }
+    }
+
+    fn keys(&mut self, path: &str, other: &serde_json::Map<String, serde_json::Value>) {
+        for key in other.keys() {
+            self.push(path, key);
+        }
+    }
+
+    /// Records the blocked attributes of a 2.5 media object, which 3.0 only has per request.
+    fn battr(&mut self, path: &str, battr: Option<&Vec<crate::CreativeAttribute>>) {
+        if let Some(battr) = battr {
+            self.battr.push((join(path, "battr"), battr.clone()));
+        }
+    }
+
+    /// Returns the union of the recorded blocked attributes, reporting the narrower lists.
+    fn blocked_attributes(&mut self) -> Option<Vec<crate::CreativeAttribute>> {
+        let mut union: Vec<crate::CreativeAttribute> = Vec::new();
+        for attr in self.battr.iter().flat_map(|(_, battr)| battr) {
+            if !union.contains(attr) {
+                union.push(*attr);
+            }
+        }
+        for (path, battr) in std::mem::take(&mut self.battr) {
+            if union.iter().any(|attr| !battr.contains(attr)) {
+                self.unmapped.push(Unmapped { path });
+            }
+        }
+        (!union.is_empty()).then_some(union)
+    }
+}
+
+fn join(path: &str, field: &str) -> String {
+    match path.is_empty() {
+        true => field.to_owned(),
+        false => format!("{}.{}", path, field),
+    }
+}
+
+fn envelope<'a>(request: Option<v3::Request<'a>>, response: Option<v3::Response<'a>>) -> v3::Openrtb<'a> {
+    v3::Openrtb {
+        ver: v3::VERSION.into(),
+        domainspec: Some(v3::DOMAIN_SPEC.into()),
+        domainver: v3::DOMAIN_VERSION.into(),
+        request,
+        response,
+    }
+}
+
+fn check_domain(openrtb: &v3::Openrtb) -> Result<(), Error> {
+    match openrtb.domainspec.as_deref() {
+        None | Some(v3::DOMAIN_SPEC) => Ok(()),
+        Some(spec) => Err(Error::DomainSpec(spec.to_owned())),
+    }
+}
+
+/// Makes the 2.5 default taxonomy explicit, 3.0 defaulting to IAB Content Category Taxonomy 2.0.
+fn upgrade_cattax(cattax: Option<crate::CategoryTaxonomy>, cat: bool) -> Option<crate::CategoryTaxonomy> {
+    cattax.or(cat.then_some(crate::CategoryTaxonomy::IabContent1_0))
+}
+
+/// Makes the 3.0 default taxonomy explicit, omitting the 2.5 default.
+fn downgrade_cattax(cattax: Option<crate::CategoryTaxonomy>, cat: bool) -> Option<crate::CategoryTaxonomy> {
+    match cattax.or(cat.then_some(crate::CategoryTaxonomy::IabContent2_0)) {
+        Some(crate::CategoryTaxonomy::IabContent1_0) => None,
+        cattax => cattax,
+    }
+}
+
+fn object<'a>(value: Option<serde_json::Value>) -> Option<json_ext::Object<'a>> {
+    value.and_then(|value| serde::Deserialize::deserialize(value).ok())
+}
+
+fn value(object: &Option<json_ext::Object>) -> Option<serde_json::Value> {
+    object.as_ref().and_then(|object| serde_json::to_value(object).ok())
+}
+
+fn upgrade_imp<'a>(
+    imp: &crate::Imp<'a>,
+    wlang: Option<&Vec<std::borrow::Cow<'a, str>>>,
+    path: &str,
+    report: &mut Report,
+) -> v3::Item<'a> {
+    let mut display = None;
+    if let Some(banner) = &imp.banner {
+        let path = join(path, "banner");
+        report.check(&path, "id", &banner.id);
+        report.check(&path, "vcm", &banner.vcm);
+        report.battr(&path, banner.battr.as_ref());
+        display = Some(upgrade_banner(banner, &path, report));
+    }
+    if let Some(native) = &imp.native {
+        let path = join(path, "native");
+        let display = display.get_or_insert_with(v3::DisplayPlacement::default);
+        upgrade_native(native, display, &path, report);
+    }
+
+    let clktype = imp
+        .clickbrowser
+        .map(|flag| if crate::ext::is_set(Some(flag)) { 3 } else { 2 });
+    match &mut display {
+        Some(display) => {
+            display.instl = imp.instl;
+            display.ifrbust = imp.iframebuster.clone();
+            display.clktype = clktype;
+        }
+        None => {
+            report.check(path, "instl", &imp.instl);
+            report.check(path, "iframebuster", &imp.iframebuster);
+        }
+    }
+
+    let video = imp.video.as_ref().map(|video| {
+        let path = join(path, "video");
+        report.battr(&path, video.battr.as_ref());
+        v3::VideoPlacement {
+            clktype,
+            ..upgrade_video(video, &path, report)
+        }
+    });
+    let audio = imp.audio.as_ref().map(|audio| {
+        let path = join(path, "audio");
+        report.battr(&path, audio.battr.map(|battr| vec![battr]).as_ref());
+        v3::AudioPlacement {
+            clktype,
+            ..upgrade_audio(audio, &path, report)
+        }
+    });
+    if display.is_none() && video.is_none() && audio.is_none() {
+        report.check(path, "clickbrowser", &imp.clickbrowser);
+    }
+
+    let pmp = imp.pmp.as_ref();
+    if let Some(pmp) = pmp {
+        report.check(&join(path, "pmp"), "ext", &pmp.ext);
+    }
+
+    v3::Item {
+        id: imp.id.clone(),
+        flr: imp.bidfloor,
+        flrcur: imp.bidfloorcur.clone(),
+        exp: imp.exp,
+        metric: imp.metric.clone(),
+        deal: pmp
+            .and_then(|pmp| pmp.deals.as_ref())
+            .map(|deals| deals.iter().map(upgrade_deal).collect()),
+        private: pmp.and_then(|pmp| pmp.private_auction),
+        spec: v3::Spec {
+            placement: v3::Placement {
+                tagid: imp.tagid.clone(),
+                sdk: imp.displaymanager.clone(),
+                sdkver: imp.displaymanagerver.clone(),
+                wlang: wlang.cloned(),
+                secure: imp.secure,
+                display,
+                video,
+                audio,
+                ..Default::default()
+            },
+        },
+        ext: imp.ext.clone(),
+        ..Default::default()
+    }
+}
+
+fn downgrade_item<'a>(
+    item: &v3::Item<'a>,
+    battr: Option<&Vec<crate::CreativeAttribute>>,
+    path: &str,
+    report: &mut Report,
+) -> crate::Imp<'a> {
+    if item.qty.is_some_and(|qty| qty != 1) {
+        report.push(path, "qty");
+    }
+    report.check(path, "seq", &item.seq);
+    report.check(path, "dt", &item.dt);
+    if item.dlvy.is_some_and(|dlvy| dlvy != 0) {
+        report.push(path, "dlvy");
+    }
+
+    let placement = &item.spec.placement;
+    let ppath = join(path, "spec.placement");
+    report.check(&ppath, "ssai", &placement.ssai);
+    report.check(&ppath, "reward", &placement.reward);
+    report.check(&ppath, "admx", &placement.admx);
+    report.check(&ppath, "curlx", &placement.curlx);
+    report.check(&ppath, "ext", &placement.ext);
+
+    let mut clktypes = Vec::new();
+    let (banner, native) = match &placement.display {
+        Some(display) => {
+            let path = join(&ppath, "display");
+            clktypes.push((join(&path, "clktype"), display.clktype));
+            downgrade_display(display, battr, &path, report)
+        }
+        None => (None, None),
+    };
+    let video = placement.video.as_ref().map(|video| {
+        let path = join(&ppath, "video");
+        clktypes.push((join(&path, "clktype"), video.clktype));
+        crate::Video {
+            battr: battr.cloned(),
+            ..downgrade_video(video, &path, report)
+        }
+    });
+    let audio = placement.audio.as_ref().map(|audio| {
+        let path = join(&ppath, "audio");
+        clktypes.push((join(&path, "clktype"), audio.clktype));
+        if battr.is_some_and(|battr| battr.len() > 1) {
+            report.push("request.context.restrictions", "battr");
+        }
+        crate::Audio {
+            battr: battr.and_then(|battr| battr.first().copied()),
+            ..downgrade_audio(audio, &path, report)
+        }
+    });
+
+    let mut clickbrowser = None;
+    for (path, clktype) in clktypes {
+        let flag = match clktype {
+            None => continue,
+            Some(2) => false,
+            Some(3) => true,
+            Some(_) => {
+                report.unmapped.push(Unmapped { path });
+                continue;
+            }
+        };
+        match clickbrowser {
+            None => clickbrowser = Some(flag),
+            Some(previous) if previous != flag => report.unmapped.push(Unmapped { path }),
+            Some(_) => {}
+        }
+    }
+
+    let deals = item.deal.as_ref().map(|deals| {
+        deals
+            .iter()
+            .enumerate()
+            .map(|(i, deal)| downgrade_deal(deal, &format!("{}.deal[{}]", path, i), report))
+            .collect()
+    });
+    let pmp = (deals.is_some() || item.private.is_some()).then_some(crate::Pmp {
+        private_auction: item.private,
+        deals,
+        ext: None,
+    });
+
+    let display = placement.display.as_ref();
+    crate::Imp {
+        id: item.id.clone(),
+        metric: item.metric.clone(),
+        banner,
+        video,
+        audio,
+        native,
+        pmp,
+        displaymanager: placement.sdk.clone(),
+        displaymanagerver: placement.sdkver.clone(),
+        instl: display.and_then(|display| display.instl),
+        tagid: placement.tagid.clone(),
+        bidfloor: item.flr,
+        bidfloorcur: item.flrcur.clone(),
+        clickbrowser: clickbrowser.map(crate::ext::flag),
+        secure: placement.secure,
+        iframebuster: display.and_then(|display| display.ifrbust.clone()),
+        exp: item.exp,
+        ext: item.ext.clone(),
+    }
+}
+
+fn upgrade_deal<'a>(deal: &crate::Deal<'a>) -> v3::Deal<'a> {
+    v3::Deal {
+        id: deal.id.clone(),
+        flr: deal.bidfloor,
+        flrcur: deal.bidfloorcur.clone(),
+        at: deal.at.map(|at| match at {
+            crate::AuctionType::FirstPrice => 1,
+            crate::AuctionType::SecondPricePlus => 2,
+            crate::AuctionType::ExchangeSpecific(at) => at,
+        }),
+        wseat: deal.wseat.clone(),
+        wadomain: deal.wadomain.clone(),
+        ext: deal.ext.clone(),
+    }
+}
+
+fn downgrade_deal<'a>(deal: &v3::Deal<'a>, path: &str, report: &mut Report) -> crate::Deal<'a> {
+    let at = deal.at.and_then(|at| {
+        let at = serde_json::from_value(at.into()).ok();
+        if at.is_none() {
+            report.push(path, "at");
+        }
+        at
+    });
+    crate::Deal {
+        id: deal.id.clone(),
+        bidfloor: deal.flr,
+        bidfloorcur: deal.flrcur.clone(),
+        at,
+        wseat: deal.wseat.clone(),
+        wadomain: deal.wadomain.clone(),
+        ext: deal.ext.clone(),
+    }
+}
+
+/// Maps the fields shared by imp banners and companion ads.
+#[allow(deprecated)]
+fn upgrade_banner<'a>(banner: &crate::Banner<'a>, path: &str, report: &mut Report) -> v3::DisplayPlacement<'a> {
+    report.check(path, "wmax", &banner.wmax);
+    report.check(path, "hmax", &banner.hmax);
+    report.check(path, "wmin", &banner.wmin);
+    report.check(path, "hmin", &banner.hmin);
+    report.check(path, "btype", &banner.btype);
+    if banner.format.is_none() {
+        report.check(path, "expdir", &banner.expdir);
+    }
+
+    let displayfmt = banner.format.as_ref().map(|formats| {
+        formats
+            .iter()
+            .enumerate()
+            .map(|(i, format)| {
+                report.check(&format!("{}.format[{}]", path, i), "wmin", &format.wmin);
+                v3::DisplayFormat {
+                    w: format.w,
+                    h: format.h,
+                    wratio: format.wratio,
+                    hratio: format.hratio,
+                    expdir: banner.expdir.clone(),
+                    ext: format.ext.clone(),
+                }
+            })
+            .collect()
+    });
+
+    v3::DisplayPlacement {
+        pos: banner.pos,
+        topframe: banner.topframe,
+        mime: banner.mimes.clone(),
+        api: banner.api.clone(),
+        w: banner.w,
+        h: banner.h,
+        displayfmt,
+        ext: banner.ext.clone(),
+        ..Default::default()
+    }
+}
+
+/// Maps the banner fields of a display placement.
+fn downgrade_banner<'a>(display: &v3::DisplayPlacement<'a>, path: &str, report: &mut Report) -> crate::Banner<'a> {
+    let mut expdir = None;
+    let format = display.displayfmt.as_ref().map(|formats| {
+        formats
+            .iter()
+            .enumerate()
+            .map(|(i, format)| {
+                match i {
+                    0 => expdir = format.expdir.clone(),
+                    _ if format.expdir != expdir => report.push(&format!("{}.displayfmt[{}]", path, i), "expdir"),
+                    _ => {}
+                }
+                crate::Format {
+                    w: format.w,
+                    h: format.h,
+                    wratio: format.wratio,
+                    hratio: format.hratio,
+                    wmin: None,
+                    ext: format.ext.clone(),
+                }
+            })
+            .collect()
+    });
+
+    crate::Banner {
+        format,
+        w: display.w,
+        h: display.h,
+        pos: display.pos,
+        mimes: display.mime.clone(),
+        topframe: display.topframe,
+        expdir,
+        api: display.api.clone(),
+        ext: display.ext.clone(),
+        ..Default::default()
+    }
+}
+
+fn downgrade_display<'a>(
+    display: &v3::DisplayPlacement<'a>,
+    battr: Option<&Vec<crate::CreativeAttribute>>,
+    path: &str,
+    report: &mut Report,
+) -> (Option<crate::Banner<'a>>, Option<crate::Native<'a>>) {
+    report.check(path, "ampren", &display.ampren);
+    report.check(path, "ctype", &display.ctype);
+    if display.unit.is_some_and(|unit| unit != 1) {
+        report.push(path, "unit");
+    }
+
+    let native = display
+        .nativefmt
+        .as_ref()
+        .map(|nativefmt| downgrade_native(display, nativefmt, battr, path, report));
+    let is_banner = display.displayfmt.is_some() || display.w.is_some() || display.h.is_some() || native.is_none();
+    if native.is_none() {
+        report.check(path, "ptype", &display.ptype);
+        report.check(path, "context", &display.context);
+        report.check(path, "priv", &display.r#priv);
+    } else if !is_banner {
+        report.check(path, "pos", &display.pos);
+        report.check(path, "topframe", &display.topframe);
+        report.check(path, "mime", &display.mime);
+    }
+
+    match (is_banner, native) {
+        (true, native) => {
+            let banner = crate::Banner {
+                battr: battr.cloned(),
+                ..downgrade_banner(display, path, report)
+            };
+            (Some(banner), native)
+        }
+        (false, Some(native)) => (
+            None,
+            Some(crate::Native {
+                ext: display.ext.clone(),
+                ..native
+            }),
+        ),
+        (false, None) => (None, None),
+    }
+}
+
+fn upgrade_native<'a>(
+    native: &crate::Native<'a>,
+    display: &mut v3::DisplayPlacement<'a>,
+    path: &str,
+    report: &mut Report,
+) {
+    report.battr(path, native.battr.as_ref());
+    if let Some(api) = &native.api {
+        let apis = display.api.get_or_insert_with(Vec::new);
+        for api in api {
+            if !apis.contains(api) {
+                apis.push(*api);
+            }
+        }
+    }
+    match display.ext {
+        None => display.ext = native.ext.clone(),
+        Some(_) => report.check(path, "ext", &native.ext),
+    }
+
+    let request = match parse_native_request(&native.request) {
+        Some(request) => request,
+        None => {
+            report.push(path, "request");
+            display.nativefmt = Some(v3::NativeFormat::default());
+            return;
+        }
+    };
+    let path = join(path, "request");
+    report.keys(&path, &request.other);
+    display.context = request.context;
+    display.ptype = request.plcmttype;
+    display.r#priv = request.privacy;
+    display.nativefmt = Some(v3::NativeFormat {
+        asset: request
+            .assets
+            .into_iter()
+            .enumerate()
+            .map(|(i, asset)| upgrade_asset(asset, &format!("{}.assets[{}]", path, i), report))
+            .collect(),
+        ext: object(request.ext),
+    });
+}
+
+/// Parses a native request, either 1.0 (wrapped in a `native` object) or 1.1+.
+fn parse_native_request(request: &str) -> Option<NativeRequest> {
+    let value: serde_json::Value = serde_json::from_str(request).ok()?;
+    let value = match value {
+        serde_json::Value::Object(mut map) if map.get("native").is_some_and(|v| v.is_object()) => {
+            map.remove("native")?
+        }
+        value => value,
+    };
+    serde_json::from_value(value).ok()
+}
+
+fn downgrade_native<'a>(
+    display: &v3::DisplayPlacement<'a>,
+    nativefmt: &v3::NativeFormat<'a>,
+    battr: Option<&Vec<crate::CreativeAttribute>>,
+    path: &str,
+    report: &mut Report,
+) -> crate::Native<'a> {
+    let path = join(path, "nativefmt");
+    let request = NativeRequest {
+        ver: Some(NATIVE_VERSION.to_owned()),
+        context: display.context,
+        plcmttype: display.ptype,
+        privacy: display.r#priv,
+        assets: nativefmt
+            .asset
+            .iter()
+            .enumerate()
+            .map(|(i, asset)| downgrade_asset(asset, &format!("{}.asset[{}]", path, i), report))
+            .collect(),
+        ext: value(&nativefmt.ext),
+        other: Default::default(),
+    };
+    crate::Native {
+        request: serde_json::to_string(&request)
+            .expect("a native request serializes")
+            .into(),
+        ver: Some(NATIVE_VERSION.into()),
+        api: display.api.clone(),
+        battr: battr.cloned(),
+        ext: None,
+    }
+}
+
+fn upgrade_asset<'a>(asset: NativeAsset, path: &str, report: &mut Report) -> v3::AssetFormat<'a> {
+    report.keys(path, &asset.other);
+    v3::AssetFormat {
+        id: asset.id,
+        req: asset.required,
+        title: asset.title.map(|title| {
+            report.keys(&join(path, "title"), &title.other);
+            v3::TitleAssetFormat {
+                len: title.len,
+                ext: object(title.ext),
+            }
+        }),
+        img: asset.img.map(|img| {
+            report.keys(&join(path, "img"), &img.other);
+            v3::ImageAssetFormat {
+                r#type: img.r#type,
+                mime: img.mimes.map(|mimes| mimes.into_iter().map(Into::into).collect()),
+                w: img.w,
+                h: img.h,
+                wmin: img.wmin,
+                hmin: img.hmin,
+                wratio: None,
+                hratio: None,
+                ext: object(img.ext),
+            }
+        }),
+        video: asset.video.map(|video| {
+            report.keys(&join(path, "video"), &video.other);
+            v3::VideoPlacement {
+                mime: video.mimes.into_iter().map(Into::into).collect(),
+                mindur: video.minduration,
+                maxdur: video.maxduration,
+                ctype: video.protocols,
+                ext: object(video.ext),
+                ..Default::default()
+            }
+        }),
+        data: asset.data.map(|data| {
+            report.keys(&join(path, "data"), &data.other);
+            v3::DataAssetFormat {
+                r#type: data.r#type,
+                len: data.len,
+                ext: object(data.ext),
+            }
+        }),
+        ext: object(asset.ext),
+    }
+}
+
+fn downgrade_asset(asset: &v3::AssetFormat, path: &str, report: &mut Report) -> NativeAsset {
+    NativeAsset {
+        id: asset.id,
+        required: asset.req,
+        title: asset.title.as_ref().map(|title| NativeTitle {
+            len: title.len,
+            ext: value(&title.ext),
+            other: Default::default(),
+        }),
+        img: asset.img.as_ref().map(|img| {
+            let path = join(path, "img");
+            report.check(&path, "wratio", &img.wratio);
+            report.check(&path, "hratio", &img.hratio);
+            NativeImage {
+                r#type: img.r#type,
+                w: img.w,
+                h: img.h,
+                wmin: img.wmin,
+                hmin: img.hmin,
+                mimes: img
+                    .mime
+                    .as_ref()
+                    .map(|mime| mime.iter().map(|mime| mime.to_string()).collect()),
+                ext: value(&img.ext),
+                other: Default::default(),
+            }
+        }),
+        video: asset.video.as_ref().map(|video| {
+            let mapped = v3::VideoPlacement {
+                mime: video.mime.clone(),
+                mindur: video.mindur,
+                maxdur: video.maxdur,
+                ctype: video.ctype.clone(),
+                ext: video.ext.clone(),
+                ..Default::default()
+            };
+            if &mapped != video {
+                report.push(path, "video");
+            }
+            NativeVideo {
+                mimes: video.mime.iter().map(|mime| mime.to_string()).collect(),
+                minduration: video.mindur,
+                maxduration: video.maxdur,
+                protocols: video.ctype.clone(),
+                ext: value(&video.ext),
+                other: Default::default(),
+            }
+        }),
+        data: asset.data.as_ref().map(|data| NativeData {
+            r#type: data.r#type,
+            len: data.len,
+            ext: value(&data.ext),
+            other: Default::default(),
+        }),
+        ext: value(&asset.ext),
+        other: Default::default(),
+    }
+}
+
+fn upgrade_video<'a>(video: &crate::Video<'a>, path: &str, report: &mut Report) -> v3::VideoPlacement<'a> {
+    report.check(path, "sequence", &video.sequence);
+    if video.playbackmethod.as_ref().is_some_and(|methods| methods.len() > 1) {
+        report.push(path, "playbackmethod");
+    }
+    v3::VideoPlacement {
+        ptype: video.placement,
+        pos: video.pos,
+        delay: video.startdelay,
+        skip: video.skip,
+        skipmin: video.skipmin,
+        skipafter: video.skipafter,
+        playmethod: video
+            .playbackmethod
+            .as_ref()
+            .and_then(|methods| methods.first().copied()),
+        playend: video.playbackend,
+        mime: video.mimes.clone(),
+        api: video.api.clone(),
+        ctype: video.protocols.clone(),
+        w: video.w,
+        h: video.h,
+        mindur: video.minduration,
+        maxdur: video.maxduration,
+        maxext: video.maxextended,
+        minbr: video.minbitrate,
+        maxbr: video.maxbitrate,
+        delivery: video.delivery.clone(),
+        linear: video.linearity,
+        boxing: video.boxingallowed,
+        comp: upgrade_companions(video.companionad.as_ref(), &join(path, "companionad"), report),
+        comptype: video.companiontype.clone(),
+        ext: video.ext.clone(),
+        ..Default::default()
+    }
+}
+
+fn downgrade_video<'a>(video: &v3::VideoPlacement<'a>, path: &str, report: &mut Report) -> crate::Video<'a> {
+    report.check(path, "maxseq", &video.maxseq);
+    if video.unit.is_some_and(|unit| unit != 1) {
+        report.push(path, "unit");
+    }
+    crate::Video {
+        mimes: video.mime.clone(),
+        minduration: video.mindur,
+        maxduration: video.maxdur,
+        protocols: video.ctype.clone(),
+        w: video.w,
+        h: video.h,
+        startdelay: video.delay,
+        placement: video.ptype,
+        linearity: video.linear,
+        skip: video.skip,
+        skipmin: video.skipmin,
+        skipafter: video.skipafter,
+        maxextended: video.maxext,
+        minbitrate: video.minbr,
+        maxbitrate: video.maxbr,
+        boxingallowed: video.boxing,
+        playbackmethod: video.playmethod.map(|method| vec![method]),
+        playbackend: video.playend,
+        delivery: video.delivery.clone(),
+        pos: video.pos,
+        companionad: downgrade_companions(video.comp.as_ref(), &join(path, "comp"), report),
+        api: video.api.clone(),
+        companiontype: video.comptype.clone(),
+        ext: video.ext.clone(),
+        ..Default::default()
+    }
+}
+
+fn upgrade_audio<'a>(audio: &crate::Audio<'a>, path: &str, report: &mut Report) -> v3::AudioPlacement<'a> {
+    report.check(path, "sequence", &audio.sequence);
+    v3::AudioPlacement {
+        delay: audio.startdelay,
+        mime: audio.mimes.clone(),
+        api: audio.api.clone(),
+        ctype: audio.protocols.clone(),
+        mindur: audio.minduration,
+        maxdur: audio.maxduration,
+        maxext: audio.maxextended,
+        minbr: audio.minbitrate,
+        maxbr: audio.maxbitrate,
+        delivery: audio.delivery.clone(),
+        maxseq: audio.maxseq,
+        comp: upgrade_companions(audio.companionad.as_ref(), &join(path, "companionad"), report),
+        comptype: audio.companiontype.clone(),
+        feed: audio.feed,
+        stitched: audio.stitched,
+        nvol: audio.nvol,
+        ext: audio.ext.clone(),
+        ..Default::default()
+    }
+}
+
+fn downgrade_audio<'a>(audio: &v3::AudioPlacement<'a>, path: &str, report: &mut Report) -> crate::Audio<'a> {
+    report.check(path, "skip", &audio.skip);
+    report.check(path, "skipmin", &audio.skipmin);
+    report.check(path, "skipafter", &audio.skipafter);
+    report.check(path, "playmethod", &audio.playmethod);
+    report.check(path, "playend", &audio.playend);
+    crate::Audio {
+        mimes: audio.mime.clone(),
+        minduration: audio.mindur,
+        maxduration: audio.maxdur,
+        protocols: audio.ctype.clone(),
+        startdelay: audio.delay,
+        maxextended: audio.maxext,
+        minbitrate: audio.minbr,
+        maxbitrate: audio.maxbr,
+        delivery: audio.delivery.clone(),
+        companionad: downgrade_companions(audio.comp.as_ref(), &join(path, "comp"), report),
+        api: audio.api.clone(),
+        companiontype: audio.comptype.clone(),
+        maxseq: audio.maxseq,
+        feed: audio.feed,
+        stitched: audio.stitched,
+        nvol: audio.nvol,
+        ext: audio.ext.clone(),
+        ..Default::default()
+    }
+}
+
+fn upgrade_companions<'a>(
+    banners: Option<&Vec<crate::Banner<'a>>>,
+    path: &str,
+    report: &mut Report,
+) -> Option<Vec<v3::Companion<'a>>> {
+    banners.map(|banners| {
+        banners
+            .iter()
+            .enumerate()
+            .map(|(i, banner)| {
+                let path = format!("{}[{}]", path, i);
+                report.check(&path, "battr", &banner.battr);
+                v3::Companion {
+                    id: banner.id.clone(),
+                    vcm: banner.vcm,
+                    display: upgrade_banner(banner, &path, report),
+                    ext: None,
+                }
+            })
+            .collect()
+    })
+}
+
+fn downgrade_companions<'a>(
+    companions: Option<&Vec<v3::Companion<'a>>>,
+    path: &str,
+    report: &mut Report,
+) -> Option<Vec<crate::Banner<'a>>> {
+    companions.map(|companions| {
+        companions
+            .iter()
+            .enumerate()
+            .map(|(i, companion)| {
+                let path = format!("{}[{}]", path, i);
+                report.check(&path, "ext", &companion.ext);
+                let display = &companion.display;
+                let banner = v3::DisplayPlacement {
+                    pos: display.pos,
+                    topframe: display.topframe,
+                    mime: display.mime.clone(),
+                    api: display.api.clone(),
+                    w: display.w,
+                    h: display.h,
+                    displayfmt: display.displayfmt.clone(),
+                    ext: display.ext.clone(),
+                    ..Default::default()
+                };
+                if &banner != display {
+                    report.push(&path, "display");
+                }
+                crate::Banner {
+                    id: companion.id.clone(),
+                    vcm: companion.vcm,
+                    ..downgrade_banner(display, &join(&path, "display"), report)
+                }
+            })
+            .collect()
+    })
+}
+
+fn upgrade_site<'a>(site: &crate::Site<'a>, path: &str, report: &mut Report) -> v3::Site<'a> {
+    let cat = site.cat.is_some() || site.sectioncat.is_some() || site.pagecat.is_some();
+    v3::Site {
+        id: site.id.clone(),
+        name: site.name.clone(),
+        r#pub: site.publisher.as_ref().map(upgrade_publisher),
+        content: site
+            .content
+            .as_ref()
+            .map(|content| upgrade_content(content, &join(path, "content"), report)),
+        domain: site.domain.clone(),
+        cat: site.cat.clone(),
+        sectcat: site.sectioncat.clone(),
+        pagecat: site.pagecat.clone(),
+        cattax: upgrade_cattax(site.cattax, cat),
+        privpolicy: site.privacypolicy,
+        keywords: site.keywords.clone(),
+        page: site.page.clone(),
+        r#ref: site.r#ref.clone(),
+        search: site.search.clone(),
+        mobile: site.mobile,
+        amp: None,
+        ext: site.ext.clone(),
+    }
+}
+
+fn downgrade_site<'a>(site: &v3::Site<'a>, path: &str, report: &mut Report) -> crate::Site<'a> {
+    report.check(path, "amp", &site.amp);
+    let cat = site.cat.is_some() || site.sectcat.is_some() || site.pagecat.is_some();
+    crate::Site {
+        id: site.id.clone(),
+        name: site.name.clone(),
+        domain: site.domain.clone(),
+        cat: site.cat.clone(),
+        sectioncat: site.sectcat.clone(),
+        pagecat: site.pagecat.clone(),
+        cattax: downgrade_cattax(site.cattax, cat),
+        page: site.page.clone(),
+        r#ref: site.r#ref.clone(),
+        search: site.search.clone(),
+        mobile: site.mobile,
+        privacypolicy: site.privpolicy,
+        publisher: site.r#pub.as_ref().map(downgrade_publisher),
+        content: site.content.as_ref().map(downgrade_content),
+        keywords: site.keywords.clone(),
+        ext: site.ext.clone(),
+    }
+}
+
+fn upgrade_app<'a>(app: &crate::App<'a>, path: &str, report: &mut Report) -> v3::App<'a> {
+    let cat = app.cat.is_some() || app.sectioncat.is_some() || app.pagecat.is_some();
+    v3::App {
+        id: app.id.clone(),
+        name: app.name.clone(),
+        r#pub: app.publisher.as_ref().map(upgrade_publisher),
+        content: app
+            .content
+            .as_ref()
+            .map(|content| upgrade_content(content, &join(path, "content"), report)),
+        domain: app.domain.clone(),
+        cat: app.cat.clone(),
+        sectcat: app.sectioncat.clone(),
+        pagecat: app.pagecat.clone(),
+        cattax: upgrade_cattax(app.cattax, cat),
+        privpolicy: app.privacypolicy,
+        keywords: app.keywords.clone(),
+        bundle: app.bundle.clone(),
+        storeid: None,
+        storeurl: app.storeurl.clone(),
+        ver: app.ver.clone(),
+        paid: app.paid,
+        ext: app.ext.clone(),
+    }
+}
+
+fn downgrade_app<'a>(app: &v3::App<'a>, path: &str, report: &mut Report) -> crate::App<'a> {
+    report.check(path, "storeid", &app.storeid);
+    let cat = app.cat.is_some() || app.sectcat.is_some() || app.pagecat.is_some();
+    crate::App {
+        id: app.id.clone(),
+        name: app.name.clone(),
+        bundle: app.bundle.clone(),
+        domain: app.domain.clone(),
+        storeurl: app.storeurl.clone(),
+        cat: app.cat.clone(),
+        sectioncat: app.sectcat.clone(),
+        pagecat: app.pagecat.clone(),
+        cattax: downgrade_cattax(app.cattax, cat),
+        ver: app.ver.clone(),
+        privacypolicy: app.privpolicy,
+        paid: app.paid,
+        publisher: app.r#pub.as_ref().map(downgrade_publisher),
+        content: app.content.as_ref().map(downgrade_content),
+        keywords: app.keywords.clone(),
+        ext: app.ext.clone(),
+    }
+}
+
+fn upgrade_publisher<'a>(publisher: &crate::Publisher<'a>) -> crate::Publisher<'a> {
+    crate::Publisher {
+        cattax: upgrade_cattax(publisher.cattax, publisher.cat.is_some()),
+        ..publisher.clone()
+    }
+}
+
+fn downgrade_publisher<'a>(publisher: &crate::Publisher<'a>) -> crate::Publisher<'a> {
+    crate::Publisher {
+        cattax: downgrade_cattax(publisher.cattax, publisher.cat.is_some()),
+        ..publisher.clone()
+    }
+}
+
+#[allow(deprecated)]
+fn upgrade_content<'a>(content: &crate::Content<'a>, path: &str, report: &mut Report) -> v3::Content<'a> {
+    if content.prodq.is_some() {
+        report.check(path, "videoquality", &content.videoquality);
+    }
+    v3::Content {
+        id: content.id.clone(),
+        episode: content.episode,
+        title: content.title.clone(),
+        series: content.series.clone(),
+        season: content.season.clone(),
+        artist: content.artist.clone(),
+        genre: content.genre.clone(),
+        album: content.album.clone(),
+        isrc: content.isrc.clone(),
+        url: content.url.clone(),
+        cat: content.cat.clone(),
+        cattax: upgrade_cattax(content.cattax, content.cat.is_some()),
+        prodq: content.prodq.or(content.videoquality),
+        context: content.context,
+        rating: content.contentrating.clone(),
+        urating: content.userrating.clone(),
+        mrating: content.qagmediarating,
+        keywords: content.keywords.clone(),
+        live: content.livestream,
+        srcrel: content.sourcerelationship,
+        len: content.len,
+        lang: content.language.clone(),
+        embed: content.embeddable,
+        producer: content.producer.as_ref().map(|producer| crate::Producer {
+            cattax: upgrade_cattax(producer.cattax, producer.cat.is_some()),
+            ..producer.clone()
+        }),
+        data: content.data.clone(),
+        ext: content.ext.clone(),
+    }
+}
+
+#[allow(deprecated)]
+fn downgrade_content<'a>(content: &v3::Content<'a>) -> crate::Content<'a> {
+    crate::Content {
+        id: content.id.clone(),
+        episode: content.episode,
+        title: content.title.clone(),
+        series: content.series.clone(),
+        season: content.season.clone(),
+        artist: content.artist.clone(),
+        genre: content.genre.clone(),
+        album: content.album.clone(),
+        isrc: content.isrc.clone(),
+        producer: content.producer.as_ref().map(|producer| crate::Producer {
+            cattax: downgrade_cattax(producer.cattax, producer.cat.is_some()),
+            ..producer.clone()
+        }),
+        url: content.url.clone(),
+        cat: content.cat.clone(),
+        cattax: downgrade_cattax(content.cattax, content.cat.is_some()),
+        prodq: content.prodq,
+        videoquality: None,
+        context: content.context,
+        contentrating: content.rating.clone(),
+        userrating: content.urating.clone(),
+        qagmediarating: content.mrating,
+        keywords: content.keywords.clone(),
+        livestream: content.live,
+        sourcerelationship: content.srcrel,
+        len: content.len,
+        language: content.lang.clone(),
+        embeddable: content.embed,
+        data: content.data.clone(),
+        ext: content.ext.clone(),
+    }
+}
+
+/// The operating systems of AdCOM, indexed from 1.
+const OPERATING_SYSTEMS: [&str; 28] = [
+    "3DS System Software",
+    "Android",
+    "Apple TV Software",
+    "Asha",
+    "Bada",
+    "BlackBerry",
+    "BREW",
+    "ChromeOS",
+    "Darwin",
+    "FireOS",
+    "FirefoxOS",
+    "HelenOS",
+    "iOS",
+    "Linux",
+    "MacOS",
+    "MeeGo",
+    "MorphOS",
+    "NetBSD",
+    "NucleusPLUS",
+    "PS Vita System Software",
+    "PS3 System Software",
+    "PS4 Software",
+    "PSP System Software",
+    "Symbian",
+    "Tizen",
+    "WatchOS",
+    "WebOS",
+    "Windows",
+];
+
+fn os_id(os: &str) -> Option<i32> {
+    let os = match os.trim().to_ascii_lowercase().as_str() {
+        "os x" | "osx" | "mac os x" | "mac os" | "macos" => "macos".to_owned(),
+        "tvos" => "apple tv software".to_owned(),
+        os => os.to_owned(),
+    };
+    OPERATING_SYSTEMS
+        .iter()
+        .position(|name| name.to_ascii_lowercase() == os)
+        .map(|i| i as i32 + 1)
+}
+
+fn os_name(os: i32) -> Option<&'static str> {
+    OPERATING_SYSTEMS
+        .get(usize::try_from(os).ok()?.checked_sub(1)?)
+        .copied()
+}
+
+fn upgrade_device<'a>(device: &crate::Device<'a>, path: &str, report: &mut Report) -> v3::Device<'a> {
+    report.check(path, "flashver", &device.flashver);
+    report.check(path, "didsha1", &device.didsha1);
+    report.check(path, "didmd5", &device.didmd5);
+    report.check(path, "dpidsha1", &device.dpidsha1);
+    report.check(path, "dpidmd5", &device.dpidmd5);
+    report.check(path, "macsha1", &device.macsha1);
+    report.check(path, "macmd5", &device.macmd5);
+    let os = device.os.as_ref().and_then(|os| {
+        let id = os_id(os);
+        if id.is_none() {
+            report.push(path, "os");
+        }
+        id
+    });
+    v3::Device {
+        r#type: device.devicetype,
+        ua: device.ua.clone(),
+        ifa: device.ifa.clone(),
+        dnt: device.dnt,
+        lmt: device.lmt,
+        make: device.make.clone(),
+        model: device.model.clone(),
+        os,
+        osv: device.osv.clone(),
+        hwv: device.hwv.clone(),
+        h: device.h,
+        w: device.w,
+        ppi: device.ppi,
+        pxratio: device.pxratio.map(|pxratio| pxratio as f32),
+        js: device.js,
+        lang: device.language.clone(),
+        ip: device.ip.clone(),
+        ipv6: device.ipv6.clone(),
+        carrier: device.carrier.clone(),
+        mccmnc: device.mccmnc.clone(),
+        contype: device.connectiontype,
+        geofetch: device.geofetch,
+        geo: device
+            .geo
+            .as_ref()
+            .map(|geo| upgrade_geo(geo, &join(path, "geo"), report)),
+        ext: device.ext.clone(),
+        ..Default::default()
+    }
+}
+
+fn downgrade_device<'a>(device: &v3::Device<'a>, path: &str, report: &mut Report) -> crate::Device<'a> {
+    report.check(path, "xff", &device.xff);
+    report.check(path, "iptr", &device.iptr);
+    report.check(path, "mccmncsim", &device.mccmncsim);
+    let os = device.os.and_then(|os| {
+        let name = os_name(os);
+        if name.is_none() {
+            report.push(path, "os");
+        }
+        name
+    });
+    let pxratio = device.pxratio.map(|pxratio| {
+        if pxratio.fract() != 0.0 {
+            report.push(path, "pxratio");
+        }
+        pxratio.round() as i32
+    });
+    crate::Device {
+        ua: device.ua.clone(),
+        geo: device
+            .geo
+            .as_ref()
+            .map(|geo| downgrade_geo(geo, &join(path, "geo"), report)),
+        dnt: device.dnt,
+        lmt: device.lmt,
+        ip: device.ip.clone(),
+        ipv6: device.ipv6.clone(),
+        devicetype: device.r#type,
+        make: device.make.clone(),
+        model: device.model.clone(),
+        os: os.map(Into::into),
+        osv: device.osv.clone(),
+        hwv: device.hwv.clone(),
+        h: device.h,
+        w: device.w,
+        ppi: device.ppi,
+        pxratio,
+        js: device.js,
+        geofetch: device.geofetch,
+        language: device.lang.clone(),
+        carrier: device.carrier.clone(),
+        mccmnc: device.mccmnc.clone(),
+        connectiontype: device.contype,
+        ifa: device.ifa.clone(),
+        ext: device.ext.clone(),
+        ..Default::default()
+    }
+}
+
+fn upgrade_geo<'a>(geo: &crate::Geo<'a>, path: &str, report: &mut Report) -> v3::Geo<'a> {
+    report.check(path, "regionfips104", &geo.regionfips104);
+    let country = geo
+        .country
+        .as_ref()
+        .and_then(|country| match crate::iso::Country::parse(country) {
+            Ok(country) => Some(country.alpha2().into()),
+            Err(_) => {
+                report.push(path, "country");
+                None
+            }
+        });
+    v3::Geo {
+        r#type: geo.r#type,
+        lat: geo.lat,
+        lon: geo.lon,
+        accur: geo.accuracy,
+        lastfix: geo.lastfix,
+        ipserv: geo.ipservice,
+        country,
+        region: geo.region.clone(),
+        metro: geo.metro.clone(),
+        city: geo.city.clone(),
+        zip: geo.zip.clone(),
+        utcoffset: geo.utcoffset,
+        ext: geo.ext.clone(),
+    }
+}
+
+fn downgrade_geo<'a>(geo: &v3::Geo<'a>, path: &str, report: &mut Report) -> crate::Geo<'a> {
+    let country = geo
+        .country
+        .as_ref()
+        .and_then(|country| match crate::iso::Country::parse(country) {
+            Ok(country) => Some(country.alpha3().into()),
+            Err(_) => {
+                report.push(path, "country");
+                None
+            }
+        });
+    crate::Geo {
+        lat: geo.lat,
+        lon: geo.lon,
+        r#type: geo.r#type,
+        accuracy: geo.accur,
+        lastfix: geo.lastfix,
+        ipservice: geo.ipserv,
+        country,
+        region: geo.region.clone(),
+        regionfips104: None,
+        metro: geo.metro.clone(),
+        city: geo.city.clone(),
+        zip: geo.zip.clone(),
+        utcoffset: geo.utcoffset,
+        ext: geo.ext.clone(),
+    }
+}
+
+fn upgrade_user<'a>(user: &crate::User<'a>, path: &str, report: &mut Report) -> v3::User<'a> {
+    let mut ext = user.ext.clone();
+    let consent = crate::ext::get_str(ext.as_ref(), "consent").map(|consent| {
+        crate::ext::remove(&mut ext, "consent");
+        consent.into()
+    });
+    v3::User {
+        id: user.id.clone(),
+        buyeruid: user.buyeruid.clone(),
+        yob: user.yob,
+        gender: user.gender,
+        keywords: user.keywords.clone(),
+        consent,
+        geo: user
+            .geo
+            .as_ref()
+            .map(|geo| upgrade_geo(geo, &join(path, "geo"), report)),
+        data: user.data.clone(),
+        ext,
+    }
+}
+
+fn downgrade_user<'a>(user: &v3::User<'a>, path: &str, report: &mut Report) -> crate::User<'a> {
+    let mut ext = user.ext.clone();
+    if let Some(consent) = &user.consent {
+        if crate::ext::set(&mut ext, "consent", consent.as_ref().into()).is_err() {
+            report.push(path, "consent");
+        }
+    }
+    crate::User {
+        id: user.id.clone(),
+        buyeruid: user.buyeruid.clone(),
+        yob: user.yob,
+        gender: user.gender,
+        keywords: user.keywords.clone(),
+        customdata: None,
+        geo: user
+            .geo
+            .as_ref()
+            .map(|geo| downgrade_geo(geo, &join(path, "geo"), report)),
+        data: user.data.clone(),
+        ext,
+    }
+}
+
+fn upgrade_regs<'a>(regs: &crate::Regs<'a>) -> v3::Regs<'a> {
+    let mut ext = regs.ext.clone();
+    let gdpr = crate::ext::get(ext.as_ref(), "gdpr")
+        .as_ref()
+        .and_then(crate::ext::as_flag)
+        .map(|gdpr| {
+            crate::ext::remove(&mut ext, "gdpr");
+            crate::ext::flag(gdpr)
+        });
+    v3::Regs {
+        coppa: regs.coppa,
+        gdpr,
+        ext,
+    }
+}
+
+fn downgrade_regs<'a>(regs: &v3::Regs<'a>, path: &str, report: &mut Report) -> crate::Regs<'a> {
+    let mut ext = regs.ext.clone();
+    if let Some(gdpr) = regs.gdpr {
+        let gdpr = u8::from(crate::ext::is_set(Some(gdpr)));
+        if crate::ext::set(&mut ext, "gdpr", gdpr.into()).is_err() {
+            report.push(path, "gdpr");
+        }
+    }
+    crate::Regs { coppa: regs.coppa, ext }
+}
+
+fn upgrade_source<'a>(source: &crate::Source<'a>, path: &str, report: &mut Report) -> v3::Source<'a> {
+    report.check(path, "fd", &source.fd);
+    v3::Source {
+        tid: source.tid.clone(),
+        pchain: source.pchain.clone(),
+        ext: source.ext.clone(),
+        ..Default::default()
+    }
+}
+
+fn downgrade_source<'a>(source: &v3::Source<'a>, path: &str, report: &mut Report) -> crate::Source<'a> {
+    report.check(path, "ts", &source.ts);
+    report.check(path, "ds", &source.ds);
+    report.check(path, "dsmap", &source.dsmap);
+    report.check(path, "cert", &source.cert);
+    crate::Source {
+        fd: None,
+        tid: source.tid.clone(),
+        pchain: source.pchain.clone(),
+        ext: source.ext.clone(),
+    }
+}
+
+fn upgrade_bid<'a>(bid: &crate::Bid<'a>, path: &str, report: &mut Report) -> v3::Bid<'a> {
+    let mut ad = v3::Ad {
+        id: bid.crid.clone(),
+        adomain: bid.adomain.clone(),
+        bundle: bid.bundle.clone().map(|bundle| vec![bundle]),
+        iurl: bid.iurl.clone(),
+        cat: bid.cat.clone(),
+        cattax: upgrade_cattax(bid.cattax, bid.cat.is_some()),
+        lang: bid.language.clone(),
+        attr: bid.attr.clone(),
+        mrating: bid.qagmediarating,
+        ..Default::default()
+    };
+    let api = bid.api.map(|api| vec![api]);
+    match media_kind(bid) {
+        MediaKind::Display => {
+            report.check(path, "protocol", &bid.protocol);
+            ad.display = Some(v3::Display {
+                api,
+                w: bid.w,
+                h: bid.h,
+                wratio: bid.wratio,
+                hratio: bid.hratio,
+                adm: bid.adm.clone(),
+                ..Default::default()
+            });
+        }
+        kind => {
+            report.check(path, "w", &bid.w);
+            report.check(path, "h", &bid.h);
+            report.check(path, "wratio", &bid.wratio);
+            report.check(path, "hratio", &bid.hratio);
+            let video = v3::Video {
+                api,
+                ctype: bid.protocol,
+                adm: bid.adm.clone(),
+                ..Default::default()
+            };
+            match kind {
+                MediaKind::Audio => {
+                    ad.audio = Some(v3::Audio {
+                        api: video.api,
+                        ctype: video.ctype,
+                        adm: video.adm,
+                        ..Default::default()
+                    })
+                }
+                _ => ad.video = Some(video),
+            }
+        }
+    }
+
+    v3::Bid {
+        id: Some(bid.id.clone()),
+        item: bid.impid.clone(),
+        price: bid.price,
+        deal: bid.dealid.clone(),
+        cid: bid.cid.clone(),
+        tactic: bid.tactic.clone(),
+        purl: bid.nurl.clone(),
+        burl: bid.burl.clone(),
+        lurl: bid.lurl.clone(),
+        exp: bid.exp,
+        mid: bid.adid.clone(),
+        r#macro: None,
+        media: Some(v3::Media { ad }),
+        ext: bid.ext.clone(),
+    }
+}
+
+fn downgrade_bid<'a>(bid: &v3::Bid<'a>, path: &str, report: &mut Report) -> crate::Bid<'a> {
+    report.check(path, "macro", &bid.r#macro);
+    let mut res = crate::Bid {
+        id: bid.id.clone().unwrap_or_default(),
+        impid: bid.item.clone(),
+        price: bid.price,
+        nurl: bid.purl.clone(),
+        burl: bid.burl.clone(),
+        lurl: bid.lurl.clone(),
+        adid: bid.mid.clone(),
+        cid: bid.cid.clone(),
+        tactic: bid.tactic.clone(),
+        dealid: bid.deal.clone(),
+        exp: bid.exp,
+        ext: bid.ext.clone(),
+        ..Default::default()
+    };
+    let ad = match &bid.media {
+        Some(media) => &media.ad,
+        None => return res,
+    };
+    let path = join(path, "media.ad");
+    report.check(&path, "secure", &ad.secure);
+    report.check(&path, "init", &ad.init);
+    report.check(&path, "lastmod", &ad.lastmod);
+    report.check(&path, "ext", &ad.ext);
+    if ad.bundle.as_ref().is_some_and(|bundle| bundle.len() > 1) {
+        report.push(&path, "bundle");
+    }
+    res.crid = ad.id.clone();
+    res.adomain = ad.adomain.clone();
+    res.bundle = ad.bundle.as_ref().and_then(|bundle| bundle.first().cloned());
+    res.iurl = ad.iurl.clone();
+    res.cat = ad.cat.clone();
+    res.cattax = downgrade_cattax(ad.cattax, ad.cat.is_some());
+    res.language = ad.lang.clone();
+    res.attr = ad.attr.clone();
+    res.qagmediarating = ad.mrating;
+
+    let mut api = None;
+    if let Some(display) = &ad.display {
+        let path = join(&path, "display");
+        report.check(&path, "mime", &display.mime);
+        report.check(&path, "ctype", &display.ctype);
+        report.check(&path, "priv", &display.r#priv);
+        report.check(&path, "curl", &display.curl);
+        report.check(&path, "ext", &display.ext);
+        api = Some((path, display.api.as_ref()));
+        res.w = display.w;
+        res.h = display.h;
+        res.wratio = display.wratio;
+        res.hratio = display.hratio;
+        res.adm = display.adm.clone();
+    }
+    // Audio has the same shape as video.
+    let audio = ad.audio.as_ref().map(|audio| v3::Video {
+        mime: audio.mime.clone(),
+        api: audio.api.clone(),
+        ctype: audio.ctype,
+        dur: audio.dur,
+        adm: audio.adm.clone(),
+        curl: audio.curl.clone(),
+        ext: audio.ext.clone(),
+    });
+    for (field, video) in [("video", ad.video.as_ref()), ("audio", audio.as_ref())] {
+        let (path, video) = match video {
+            Some(video) => (join(&path, field), video),
+            None => continue,
+        };
+        if api.is_some() {
+            report.unmapped.push(Unmapped { path });
+            continue;
+        }
+        report.check(&path, "mime", &video.mime);
+        report.check(&path, "dur", &video.dur);
+        report.check(&path, "curl", &video.curl);
+        report.check(&path, "ext", &video.ext);
+        res.protocol = video.ctype;
+        res.adm = video.adm.clone();
+        api = Some((path, video.api.as_ref()));
+    }
+    if let Some((path, Some(apis))) = api {
+        if apis.len() > 1 {
+            report.push(&path, "api");
+        }
+        res.api = apis.first().copied();
+    }
+
+    res
+}
+
+enum MediaKind {
+    Display,
+    Video,
+    Audio,
+}
+
+/// Guesses the kind of media of a bid from its markup, or from its protocol.
+fn media_kind(bid: &crate::Bid) -> MediaKind {
+    match (bid.adm.as_deref(), bid.protocol) {
+        (Some(adm), _) if adm.contains("<DAAST") => MediaKind::Audio,
+        (Some(adm), _) if adm.contains("<VAST") => MediaKind::Video,
+        (Some(_), _) | (None, None) => MediaKind::Display,
+        (None, Some(crate::Protocol::Daast1 | crate::Protocol::Daast1Wrapper)) => MediaKind::Audio,
+        (None, Some(_)) => MediaKind::Video,
+    }
+}
+
+/// The version of the native requests written when converting from 3.0.
+const NATIVE_VERSION: &str = "1.2";
+
+/// The native request (Native Ads 1.2), carried as a string in `imp.native.request`.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct NativeRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ver: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    context: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    plcmttype: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    privacy: Option<json_ext::Flag>,
+    assets: Vec<NativeAsset>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ext: Option<serde_json::Value>,
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct NativeAsset {
+    id: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    required: Option<json_ext::Flag>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<NativeTitle>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    img: Option<NativeImage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    video: Option<NativeVideo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    data: Option<NativeData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ext: Option<serde_json::Value>,
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct NativeTitle {
+    len: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ext: Option<serde_json::Value>,
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct NativeImage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    r#type: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    w: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wmin: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    h: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hmin: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mimes: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ext: Option<serde_json::Value>,
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct NativeVideo {
+    mimes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    minduration: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    maxduration: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    protocols: Option<Vec<crate::Protocol>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ext: Option<serde_json::Value>,
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct NativeData {
+    r#type: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    len: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ext: Option<serde_json::Value>,
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn paths(unmapped: Vec<Unmapped>) -> Vec<String> {
+        unmapped.into_iter().map(|unmapped| unmapped.path).collect()
+    }
+
+    #[test]
+    fn request() -> Result<(), Box<dyn std::error::Error>> {
+        let json = r#"{
+            "id": "1",
+            "imp": [{
+                "id": "1",
+                "banner": {"format": [{"w": 300, "h": 250}], "battr": [1, 2]},
+                "native": {
+                    "request": "{\"native\":{\"ver\":\"1.0\",\"plcmttype\":1,\"plcmtcnt\":1,\"assets\":[{\"id\":1,\"required\":1,\"title\":{\"len\":25}},{\"id\":2,\"img\":{\"type\":3,\"w\":100,\"h\":100}}]}}",
+                    "battr": [1],
+                    "ext": {"a": 1}
+                },
+                "clickbrowser": 1,
+                "instl": 1
+            }],
+            "bseat": ["s"],
+            "user": {"customdata": "c", "ext": {"consent": "abc"}},
+            "regs": {"coppa": 1, "ext": {"gdpr": 1, "us_privacy": "1YNN"}},
+            "device": {"os": "Android", "pxratio": 2, "geo": {"country": "FRA"}}
+        }"#;
+        let req: crate::BidRequest = serde_json::from_str(json)?;
+        let (openrtb, unmapped) = from_bid_request(&req);
+        assert_eq!(
+            paths(unmapped),
+            ["imp[0].native.request.plcmtcnt", "imp[0].native.battr"]
+        );
+
+        let request = openrtb.request.as_ref().unwrap();
+        assert_eq!(request.seat.as_deref(), Some(&["s".into()][..]));
+        assert!(!crate::ext::is_set(request.wseat));
+        assert_eq!(request.cdata.as_deref(), Some("c"));
+        let display = request.item[0].spec.placement.display.as_ref().unwrap();
+        assert_eq!(display.clktype, Some(3));
+        assert_eq!(display.ptype, Some(1));
+        assert_eq!(display.displayfmt.as_ref().unwrap()[0].w, Some(300));
+        let nativefmt = display.nativefmt.as_ref().unwrap();
+        assert_eq!(nativefmt.asset.len(), 2);
+        assert_eq!(nativefmt.asset[0].title.as_ref().unwrap().len, 25);
+        assert_eq!(nativefmt.asset[1].img.as_ref().unwrap().r#type, Some(3));
+        let context = request.context.as_ref().unwrap();
+        let battr = context.restrictions.as_ref().unwrap().battr.as_ref().unwrap();
+        assert_eq!(serde_json::to_string(battr)?, "[1,2]");
+        assert_eq!(context.user.as_ref().unwrap().consent.as_deref(), Some("abc"));
+        assert_eq!(context.user.as_ref().unwrap().ext, None);
+        let regs = context.regs.as_ref().unwrap();
+        assert!(crate::ext::is_set(regs.gdpr));
+        assert_eq!(crate::ext::get(regs.ext.as_ref(), "gdpr"), None);
+        let device = context.device.as_ref().unwrap();
+        assert_eq!(device.os, Some(2));
+        assert_eq!(device.pxratio, Some(2.0));
+        assert_eq!(device.geo.as_ref().unwrap().country.as_deref(), Some("FR"));
+
+        let (back, unmapped) = to_bid_request(&openrtb)?;
+        assert!(unmapped.is_empty());
+        assert_eq!(back.bseat, req.bseat);
+        assert_eq!(back.wseat, None);
+        assert_eq!(back.user, req.user);
+        assert_eq!(back.regs, req.regs);
+        assert_eq!(back.device, req.device);
+        let imp = &back.imp[0];
+        assert_eq!(imp.clickbrowser, req.imp[0].clickbrowser);
+        assert_eq!(imp.instl, req.imp[0].instl);
+        assert_eq!(
+            imp.banner.as_ref().unwrap().format,
+            req.imp[0].banner.as_ref().unwrap().format
+        );
+        let native = imp.native.as_ref().unwrap();
+        assert_eq!(native.ver.as_deref(), Some("1.2"));
+        assert_eq!(
+            native.request,
+            r#"{"ver":"1.2","plcmttype":1,"assets":[{"id":1,"required":1,"title":{"len":25}},{"id":2,"img":{"type":3,"w":100,"h":100}}]}"#
+        );
+        assert_eq!(native.ext, None);
+        assert_eq!(
+            imp.banner.as_ref().unwrap().ext,
+            req.imp[0].native.as_ref().unwrap().ext
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn unmapped() -> Result<(), Box<dyn std::error::Error>> {
+        let json = r#"{
+            "ver": "3.0",
+            "domainspec": "adcom",
+            "domainver": "1.0",
+            "request": {
+                "id": "1",
+                "item": [
+                    {"id": "1", "qty": 2, "spec": {"placement": {"ssai": 1, "wlang": ["en"], "display": {"clktype": 1, "w": 300, "h": 250, "ampren": 1}}}},
+                    {"id": "2", "deal": [{"id": "d", "at": 3}], "spec": {"placement": {"video": {"mime": ["video/mp4"], "clktype": 2}, "audio": {"mime": ["audio/mp4"], "clktype": 3}}}}
+                ],
+                "context": {
+                    "site": {"amp": 1},
+                    "app": {},
+                    "device": {"os": 99, "pxratio": 1.5},
+                    "restrictions": {"battr": [1, 2]}
+                }
+            }
+        }"#;
+        let openrtb: v3::Openrtb = serde_json::from_str(json)?;
+        let (req, unmapped) = to_bid_request(&openrtb)?;
+        assert_eq!(
+            paths(unmapped),
+            [
+                "request.item[0].qty",
+                "request.item[0].spec.placement.ssai",
+                "request.item[0].spec.placement.display.ampren",
+                "request.item[0].spec.placement.display.clktype",
+                "request.item[1].spec.placement.wlang",
+                "request.context.restrictions.battr",
+                "request.item[1].spec.placement.audio.clktype",
+                "request.item[1].deal[0].at",
+                "request.context.app",
+                "request.context.site.amp",
+                "request.context.device.os",
+                "request.context.device.pxratio",
+            ]
+        );
+        assert_eq!(req.wlang.as_deref(), Some(&["en".into()][..]));
+        assert_eq!(req.imp[1].clickbrowser, Some(crate::ext::flag(false)));
+        assert_eq!(req.imp[1].video.as_ref().unwrap().battr.as_ref().map(Vec::len), Some(2));
+        assert_eq!(
+            req.imp[1].audio.as_ref().unwrap().battr,
+            req.imp[1]
+                .video
+                .as_ref()
+                .unwrap()
+                .battr
+                .as_ref()
+                .unwrap()
+                .first()
+                .copied()
+        );
+        assert_eq!(req.imp[1].pmp.as_ref().unwrap().deals.as_ref().unwrap()[0].at, None);
+        assert_eq!(req.device.as_ref().unwrap().pxratio, Some(2));
+        assert!(req.channel.as_ref().unwrap().is_site());
+
+        Ok(())
+    }
+
+    #[test]
+    fn response() -> Result<(), Box<dyn std::error::Error>> {
+        let json = r#"{
+            "id": "1",
+            "seatbid": [{"bid": [
+                {"id": "1", "impid": "1", "price": 1.0, "adm": "<DAAST version=\"1.0\"></DAAST>", "w": 300},
+                {"id": "2", "impid": "1", "price": 1.0, "protocol": 3, "cat": ["IAB1"], "bundle": "com.foo"},
+                {"id": "3", "impid": "1", "price": 1.0, "crid": "c", "api": 3, "w": 300, "h": 250}
+            ]}]
+        }"#;
+        let res: crate::BidResponse = serde_json::from_str(json)?;
+        let (openrtb, unmapped) = from_bid_response(&res);
+        assert_eq!(paths(unmapped), ["seatbid[0].bid[0].w"]);
+
+        let bids = &openrtb.response.as_ref().unwrap().seatbid.as_ref().unwrap()[0].bid;
+        let ad = |i: usize| &bids[i].media.as_ref().unwrap().ad;
+        assert!(ad(0).audio.is_some());
+        assert!(ad(1).video.is_some());
+        assert_eq!(ad(1).cattax, Some(crate::CategoryTaxonomy::IabContent1_0));
+        assert_eq!(ad(2).id.as_deref(), Some("c"));
+        assert_eq!(ad(2).display.as_ref().unwrap().w, Some(300));
+
+        let (back, unmapped) = to_bid_response(&openrtb)?;
+        assert!(unmapped.is_empty());
+        let bids = &back.seatbid.as_ref().unwrap()[0].bid;
+        assert_eq!(bids[0].w, None);
+        assert_eq!(bids[1..], res.seatbid.as_ref().unwrap()[0].bid[1..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn error() {
+        let mut openrtb = from_bid_response(&Default::default()).0;
+        assert_eq!(to_bid_request(&openrtb), Err(Error::MissingRequest));
+        assert!(to_bid_response(&openrtb).is_ok());
+        openrtb.domainspec = Some("other".into());
+        assert_eq!(to_bid_response(&openrtb), Err(Error::DomainSpec("other".into())));
+    }
+}
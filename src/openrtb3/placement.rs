@@ -0,0 +1,708 @@
+/// Object: Placement
+///
+/// This object describes the context and rules for the media to be displayed in the placement
+/// offered by an item. Its display, video and audio subtypes describe the media formats the
+/// placement accepts.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Placement<'a> {
+    /// string
+    /// Identifier for specific ad placement or ad tag that was used to initiate the auction.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub tagid: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer; default 0
+    /// Indicates if this object represents an impression that is subject to server-side ad
+    /// insertion, where 0 = status unknown, 1 = all SSAI, 2 = creative assets only, 3 = no SSAI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssai: Option<i32>,
+
+    /// string
+    /// Name of ad mediation partner, SDK technology, or player responsible for rendering ad
+    /// (typically video or mobile).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub sdk: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Version of ad mediation partner, SDK technology, or player responsible for rendering ad.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub sdkver: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer; default 0
+    /// Indicates if this is a rewarded placement, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reward: Option<json_ext::Flag>,
+
+    /// string array
+    /// Allow list of languages for creatives using ISO-639-1-alpha-2. Omission implies no specific
+    /// restrictions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub wlang: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer
+    /// Flag to indicate if the creative is secure, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secure: Option<json_ext::Flag>,
+
+    /// integer
+    /// Indicator of whether the placement supports the markup of ads by an ad server (i.e., by
+    /// the adm attribute of media), where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admx: Option<json_ext::Flag>,
+
+    /// integer
+    /// Indicator of whether the placement supports retrieving the markup of ads from a URL (i.e.,
+    /// by the curl attribute of media), where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub curlx: Option<json_ext::Flag>,
+
+    /// object
+    /// Placement Subtype Object that indicates that this may be a display placement.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<crate::openrtb3::DisplayPlacement<'a>>,
+
+    /// object
+    /// Placement Subtype Object that indicates that this may be a video placement.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub video: Option<crate::openrtb3::VideoPlacement<'a>>,
+
+    /// object
+    /// Placement Subtype Object that indicates that this may be an audio placement.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<crate::openrtb3::AudioPlacement<'a>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: DisplayPlacement
+///
+/// This object signals that the placement may be a display placement. It covers banner and
+/// native ads, the accepted sizes being given by displayfmt and the native assets by nativefmt.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct DisplayPlacement<'a> {
+    /// integer
+    /// Placement position on screen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pos: Option<crate::AdPosition>,
+
+    /// integer; default 0
+    /// Indicates if this is an interstitial placement, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instl: Option<json_ext::Flag>,
+
+    /// integer
+    /// Indicates if the placement will be in an iframe, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topframe: Option<json_ext::Flag>,
+
+    /// string array
+    /// Array of supported iframe busters.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ifrbust: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer; default 1
+    /// Indicates the click type of the placement, where 0 = non-clickable, 1 = clickable (details
+    /// unknown), 2 = clickable with embedded browser/webview, 3 = clickable with native browser.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clktype: Option<i32>,
+
+    /// integer; default 0
+    /// Indicates if the placement will render AMP content, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ampren: Option<json_ext::Flag>,
+
+    /// integer
+    /// The display placement type, where 1 = in the feed of content, 2 = in the atomic unit of the
+    /// content, 3 = outside the core content, 4 = recommendation widget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ptype: Option<i32>,
+
+    /// integer
+    /// The context of the placement, where 1 = content-centric, 2 = social-centric, 3 =
+    /// product context. Refer to the Display Context Types list of AdCOM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<i32>,
+
+    /// string array
+    /// Array of supported mime types (e.g., “image/jpeg”, “image/gif”). If omitted, all types are
+    /// assumed.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer array
+    /// List of supported APIs. If an API is not explicitly listed, it is assumed not to be
+    /// supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<crate::ApiFramework>>,
+
+    /// integer array
+    /// Creative subtypes permitted, where 1 = HTML, 2 = AMPHTML, 3 = structured image object, 4 =
+    /// structured native object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<Vec<i32>>,
+
+    /// integer
+    /// Width of the placement in units specified by unit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub w: Option<i32>,
+
+    /// integer
+    /// Height of the placement in units specified by unit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub h: Option<i32>,
+
+    /// integer; default 1
+    /// Unit of size used for placement size (i.e., w and h attributes), where 1 = device
+    /// independent pixels, 2 = inches, 3 = centimeters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<i32>,
+
+    /// integer; default 0
+    /// Indicator of whether the placement supports a buyer-specific privacy notice, where 0 = no,
+    /// 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#priv: Option<json_ext::Flag>,
+
+    /// object array
+    /// Array of DisplayFormat objects representing the display formats permitted for this
+    /// placement.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub displayfmt: Option<Vec<crate::openrtb3::DisplayFormat<'a>>>,
+
+    /// object
+    /// NativeFormat object representing the native formats permitted for this placement.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub nativefmt: Option<crate::openrtb3::NativeFormat<'a>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: DisplayFormat
+///
+/// This object represents an allowed size (i.e., height and width combination) and/or aspect
+/// ratio for a display placement.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct DisplayFormat<'a> {
+    /// integer
+    /// Absolute width of the creative in units specified by DisplayPlacement.unit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub w: Option<i32>,
+
+    /// integer
+    /// Absolute height of the creative in units specified by DisplayPlacement.unit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub h: Option<i32>,
+
+    /// integer
+    /// Relative width of the creative when expressing size as a ratio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wratio: Option<i32>,
+
+    /// integer
+    /// Relative height of the creative when expressing size as a ratio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hratio: Option<i32>,
+
+    /// integer array
+    /// Directions in which the creative is permitted to expand.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expdir: Option<Vec<crate::ExpandableDirection>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: NativeFormat
+///
+/// This object specifies the native asset formats of a display placement.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct NativeFormat<'a> {
+    /// object array; required
+    /// Array of AssetFormat objects, one per asset permitted in the native ad.
+    #[serde(borrow)]
+    pub asset: Vec<crate::openrtb3::AssetFormat<'a>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: AssetFormat
+///
+/// This object represents the permitted specifications of a single asset of a native ad. Exactly
+/// one of title, img, video or data is present.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct AssetFormat<'a> {
+    /// integer; required
+    /// Asset ID, unique within the scope of this placement specification.
+    pub id: i32,
+
+    /// integer; default 0
+    /// Indicator of whether or not this asset is required, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub req: Option<json_ext::Flag>,
+
+    /// object
+    /// Asset Format Subtype Object for title assets.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<crate::openrtb3::TitleAssetFormat<'a>>,
+
+    /// object
+    /// Asset Format Subtype Object for image assets.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub img: Option<crate::openrtb3::ImageAssetFormat<'a>>,
+
+    /// object
+    /// Asset Format Subtype Object for video assets, using the VideoPlacement subtype object.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub video: Option<crate::openrtb3::VideoPlacement<'a>>,
+
+    /// object
+    /// Asset Format Subtype Object for data assets.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<crate::openrtb3::DataAssetFormat<'a>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: TitleAssetFormat
+///
+/// This object is used to provide native asset format specifications for title elements.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct TitleAssetFormat<'a> {
+    /// integer; required
+    /// The maximum allowed length of the title value.
+    pub len: i32,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: ImageAssetFormat
+///
+/// This object is used to provide native asset format specifications for image elements.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct ImageAssetFormat<'a> {
+    /// integer
+    /// The type of image asset supported, where 1 = icon, 3 = main image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<i32>,
+
+    /// string array
+    /// Array of supported mime types (e.g., “image/jpeg”, “image/gif”).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer
+    /// The absolute width of the image asset in device independent pixels (DIPS).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub w: Option<i32>,
+
+    /// integer
+    /// The absolute height of the image asset in device independent pixels (DIPS).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub h: Option<i32>,
+
+    /// integer
+    /// The minimum requested absolute width of the image in device independent pixels (DIPS).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wmin: Option<i32>,
+
+    /// integer
+    /// The minimum requested absolute height of the image in device independent pixels (DIPS).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hmin: Option<i32>,
+
+    /// integer
+    /// Relative width of the image asset when expressing size as a ratio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wratio: Option<i32>,
+
+    /// integer
+    /// Relative height of the image asset when expressing size as a ratio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hratio: Option<i32>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: DataAssetFormat
+///
+/// This object is used to provide native asset format specifications for data elements, which
+/// are arbitrary strings such as ratings, prices or sponsor names.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct DataAssetFormat<'a> {
+    /// integer; required
+    /// The type of data asset supported, e.g. 1 = sponsored, 2 = descriptive text, 12 = call to
+    /// action text. Refer to the Native Data Asset Types list of AdCOM.
+    pub r#type: i32,
+
+    /// integer
+    /// The maximum allowed length of the data value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub len: Option<i32>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: VideoPlacement
+///
+/// This object signals that the placement may be a video placement and provides additional detail
+/// about permitted video ads.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct VideoPlacement<'a> {
+    /// integer
+    /// Placement subtype.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ptype: Option<crate::VideoPlacementType>,
+
+    /// integer
+    /// Placement position on screen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pos: Option<crate::AdPosition>,
+
+    /// integer
+    /// Indicates the start delay in seconds for pre-roll, mid-roll, or post-roll ad placements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delay: Option<crate::StartDelay>,
+
+    /// integer
+    /// Indicates if the placement imposes ad skippability, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip: Option<json_ext::Flag>,
+
+    /// integer; default 0
+    /// The placement allows creatives of total duration greater than this number of seconds to
+    /// be skipped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipmin: Option<i32>,
+
+    /// integer; default 0
+    /// Number of seconds a creative must play before the placement enables skipping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipafter: Option<i32>,
+
+    /// integer
+    /// Playback method in use for this placement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playmethod: Option<crate::PlaybackMethod>,
+
+    /// integer
+    /// The event that causes playback to end for this placement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playend: Option<crate::PlaybackCessationMode>,
+
+    /// integer; default 1
+    /// Indicates the click type of the placement, where 0 = non-clickable, 1 = clickable (details
+    /// unknown), 2 = clickable with embedded browser/webview, 3 = clickable with native browser.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clktype: Option<i32>,
+
+    /// string array; required
+    /// Array of supported mime types (e.g., “video/mp4”).
+    #[serde(borrow)]
+    pub mime: Vec<std::borrow::Cow<'a, str>>,
+
+    /// integer array
+    /// List of supported APIs for this placement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<crate::ApiFramework>>,
+
+    /// integer array; recommended
+    /// Creative subtypes permitted for this placement, e.g. VAST versions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<Vec<crate::Protocol>>,
+
+    /// integer
+    /// Width of the placement in units specified by unit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub w: Option<i32>,
+
+    /// integer
+    /// Height of the placement in units specified by unit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub h: Option<i32>,
+
+    /// integer; default 1
+    /// Units of size used for w and h attributes, where 1 = device independent pixels, 2 =
+    /// inches, 3 = centimeters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<i32>,
+
+    /// integer; default 0
+    /// Minimum creative duration in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mindur: Option<i32>,
+
+    /// integer
+    /// Maximum creative duration in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maxdur: Option<i32>,
+
+    /// integer; default 0
+    /// Maximum extended creative duration if extension is allowed. If 0, extension is not allowed.
+    /// If -1, extension is allowed and there is no time limit imposed. If greater than 0, then
+    /// the value represents the number of seconds of extended play supported beyond the maxdur
+    /// value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maxext: Option<crate::MaxExtendedAdDuration>,
+
+    /// integer
+    /// Minimum bit rate of the creative in Kbps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minbr: Option<i32>,
+
+    /// integer
+    /// Maximum bit rate of the creative in Kbps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maxbr: Option<i32>,
+
+    /// integer array
+    /// Array of supported creative delivery methods.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivery: Option<Vec<crate::ContentDeliveryMethod>>,
+
+    /// integer
+    /// The maximum number of ads that can be played in an ad pod.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maxseq: Option<i32>,
+
+    /// integer
+    /// Indicates if the creative must be linear, nonlinear, etc. If none specified, no
+    /// restrictions are assumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub linear: Option<crate::VideoLinearity>,
+
+    /// integer; default 1
+    /// Indicates if letterboxing of 4:3 creatives into a 16:9 window is allowed, where 0 = no, 1 =
+    /// yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub boxing: Option<json_ext::Flag>,
+
+    /// object array
+    /// Array of objects indicating that companion ads are available and describing the types of
+    /// companion ads that are permitted.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub comp: Option<Vec<crate::openrtb3::Companion<'a>>>,
+
+    /// integer array
+    /// Supported companion ad types; recommended if companion ads are specified in comp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comptype: Option<Vec<crate::CompanionType>>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: AudioPlacement
+///
+/// This object signals that the placement may be an audio placement and provides additional
+/// detail about permitted audio ads.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct AudioPlacement<'a> {
+    /// integer
+    /// Indicates the start delay in seconds for pre-roll, mid-roll, or post-roll ad placements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delay: Option<crate::StartDelay>,
+
+    /// integer
+    /// Indicates if the placement imposes ad skippability, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip: Option<json_ext::Flag>,
+
+    /// integer; default 0
+    /// The placement allows creatives of total duration greater than this number of seconds to
+    /// be skipped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipmin: Option<i32>,
+
+    /// integer; default 0
+    /// Number of seconds a creative must play before the placement enables skipping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipafter: Option<i32>,
+
+    /// integer
+    /// Playback method in use for this placement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playmethod: Option<crate::PlaybackMethod>,
+
+    /// integer
+    /// The event that causes playback to end for this placement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playend: Option<crate::PlaybackCessationMode>,
+
+    /// integer; default 1
+    /// Indicates the click type of the placement, where 0 = non-clickable, 1 = clickable (details
+    /// unknown), 2 = clickable with embedded browser/webview, 3 = clickable with native browser.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clktype: Option<i32>,
+
+    /// string array; required
+    /// Array of supported mime types (e.g., “audio/mp4”).
+    #[serde(borrow)]
+    pub mime: Vec<std::borrow::Cow<'a, str>>,
+
+    /// integer array
+    /// List of supported APIs for this placement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<Vec<crate::ApiFramework>>,
+
+    /// integer array; recommended
+    /// Creative subtypes permitted for this placement, e.g. VAST and DAAST versions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ctype: Option<Vec<crate::Protocol>>,
+
+    /// integer; default 0
+    /// Minimum creative duration in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mindur: Option<i32>,
+
+    /// integer
+    /// Maximum creative duration in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maxdur: Option<i32>,
+
+    /// integer; default 0
+    /// Maximum extended creative duration if extension is allowed. If 0, extension is not allowed.
+    /// If -1, extension is allowed and there is no time limit imposed. If greater than 0, then
+    /// the value represents the number of seconds of extended play supported beyond the maxdur
+    /// value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maxext: Option<crate::MaxExtendedAdDuration>,
+
+    /// integer
+    /// Minimum bit rate of the creative in Kbps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minbr: Option<i32>,
+
+    /// integer
+    /// Maximum bit rate of the creative in Kbps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maxbr: Option<i32>,
+
+    /// integer array
+    /// Array of supported creative delivery methods.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivery: Option<Vec<crate::ContentDeliveryMethod>>,
+
+    /// integer
+    /// The maximum number of ads that can be played in an ad pod.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maxseq: Option<i32>,
+
+    /// object array
+    /// Array of objects indicating that companion ads are available and describing the types of
+    /// companion ads that are permitted.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub comp: Option<Vec<crate::openrtb3::Companion<'a>>>,
+
+    /// integer array
+    /// Supported companion ad types; recommended if companion ads are specified in comp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comptype: Option<Vec<crate::CompanionType>>,
+
+    /// integer
+    /// Type of audio feed of this placement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed: Option<crate::FeedType>,
+
+    /// integer
+    /// Indicates if the ad is stitched with audio content or delivered independently, where 0 =
+    /// no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stitched: Option<json_ext::Flag>,
+
+    /// integer
+    /// Volume normalization mode of this placement.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nvol: Option<crate::VolumeNormalizationMode>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Companion
+///
+/// This object is used in video and audio placements to specify an associated or companion
+/// display type ad that is available in addition to the video or audio ad.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Companion<'a> {
+    /// string
+    /// An identifier of the companion ad placement, unique within the parent placement.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer; default 0
+    /// Indicates the companion ad rendering mode relative to the associated video or audio ad,
+    /// where 0 = concurrent, 1 = end-card.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vcm: Option<crate::VideoCompanionMode>,
+
+    /// object; required
+    /// Display Placement object describing the companion ad placement.
+    #[serde(borrow)]
+    pub display: crate::openrtb3::DisplayPlacement<'a>,
+
+    /// object
+    /// Optional vendor-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        let json = "{}";
+        let o1 = Placement::default();
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<Placement>(json)?);
+
+        let json = r#"{"mime":["video/mp4"]}"#;
+        let o1 = VideoPlacement {
+            mime: vec!["video/mp4".into()],
+            ..Default::default()
+        };
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<VideoPlacement>(json)?);
+
+        let json = r#"{"asset":[{"id":1,"title":{"len":25}}]}"#;
+        let o1 = NativeFormat {
+            asset: vec![AssetFormat {
+                id: 1,
+                title: Some(TitleAssetFormat { len: 25, ext: None }),
+                ..Default::default()
+            }],
+            ext: None,
+        };
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<NativeFormat>(json)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,353 @@
+/// Object: Openrtb
+///
+/// The top-level object of every OpenRTB 3.0 message, identifying the version of the transaction
+/// layer and of the domain specification it carries, and holding either a request or a response.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Openrtb<'a> {
+    /// string; required
+    /// Version of the Layer-3 OpenRTB specification (e.g., "3.0").
+    #[serde(borrow)]
+    pub ver: std::borrow::Cow<'a, str>,
+
+    /// string; default "adcom"
+    /// Identifier of the Layer-4 domain model used to define items for sale, media associated
+    /// with bids, etc.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub domainspec: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; required
+    /// Specification version of the Layer-4 domain model referenced in the domainspec attribute.
+    #[serde(borrow)]
+    pub domainver: std::borrow::Cow<'a, str>,
+
+    /// object; required *
+    /// Bid request container. * Required only for request payloads.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub request: Option<crate::openrtb3::Request<'a>>,
+
+    /// object; required *
+    /// Bid response container. * Required only for response payloads.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<crate::openrtb3::Response<'a>>,
+}
+
+/// The body of an OpenRTB 3.0 message, a JSON object whose only member is the [`Openrtb`] object.
+///
+/// [`Openrtb`]: ./struct.Openrtb.html
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Payload<'a> {
+    /// object; required
+    #[serde(borrow)]
+    pub openrtb: Openrtb<'a>,
+}
+
+/// Object: Request
+///
+/// The Request object contains a globally unique bid request ID. This id attribute is required as
+/// is an array of Item objects. Other attributes establish rules and restrictions that apply to
+/// all items being offered. The context of the items, the publisher, device, user and
+/// regulations, are described by the AdCOM objects of the context attribute.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Request<'a> {
+    /// string; required
+    /// Unique ID of the bid request; provided by the exchange.
+    #[serde(borrow)]
+    pub id: std::borrow::Cow<'a, str>,
+
+    /// integer; default 0
+    /// Indicator of test mode in which auctions are not billable, where 0 = live mode, 1 = test
+    /// mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test: Option<json_ext::Flag>,
+
+    /// integer
+    /// Maximum time in milliseconds the exchange allows for bids to be received including Internet
+    /// latency to avoid timeout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tmax: Option<i32>,
+
+    /// integer; default 2
+    /// Auction type, where 1 = First Price, 2 = Second Price Plus. Values greater than 500 can be
+    /// used for exchange-specific auction types.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<crate::AuctionType>,
+
+    /// string array; default ["USD"]
+    /// Array of accepted currencies for bids on this bid request using ISO-4217 alpha codes.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub cur: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// string array
+    /// Restriction list of buyer seats for bidding on this item. Knowledge of buyer’s customers and
+    /// their seat IDs must be coordinated between parties a priori. Omission implies no
+    /// restrictions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub seat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// integer; default 1
+    /// Flag that determines the restriction interpretation of the seat array, where 0 = block
+    /// list, 1 = allow list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wseat: Option<json_ext::Flag>,
+
+    /// string
+    /// Allows bidder to retrieve data set on its behalf in the exchange’s cookie (refer to cdata in
+    /// Object: Response) if supported by the exchange. The string must be in base85 cookie-safe
+    /// characters.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub cdata: Option<std::borrow::Cow<'a, str>>,
+
+    /// object
+    /// A Source object that provides data about the inventory source and which entity makes the
+    /// final decision.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<crate::openrtb3::Source<'a>>,
+
+    /// object array; required
+    /// Array of Item objects (at least one) that constitute the set of goods being offered for
+    /// sale.
+    #[serde(borrow)]
+    pub item: Vec<crate::openrtb3::Item<'a>>,
+
+    /// integer; default 0
+    /// Flag to indicate if the Exchange can verify that the items offered represent all of the
+    /// items available in context (e.g., all impressions on a web page, all video spots such as
+    /// pre/mid/post roll) to support road-blocking, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<json_ext::Flag>,
+
+    /// object; recommended
+    /// Layer-4 domain object structure that provides context for the items being offered
+    /// conforming to the specification and version referenced in openrtb.domainspec and
+    /// openrtb.domainver.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<crate::openrtb3::Context<'a>>,
+
+    /// object
+    /// Optional demand source specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Source
+///
+/// This object carries data about the source of the transaction including the unique ID of the
+/// transaction itself, source authentication information, and the chain of custody.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Source<'a> {
+    /// string; recommended
+    /// Transaction ID that must be common across all participants throughout the entire supply
+    /// chain of this transaction.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub tid: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer; recommended
+    /// Timestamp when the request originated at the beginning of the supply chain in Unix format
+    /// (i.e., milliseconds since the epoch).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ts: Option<i64>,
+
+    /// string; recommended
+    /// Digital signature used to authenticate the origin of this request.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ds: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// An ordered list of identifiers that indicates which fields from the Request and Item
+    /// objects were used to create the digital signature.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub dsmap: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; recommended
+    /// File name of the certificate (i.e., the public key) used to generate the digital signature
+    /// in ds attribute.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub cert: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; recommended
+    /// Payment ID chain string containing embedded syntax described in the TAG Payment ID Protocol.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub pchain: Option<std::borrow::Cow<'a, str>>,
+
+    /// object
+    /// Optional demand source specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Item
+///
+/// This object represents a unit of goods being offered for sale either on the open market or in
+/// relation to a private marketplace deal. The id attribute is required since there may be
+/// multiple items being offered in the same bid request and bids must reference the specific item
+/// of interest. The spec attribute holds the AdCOM Placement describing the item.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Item<'a> {
+    /// string; required
+    /// A unique identifier for this item within the context of the offer (typically starts with 1
+    /// and increments).
+    #[serde(borrow)]
+    pub id: std::borrow::Cow<'a, str>,
+
+    /// integer; default 1
+    /// The number of instances (i.e., “quantity”) of this item being offered (e.g., multiple
+    /// identical impressions in a digital out-of-home scenario).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qty: Option<i32>,
+
+    /// integer
+    /// If multiple items are offered in the same bid request, the sequence number allows for the
+    /// coordinated delivery.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq: Option<i32>,
+
+    /// float
+    /// Minimum bid price for this item expressed in CPM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flr: Option<f64>,
+
+    /// string; default "USD"
+    /// Currency of the flr attribute specified using ISO-4217 alpha codes.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub flrcur: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Advisory as to the number of seconds that may elapse between auction and fulfilment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i32>,
+
+    /// integer
+    /// Timestamp when the item is estimated to be fulfilled (e.g. when a DOOH impression will be
+    /// displayed) in Unix format (i.e., milliseconds since the epoch).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dt: Option<i64>,
+
+    /// integer; default 0
+    /// Item (e.g., an Ad object) delivery method required, where 0 = either method, 1 = the item
+    /// must be sent as part of the transaction (e.g., by value in the bid itself, fetched by URL
+    /// included in the bid), and 2 = an item previously uploaded to the exchange must be
+    /// referenced by its ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dlvy: Option<i32>,
+
+    /// object array
+    /// An array of Metric objects.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub metric: Option<Vec<crate::Metric<'a>>>,
+
+    /// object array
+    /// Array of Deal objects that convey special terms applicable to this item.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub deal: Option<Vec<crate::openrtb3::Deal<'a>>>,
+
+    /// integer; default 0
+    /// Indicator of auction eligibility to seats named in Deal objects, where 0 = all bids are
+    /// accepted, 1 = bids are restricted to the deals specified and the terms thereof.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<json_ext::Flag>,
+
+    /// object; required
+    /// Layer-4 domain object structure that provides specifies the item being offered conforming
+    /// to the specification and version referenced in openrtb.domainspec and openrtb.domainver.
+    #[serde(borrow)]
+    pub spec: crate::openrtb3::Spec<'a>,
+
+    /// object
+    /// Optional exchange-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Spec
+///
+/// The AdCOM specification of an item: the placement offered for sale.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Spec<'a> {
+    /// object; required
+    /// The Placement object describing the item.
+    #[serde(borrow)]
+    pub placement: crate::openrtb3::Placement<'a>,
+}
+
+/// Object: Deal
+///
+/// This object constitutes a specific deal that was struck a priori between a seller and a buyer.
+/// Its presence indicates that this item is available under the terms of that deal.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Deal<'a> {
+    /// string; required
+    /// A unique identifier for the deal.
+    #[serde(borrow)]
+    pub id: std::borrow::Cow<'a, str>,
+
+    /// float
+    /// Minimum deal price for this item expressed in CPM.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flr: Option<f64>,
+
+    /// string; default "USD"
+    /// Currency of the flr attribute specified using ISO-4217 alpha codes.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub flrcur: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Optional override of the overall auction type of the request, where 1 = First Price, 2 =
+    /// Second Price Plus, 3 = the value passed in flr is the agreed upon deal price.
+    /// Exchange-specific auction types can be defined using values greater than 500.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<i32>,
+
+    /// string array
+    /// Allow list of buyer seats allowed to bid on this deal. Omission implies no restrictions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub wseat: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// string array
+    /// Array of advertiser domains (e.g., advertiser.com) allowed to bid on this deal. Omission
+    /// implies no restrictions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub wadomain: Option<Vec<std::borrow::Cow<'a, str>>>,
+
+    /// object
+    /// Optional exchange-specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        let json = r#"{"openrtb":{"ver":"3.0","domainspec":"adcom","domainver":"1.0","request":{"id":"1","item":[{"id":"1","spec":{"placement":{}}}]}}}"#;
+        let o1 = Payload {
+            openrtb: Openrtb {
+                ver: "3.0".into(),
+                domainspec: Some("adcom".into()),
+                domainver: "1.0".into(),
+                request: Some(Request {
+                    id: "1".into(),
+                    item: vec![Item {
+                        id: "1".into(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                response: None,
+            },
+        };
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<Payload>(json)?);
+
+        let json = r#"{"id":"1"}"#;
+        let o1 = Deal {
+            id: "1".into(),
+            ..Default::default()
+        };
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<Deal>(json)?);
+
+        Ok(())
+    }
+}
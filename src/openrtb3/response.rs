@@ -0,0 +1,227 @@
+/// Object: Response
+///
+/// This object is the bid response object under the Openrtb root. Its id attribute is a reflection
+/// of the bid request ID. The bidid attribute is an optional response tracking ID for bidders. If
+/// specified, it will be available for use in substitution macros placed in markup and
+/// notification URLs. At least one Seatbid object is required, which contains at least one Bid
+/// for an item. Other attributes are optional.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Response<'a> {
+    /// string; required
+    /// ID of the bid request to which this is a response; must match the request.id attribute.
+    #[serde(borrow)]
+    pub id: std::borrow::Cow<'a, str>,
+
+    /// string
+    /// Bidder generated response ID to assist with logging/tracking.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub bidid: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Reason for not bidding if applicable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nbr: Option<crate::NoBidReason>,
+
+    /// string; default "USD"
+    /// Bid currency using ISO-4217 alpha codes.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub cur: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Allows bidder to set data in the exchange’s cookie, which can be retrieved on bid requests
+    /// (refer to cdata in Object: Request) if supported by the exchange. The string must be in
+    /// base85 cookie-safe characters.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub cdata: Option<std::borrow::Cow<'a, str>>,
+
+    /// object array
+    /// Array of Seatbid objects; 1+ required if a bid is to be made.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub seatbid: Option<Vec<crate::openrtb3::Seatbid<'a>>>,
+
+    /// object
+    /// Optional demand source specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Seatbid
+///
+/// A bid response can contain multiple Seatbid objects, each on behalf of a different buyer seat
+/// and each containing one or more individual bids.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Seatbid<'a> {
+    /// string
+    /// ID of the buyer seat on whose behalf this bid is made.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub seat: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer; default 0
+    /// For offers with multiple items, this flag Indicates if the bidder is willing to accept
+    /// wins on a subset of bids or requires the full group as a package, where 0 = individual
+    /// wins accepted; 1 = package win or loss only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<json_ext::Flag>,
+
+    /// object array; required
+    /// Array of 1+ Bid objects each related to an item. Multiple bids can relate to the same item.
+    #[serde(borrow)]
+    pub bid: Vec<crate::openrtb3::Bid<'a>>,
+
+    /// object
+    /// Optional demand source specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Bid
+///
+/// A Seatbid object contains one or more Bid objects, each of which relates to a specific item in
+/// the bid request offer via the item attribute and constitutes an offer to buy that item for a
+/// given price.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Bid<'a> {
+    /// string; recommended
+    /// Bidder generated bid ID to assist with logging/tracking.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; required
+    /// ID of the item object in the related bid request; specifically item.id.
+    #[serde(borrow)]
+    pub item: std::borrow::Cow<'a, str>,
+
+    /// float; required
+    /// Bid price expressed as CPM although the actual transaction is for a unit item only.
+    pub price: f64,
+
+    /// string
+    /// Reference to a deal from the bid request if this bid pertains to a private marketplace
+    /// deal; specifically deal.id.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub deal: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Campaign ID or other similar grouping of brand-related ads.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub cid: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Tactic ID to enable buyers to label bids for reporting to the exchange the tactic through
+    /// which their bid was submitted.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub tactic: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Pending notice URL called by the exchange when a bid has been declared the winner within
+    /// the scope of an OpenRTB compliant supply chain (i.e., there may still be non-compliant
+    /// decisioning such as header bidding).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub purl: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Billing notice URL called by the exchange when a winning bid becomes billable based on
+    /// exchange-specific business policy (e.g., markup rendered).
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub burl: Option<std::borrow::Cow<'a, str>>,
+
+    /// string
+    /// Loss notice URL called by the exchange when a bid is known to have been lost.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub lurl: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer
+    /// Advisory as to the number of seconds the buyer is willing to wait between auction and
+    /// fulfilment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i32>,
+
+    /// string
+    /// ID to enable media to be specified by reference if previously uploaded to the exchange
+    /// rather than including it by value in the domain objects.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub mid: Option<std::borrow::Cow<'a, str>>,
+
+    /// object array
+    /// Array of Macro objects that enable bid specific values to be substituted into markup;
+    /// especially useful for previously cached media.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub r#macro: Option<Vec<crate::openrtb3::Macro<'a>>>,
+
+    /// object
+    /// Layer-4 domain object structure that specifies the media to be presented if the bid is won
+    /// conforming to the specification and version referenced in openrtb.domainspec and
+    /// openrtb.domainver.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub media: Option<crate::openrtb3::Media<'a>>,
+
+    /// object
+    /// Optional demand source specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Macro
+///
+/// This object constitutes a buyer defined key/value pair used to inject dynamic values into
+/// media markup.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Macro<'a> {
+    /// string; required
+    /// Name of a buyer specific macro.
+    #[serde(borrow)]
+    pub key: std::borrow::Cow<'a, str>,
+
+    /// string
+    /// Value to substitute for each instance of the macro found in markup.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<std::borrow::Cow<'a, str>>,
+
+    /// object
+    /// Optional demand source specific extensions.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// Object: Media
+///
+/// The AdCOM media of a bid: the ad to be presented if the bid is won.
+#[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+pub struct Media<'a> {
+    /// object; required
+    /// The Ad object.
+    #[serde(borrow)]
+    pub ad: crate::openrtb3::Ad<'a>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        let json = r#"{"id":"1","seatbid":[{"bid":[{"item":"1","price":1.5,"media":{"ad":{"id":"c"}}}]}]}"#;
+        let o1 = Response {
+            id: "1".into(),
+            seatbid: Some(vec![Seatbid {
+                bid: vec![Bid {
+                    item: "1".into(),
+                    price: 1.5,
+                    media: Some(Media {
+                        ad: crate::openrtb3::Ad {
+                            id: Some("c".into()),
+                            ..Default::default()
+                        },
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<Response>(json)?);
+
+        Ok(())
+    }
+}
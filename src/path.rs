@@ -0,0 +1,830 @@
+//! Access to fields by path.
+//!
+//! A path names fields with dots and array elements with brackets, e.g. `device.geo.country`,
+//! `imp[0].bidfloor` or `imp[*].banner.format[*].w`, where `*` matches every element. A site or an
+//! app is addressed as `site` or `app`, as in the JSON representation. Paths continue into `ext`
+//! objects, e.g. `user.ext.consent`.
+//!
+//! [`get`], [`get_all`] and [`set`] navigate the typed objects; only the addressed value, e.g. a
+//! number, a list of strings or the contents of an `ext`, goes through its JSON representation.
+//! Unknown fields are reported as an [`Error`] whether or not the request sets them, so a path can
+//! be checked once against an empty object, e.g. when loading a configuration.
+//!
+//! ```
+//! # use openrtb2::path;
+//! let mut req: openrtb2::BidRequest = serde_json::from_str(
+//!     r#"{"id":"1","imp":[{"id":"1","banner":{"format":[{"w":300,"h":250},{"w":728,"h":90}]}}],
+//!         "device":{"geo":{"country":"USA"}}}"#,
+//! )?;
+//! assert_eq!(path::get(&req, "device.geo.country")?, Some("USA".into()));
+//! assert_eq!(path::get_all(&req, "imp[*].banner.format[*].w")?, [300, 728]);
+//!
+//! path::set(&mut req, "imp[0].bidfloor", 1.5)?;
+//! path::set(&mut req, "user.ext.consent", "abc")?;
+//! assert_eq!(req.imp[0].bidfloor, Some(1.5));
+//! assert_eq!(path::get(&req, "user.ext.consent")?, Some("abc".into()));
+//!
+//! assert_eq!(path::get(&req, "imp[0].banner.size"), Err(path::Error::UnknownField("imp[0].banner.size".into())));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`get`]: ./fn.get.html
+//! [`get_all`]: ./fn.get_all.html
+//! [`set`]: ./fn.set.html
+//! [`Error`]: ./enum.Error.html
+
+use private::{Kind, Segment};
+use serde_json::Value;
+
+/// Objects which can be navigated by path.
+///
+/// Implemented for the request and response objects of this crate, from `BidRequest` down to
+/// `Segment` and from `BidResponse` down to `Bid`, and for options and vectors of them.
+pub trait Node: serde::Serialize {
+    #[doc(hidden)]
+    fn get_path(&self, path: &[Segment<'_>], out: &mut Vec<Value>) -> Result<(), Error>;
+
+    #[doc(hidden)]
+    fn set_path(&mut self, path: &[Segment<'_>], value: &Value) -> Result<(), Error>;
+}
+
+mod private {
+    /// A segment of a parsed path.
+    pub struct Segment<'p> {
+        pub(super) kind: Kind<'p>,
+        /// The path up to and including this segment, for errors.
+        pub(super) prefix: &'p str,
+    }
+
+    pub enum Kind<'p> {
+        Field(&'p str),
+        Index(usize),
+        Wildcard,
+    }
+}
+
+/// Returns the first value at `path`, or None if the object does not set it.
+pub fn get<T: Node>(root: &T, path: &str) -> Result<Option<Value>, Error> {
+    Ok(get_all(root, path)?.into_iter().next())
+}
+
+/// Returns all values at `path`, in the order of the object.
+pub fn get_all<T: Node>(root: &T, path: &str) -> Result<Vec<Value>, Error> {
+    let mut out = Vec::new();
+    root.get_path(&parse(path)?, &mut out)?;
+    Ok(out)
+}
+
+/// Sets the value at `path`, creating the missing objects on the way.
+///
+/// Setting `null` removes an optional field.
+pub fn set<T: Node>(root: &mut T, path: &str, value: impl Into<Value>) -> Result<(), Error> {
+    root.set_path(&parse(path)?, &value.into()).map_err(|err| match err {
+        Error::Value { message, .. } => Error::Value {
+            path: path.to_owned(),
+            message,
+        },
+        err => err,
+    })
+}
+
+/// The error type for paths.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Error {
+    /// The path is not well-formed.
+    Syntax(String),
+    /// The path names a field the object does not have; holds the path up to that field.
+    UnknownField(String),
+    /// The path indexes an object, or names a field of an array or of a scalar; holds the path up
+    /// to that segment.
+    Mismatch(String),
+    /// The path indexes past the end of an array; holds the path up to that index.
+    OutOfBounds(String),
+    /// The value does not deserialize into the field.
+    Value { path: String, message: String },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax(path) => write!(f, "invalid path: {}", path),
+            Self::UnknownField(path) => write!(f, "unknown field: {}", path),
+            Self::Mismatch(path) => write!(f, "path does not match the value: {}", path),
+            Self::OutOfBounds(path) => write!(f, "index out of bounds: {}", path),
+            Self::Value { path, message } => write!(f, "invalid value for {}: {}", path, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn parse(path: &str) -> Result<Vec<Segment<'_>>, Error> {
+    let mut segments = Vec::new();
+    if path.is_empty() {
+        return Ok(segments);
+    }
+
+    let syntax = || Error::Syntax(path.to_owned());
+    let mut start = 0;
+    for part in path.split('.') {
+        let end = part.find('[').unwrap_or(part.len());
+        let name = &part[..end];
+        if name.is_empty() || name.contains(']') {
+            return Err(syntax());
+        }
+        segments.push(Segment {
+            kind: Kind::Field(name),
+            prefix: &path[..start + end],
+        });
+
+        let mut rest = &part[end..];
+        while !rest.is_empty() {
+            let close = rest.find(']').ok_or_else(syntax)?;
+            let kind = match rest.get(1..close).ok_or_else(syntax)? {
+                "*" => Kind::Wildcard,
+                index => Kind::Index(index.parse().map_err(|_| syntax())?),
+            };
+            rest = &rest[close + 1..];
+            segments.push(Segment {
+                kind,
+                prefix: &path[..start + part.len() - rest.len()],
+            });
+            if !rest.is_empty() && !rest.starts_with('[') {
+                return Err(syntax());
+            }
+        }
+        start += part.len() + 1;
+    }
+    Ok(segments)
+}
+
+impl Segment<'_> {
+    fn field(&self) -> Result<&str, Error> {
+        match self.kind {
+            Kind::Field(name) => Ok(name),
+            _ => Err(Error::Mismatch(self.prefix.to_owned())),
+        }
+    }
+}
+
+impl<T: Node + Default> Node for Option<T> {
+    fn get_path(&self, path: &[Segment<'_>], out: &mut Vec<Value>) -> Result<(), Error> {
+        option_get(self.as_ref(), path, out)
+    }
+
+    fn set_path(&mut self, path: &[Segment<'_>], value: &Value) -> Result<(), Error> {
+        match self {
+            _ if path.is_empty() && value.is_null() => *self = None,
+            Some(node) => node.set_path(path, value)?,
+            None => {
+                let mut node = T::default();
+                node.set_path(path, value)?;
+                *self = Some(node);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Node + Default> Node for Vec<T> {
+    fn get_path(&self, path: &[Segment<'_>], out: &mut Vec<Value>) -> Result<(), Error> {
+        let (segment, rest) = match path.split_first() {
+            Some(split) => split,
+            None => return leaf_get(self, path, out),
+        };
+        match segment.kind {
+            Kind::Index(i) => option_get(self.get(i), rest, out),
+            Kind::Wildcard if self.is_empty() => option_get(None::<&T>, rest, out),
+            Kind::Wildcard => self.iter().try_for_each(|node| node.get_path(rest, out)),
+            Kind::Field(_) => Err(Error::Mismatch(segment.prefix.to_owned())),
+        }
+    }
+
+    fn set_path(&mut self, path: &[Segment<'_>], value: &Value) -> Result<(), Error> {
+        let (segment, rest) = match path.split_first() {
+            Some(split) => split,
+            None => {
+                let items = value.as_array().ok_or_else(|| Error::Value {
+                    path: String::new(),
+                    message: format!("expected an array, found {}", value),
+                })?;
+                *self = items
+                    .iter()
+                    .map(|item| {
+                        let mut node = T::default();
+                        node.set_path(path, item).map(|()| node)
+                    })
+                    .collect::<Result<_, _>>()?;
+                return Ok(());
+            }
+        };
+        match segment.kind {
+            Kind::Index(i) => match self.get_mut(i) {
+                Some(node) => node.set_path(rest, value),
+                None => Err(Error::OutOfBounds(segment.prefix.to_owned())),
+            },
+            Kind::Wildcard => self.iter_mut().try_for_each(|node| node.set_path(rest, value)),
+            Kind::Field(_) => Err(Error::Mismatch(segment.prefix.to_owned())),
+        }
+    }
+}
+
+/// Gets from an optional object, checking the path against a default object if it is absent.
+fn option_get<T: Node + Default>(node: Option<&T>, path: &[Segment<'_>], out: &mut Vec<Value>) -> Result<(), Error> {
+    match node {
+        Some(node) => node.get_path(path, out),
+        None => T::default().get_path(path, &mut Vec::new()),
+    }
+}
+
+fn channel_get(
+    channel: &Option<crate::DistributionChannel>,
+    name: &str,
+    path: &[Segment<'_>],
+    out: &mut Vec<Value>,
+) -> Result<(), Error> {
+    match (name, channel) {
+        ("site", Some(crate::DistributionChannel::Site(site))) => site.get_path(path, out),
+        ("app", Some(crate::DistributionChannel::App(app))) => app.get_path(path, out),
+        ("site", _) => option_get(None::<&crate::Site>, path, out),
+        _ => option_get(None::<&crate::App>, path, out),
+    }
+}
+
+fn channel_set<'a>(
+    channel: &mut Option<crate::DistributionChannel<'a>>,
+    segment: &Segment<'_>,
+    path: &[Segment<'_>],
+    value: &Value,
+) -> Result<(), Error> {
+    use crate::DistributionChannel::{App, Site};
+    match (segment.field()?, channel.take()) {
+        ("site", Some(Site(site))) => wrap_set(channel, Some(site), path, value, Site),
+        ("site", None) => wrap_set(channel, None, path, value, Site),
+        ("app", Some(App(app))) => wrap_set(channel, Some(app), path, value, App),
+        ("app", None) => wrap_set(channel, None, path, value, App),
+        (_, other) => {
+            *channel = other;
+            Err(Error::Mismatch(segment.prefix.to_owned()))
+        }
+    }
+}
+
+/// Sets into the site or the app of a request, which is put back even if setting fails.
+fn wrap_set<'a, T: Node + Default>(
+    channel: &mut Option<crate::DistributionChannel<'a>>,
+    mut node: Option<T>,
+    path: &[Segment<'_>],
+    value: &Value,
+    wrap: fn(T) -> crate::DistributionChannel<'a>,
+) -> Result<(), Error> {
+    let result = node.set_path(path, value);
+    *channel = node.map(wrap);
+    result
+}
+
+/// Gets from a value through its JSON representation.
+fn leaf_get<T: serde::Serialize>(leaf: &T, path: &[Segment<'_>], out: &mut Vec<Value>) -> Result<(), Error> {
+    json_get(&to_value(leaf)?, path, out)
+}
+
+/// Sets into a value through its JSON representation.
+fn leaf_set<'de, T>(leaf: &mut T, path: &[Segment<'_>], value: &Value) -> Result<(), Error>
+where
+    T: serde::Serialize + serde::Deserialize<'de>,
+{
+    let mut json = to_value(&*leaf)?;
+    json_set(&mut json, path, value)?;
+    *leaf = T::deserialize(json).map_err(|err| Error::Value {
+        path: String::new(),
+        message: err.to_string(),
+    })?;
+    Ok(())
+}
+
+fn to_value<T: serde::Serialize>(value: &T) -> Result<Value, Error> {
+    serde_json::to_value(value).map_err(|err| Error::Value {
+        path: String::new(),
+        message: err.to_string(),
+    })
+}
+
+fn json_get(value: &Value, path: &[Segment<'_>], out: &mut Vec<Value>) -> Result<(), Error> {
+    let (segment, rest) = match path.split_first() {
+        Some(split) => split,
+        None => {
+            if !value.is_null() {
+                out.push(value.clone());
+            }
+            return Ok(());
+        }
+    };
+    match (&segment.kind, value) {
+        (_, Value::Null) => Ok(()),
+        (Kind::Field(name), Value::Object(map)) => match map.get(*name) {
+            Some(value) => json_get(value, rest, out),
+            None => Ok(()),
+        },
+        (Kind::Index(i), Value::Array(items)) => match items.get(*i) {
+            Some(value) => json_get(value, rest, out),
+            None => Ok(()),
+        },
+        (Kind::Wildcard, Value::Array(items)) => items.iter().try_for_each(|value| json_get(value, rest, out)),
+        _ => Err(Error::Mismatch(segment.prefix.to_owned())),
+    }
+}
+
+fn json_set(value: &mut Value, path: &[Segment<'_>], new: &Value) -> Result<(), Error> {
+    let (segment, rest) = match path.split_first() {
+        Some(split) => split,
+        None => {
+            *value = new.clone();
+            return Ok(());
+        }
+    };
+    if let (Kind::Field(_), Value::Null) = (&segment.kind, &value) {
+        *value = Value::Object(Default::default());
+    }
+    match (&segment.kind, value) {
+        (Kind::Field(name), Value::Object(map)) => {
+            let value = map.entry(*name).or_insert(Value::Null);
+            json_set(value, rest, new)?;
+            if value.is_null() {
+                map.remove(*name);
+            }
+            Ok(())
+        }
+        (Kind::Index(i), Value::Array(items)) => match items.get_mut(*i) {
+            Some(value) => json_set(value, rest, new),
+            None => Err(Error::OutOfBounds(segment.prefix.to_owned())),
+        },
+        (Kind::Wildcard, Value::Array(items)) => items.iter_mut().try_for_each(|value| json_set(value, rest, new)),
+        (Kind::Wildcard, Value::Null) => Ok(()),
+        _ => Err(Error::Mismatch(segment.prefix.to_owned())),
+    }
+}
+
+/// Returns the JSON name of a field, i.e. its identifier without the raw prefix.
+fn field_name(ident: &str) -> &str {
+    ident.trim_start_matches("r#")
+}
+
+macro_rules! impl_node {
+    ($ty:ident {
+        leaf: [$($leaf:ident),*],
+        node: [$($node:ident),*],
+        $(channel: $channel:ident,)?
+    }) => {
+        impl<'a> Node for crate::$ty<'a> {
+            #[allow(deprecated)]
+            fn get_path(&self, path: &[Segment<'_>], out: &mut Vec<Value>) -> Result<(), Error> {
+                // Fails to compile when a field is added without being listed.
+                let crate::$ty { $($leaf: _,)* $($node: _,)* $($channel: _,)? } = self;
+                let (segment, rest) = match path.split_first() {
+                    Some(split) => split,
+                    None => return leaf_get(self, path, out),
+                };
+                let name = segment.field()?;
+                $(if name == field_name(stringify!($leaf)) {
+                    return leaf_get(&self.$leaf, rest, out);
+                })*
+                $(if name == field_name(stringify!($node)) {
+                    return self.$node.get_path(rest, out);
+                })*
+                $(if name == "site" || name == "app" {
+                    return channel_get(&self.$channel, name, rest, out);
+                })?
+                Err(Error::UnknownField(segment.prefix.to_owned()))
+            }
+
+            #[allow(deprecated)]
+            fn set_path(&mut self, path: &[Segment<'_>], value: &Value) -> Result<(), Error> {
+                let (segment, rest) = match path.split_first() {
+                    Some(split) => split,
+                    None => return leaf_set(self, path, value),
+                };
+                let name = segment.field()?;
+                $(if name == field_name(stringify!($leaf)) {
+                    return leaf_set(&mut self.$leaf, rest, value);
+                })*
+                $(if name == field_name(stringify!($node)) {
+                    return self.$node.set_path(rest, value);
+                })*
+                $(if name == "site" || name == "app" {
+                    return channel_set(&mut self.$channel, segment, rest, value);
+                })?
+                Err(Error::UnknownField(segment.prefix.to_owned()))
+            }
+        }
+    };
+}
+
+impl_node!(BidRequest {
+    leaf: [id, test, at, tmax, wseat, bseat, allimps, cur, wlang, bcat, cattax, badv, bapp, ext],
+    node: [imp, device, user, source, regs],
+    channel: channel,
+});
+impl_node!(Source {
+    leaf: [fd, tid, pchain, ext],
+    node: [],
+});
+impl_node!(Regs {
+    leaf: [coppa, ext],
+    node: [],
+});
+impl_node!(Imp {
+    leaf: [
+        id,
+        displaymanager,
+        displaymanagerver,
+        instl,
+        tagid,
+        bidfloor,
+        bidfloorcur,
+        clickbrowser,
+        secure,
+        iframebuster,
+        exp,
+        ext
+    ],
+    node: [metric, banner, video, audio, native, pmp],
+});
+impl_node!(Metric {
+    leaf: [r#type, value, vendor, ext],
+    node: [],
+});
+impl_node!(Banner {
+    leaf: [w, h, wmax, hmax, wmin, hmin, btype, battr, pos, mimes, topframe, expdir, api, id, vcm, ext],
+    node: [format],
+});
+impl_node!(Format {
+    leaf: [w, h, wratio, hratio, wmin, ext],
+    node: [],
+});
+impl_node!(Video {
+    leaf: [
+        mimes,
+        minduration,
+        maxduration,
+        protocols,
+        w,
+        h,
+        startdelay,
+        placement,
+        linearity,
+        skip,
+        skipmin,
+        skipafter,
+        sequence,
+        battr,
+        maxextended,
+        minbitrate,
+        maxbitrate,
+        boxingallowed,
+        playbackmethod,
+        playbackend,
+        delivery,
+        pos,
+        api,
+        companiontype,
+        ext
+    ],
+    node: [companionad],
+});
+impl_node!(Audio {
+    leaf: [
+        mimes,
+        minduration,
+        maxduration,
+        protocols,
+        startdelay,
+        sequence,
+        battr,
+        maxextended,
+        minbitrate,
+        maxbitrate,
+        delivery,
+        api,
+        companiontype,
+        maxseq,
+        feed,
+        stitched,
+        nvol,
+        ext
+    ],
+    node: [companionad],
+});
+impl_node!(Native {
+    leaf: [request, ver, api, battr, ext],
+    node: [],
+});
+impl_node!(Pmp {
+    leaf: [private_auction, ext],
+    node: [deals],
+});
+impl_node!(Deal {
+    leaf: [id, bidfloor, bidfloorcur, at, wseat, wadomain, ext],
+    node: [],
+});
+impl_node!(Site {
+    leaf: [
+        id,
+        name,
+        domain,
+        cat,
+        sectioncat,
+        pagecat,
+        cattax,
+        page,
+        r#ref,
+        search,
+        mobile,
+        privacypolicy,
+        keywords,
+        ext
+    ],
+    node: [publisher, content],
+});
+impl_node!(App {
+    leaf: [
+        id,
+        name,
+        bundle,
+        domain,
+        storeurl,
+        cat,
+        sectioncat,
+        pagecat,
+        cattax,
+        ver,
+        privacypolicy,
+        paid,
+        keywords,
+        ext
+    ],
+    node: [publisher, content],
+});
+impl_node!(Publisher {
+    leaf: [id, name, cat, cattax, domain, ext],
+    node: [],
+});
+impl_node!(Content {
+    leaf: [
+        id,
+        episode,
+        title,
+        series,
+        season,
+        artist,
+        genre,
+        album,
+        isrc,
+        url,
+        cat,
+        cattax,
+        prodq,
+        videoquality,
+        context,
+        contentrating,
+        userrating,
+        qagmediarating,
+        keywords,
+        livestream,
+        sourcerelationship,
+        len,
+        language,
+        embeddable,
+        ext
+    ],
+    node: [producer, data],
+});
+impl_node!(Producer {
+    leaf: [id, name, cat, cattax, domain, ext],
+    node: [],
+});
+impl_node!(Device {
+    leaf: [
+        ua,
+        dnt,
+        lmt,
+        ip,
+        ipv6,
+        devicetype,
+        make,
+        model,
+        os,
+        osv,
+        hwv,
+        h,
+        w,
+        ppi,
+        pxratio,
+        js,
+        geofetch,
+        flashver,
+        language,
+        carrier,
+        mccmnc,
+        connectiontype,
+        ifa,
+        didsha1,
+        didmd5,
+        dpidsha1,
+        dpidmd5,
+        macsha1,
+        macmd5,
+        ext
+    ],
+    node: [geo],
+});
+impl_node!(Geo {
+    leaf: [
+        lat,
+        lon,
+        r#type,
+        accuracy,
+        lastfix,
+        ipservice,
+        country,
+        region,
+        regionfips104,
+        metro,
+        city,
+        zip,
+        utcoffset,
+        ext
+    ],
+    node: [],
+});
+impl_node!(User {
+    leaf: [id, buyeruid, yob, gender, keywords, customdata, ext],
+    node: [geo, data],
+});
+impl_node!(Data {
+    leaf: [id, name, ext],
+    node: [segment],
+});
+impl_node!(Segment {
+    leaf: [id, name, value, ext],
+    node: [],
+});
+impl_node!(BidResponse {
+    leaf: [id, bidid, cur, customdata, nbr, ext],
+    node: [seatbid],
+});
+impl_node!(SeatBid {
+    leaf: [seat, group, ext],
+    node: [bid],
+});
+impl_node!(Bid {
+    leaf: [
+        id,
+        impid,
+        price,
+        nurl,
+        burl,
+        lurl,
+        adm,
+        adid,
+        adomain,
+        bundle,
+        iurl,
+        cid,
+        crid,
+        tactic,
+        cat,
+        cattax,
+        attr,
+        api,
+        protocol,
+        qagmediarating,
+        language,
+        dealid,
+        w,
+        h,
+        wratio,
+        hratio,
+        exp,
+        ext
+    ],
+    node: [],
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let paths = |path| -> Result<Vec<String>, Error> {
+            Ok(super::parse(path)?
+                .iter()
+                .map(|segment| segment.prefix.to_owned())
+                .collect())
+        };
+        assert_eq!(paths(""), Ok(vec![]));
+        assert_eq!(
+            paths("imp[0].banner.format[*].w"),
+            Ok(vec![
+                "imp".to_owned(),
+                "imp[0]".to_owned(),
+                "imp[0].banner".to_owned(),
+                "imp[0].banner.format".to_owned(),
+                "imp[0].banner.format[*]".to_owned(),
+                "imp[0].banner.format[*].w".to_owned(),
+            ])
+        );
+        for path in ["imp.", ".imp", "imp[", "imp[x]", "imp[0]x", "[0]", "imp[0]]"] {
+            assert_eq!(paths(path), Err(Error::Syntax(path.to_owned())), "{}", path);
+        }
+    }
+
+    #[test]
+    fn get() -> Result<(), Box<dyn std::error::Error>> {
+        let json = include_str!("../tests/json/6.3.4_video.json");
+        let req: crate::BidRequest = serde_json::from_str(json)?;
+
+        assert_eq!(super::get(&req, "imp[0].video.w")?, Some(640.into()));
+        assert_eq!(
+            get_all(&req, "imp[*].video.companionad[*].w")?,
+            [300.into(), 728.into()] as [Value; 2]
+        );
+        assert_eq!(super::get(&req, "site.publisher.id")?, Some("pub12345".into()));
+        assert_eq!(super::get(&req, "app.bundle")?, None);
+        assert_eq!(super::get(&req, "imp[3].id")?, None);
+        assert_eq!(super::get(&req, "source.tid")?, None);
+        assert_eq!(
+            super::get(&req, "bcat[1]")?,
+            req.bcat.as_ref().map(|bcat| bcat[1].as_ref().into())
+        );
+        assert_eq!(super::get(&req, "")?, Some(serde_json::to_value(&req)?));
+
+        assert_eq!(
+            super::get(&req, "source.tidd"),
+            Err(Error::UnknownField("source.tidd".into()))
+        );
+        assert_eq!(
+            super::get(&req, "imp[0].native.requests"),
+            Err(Error::UnknownField("imp[0].native.requests".into()))
+        );
+        assert_eq!(super::get(&req, "imp.id"), Err(Error::Mismatch("imp.id".into())));
+        assert_eq!(
+            super::get(&req, "imp[0].video.w.x"),
+            Err(Error::Mismatch("imp[0].video.w.x".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn set() -> Result<(), Box<dyn std::error::Error>> {
+        let mut req = crate::BidRequest::default();
+        super::set(&mut req, "imp", serde_json::json!([{"id": "1"}, {"id": "2"}]))?;
+        super::set(&mut req, "imp[*].bidfloor", 1.5)?;
+        super::set(
+            &mut req,
+            "imp[1].banner.format",
+            serde_json::json!([{"w": 300, "h": 250}]),
+        )?;
+        super::set(&mut req, "app.publisher.id", "p")?;
+        super::set(&mut req, "user.ext.eids[0]", "x").unwrap_err();
+        super::set(&mut req, "user.ext.consent", "abc")?;
+        super::set(&mut req, "bcat", serde_json::json!(["IAB25"]))?;
+
+        assert_eq!(req.imp.len(), 2);
+        assert!(req.imp.iter().all(|imp| imp.bidfloor == Some(1.5)));
+        assert_eq!(super::get(&req, "imp[1].banner.format[0].h")?, Some(250.into()));
+        assert!(req.channel.as_ref().is_some_and(|channel| !channel.is_site()));
+        assert_eq!(
+            super::get(&req, "user.ext")?,
+            Some(serde_json::json!({"consent": "abc"}))
+        );
+
+        super::set(&mut req, "user.ext.consent", Value::Null)?;
+        assert_eq!(super::get(&req, "user.ext")?, Some(serde_json::json!({})));
+        super::set(&mut req, "user", Value::Null)?;
+        assert_eq!(req.user, None);
+
+        assert_eq!(
+            super::set(&mut req, "site.page", "https://example.com"),
+            Err(Error::Mismatch("site".into()))
+        );
+        assert!(req.channel.is_some());
+        assert_eq!(
+            super::set(&mut req, "imp[2].bidfloor", 1.0),
+            Err(Error::OutOfBounds("imp[2]".into()))
+        );
+        assert!(matches!(
+            super::set(&mut req, "imp[0].bidfloor", "high"),
+            Err(Error::Value { path, .. }) if path == "imp[0].bidfloor"
+        ));
+        assert_eq!(req.imp[0].bidfloor, Some(1.5));
+        assert_eq!(
+            super::set(&mut req, "device.geo.town", "x"),
+            Err(Error::UnknownField("device.geo.town".into()))
+        );
+        assert_eq!(req.device, None);
+
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@
 ///
 /// The following table lists the various modes for when playback terminates.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum PlaybackCessationMode {
     /// On Video Completion or when Terminated by User
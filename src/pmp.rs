@@ -4,11 +4,15 @@
 /// that may pertain to this impression. The actual deals are represented as a collection of Deal
 /// objects. Refer to Section 7.3 for more details.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Pmp<'a> {
     /// integer; default 0
     /// Indicator of auction eligibility to seats named in the Direct Deals object, where 0 = all
     /// bids are accepted, 1 = bids are restricted to the deals specified and the terms thereof.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub private_auction: Option<json_ext::Flag>,
 
     /// object array
@@ -20,6 +24,8 @@ pub struct Pmp<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
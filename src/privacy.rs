@@ -0,0 +1,351 @@
+//! Privacy scrubbing.
+//!
+//! Requests subject to COPPA (Section 7.5), "Limit Ad Tracking" or "Do Not Track", or to GDPR
+//! without consent must not carry data identifying the user. A scrub [`Profile`] removes or
+//! coarsens these fields in place:
+//!
+//! | Field                                                         | Coppa     | Lmt       | GdprNoConsent |
+//! |---------------------------------------------------------------|-----------|-----------|---------------|
+//! | `device.ifa`, `did*`, `dpid*`, `mac*`                         | removed   | removed   | removed       |
+//! | `device.ip` / `device.ipv6`                                   | /24, /48  | /24, /48  | /24, /48      |
+//! | `device.geo.lat/lon`, `user.geo.lat/lon`                      | 2 decimals| 2 decimals| 2 decimals    |
+//! | `user.id`, `user.buyeruid`, `user.customdata`                 | removed   | removed   | removed       |
+//! | `user.yob`, `user.gender`                                     | removed   | kept      | removed       |
+//! | `user.data`                                                   | kept      | kept      | removed       |
+//!
+//! [`Profile`]: ./enum.Profile.html
+
+/// A named set of scrubbing rules.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Profile {
+    /// The request falls under COPPA (`regs.coppa` = 1).
+    Coppa,
+    /// The user limits ad tracking (`device.lmt` = 1 or `device.dnt` = 1).
+    Lmt,
+    /// GDPR applies and the user did not consent.
+    GdprNoConsent,
+}
+
+impl Profile {
+    /// Number of decimals kept in latitude and longitude.
+    pub const GEO_DECIMALS: i32 = 2;
+
+    /// Returns the profiles whose signals are set in `req`. GDPR consent is not evaluated here as
+    /// it depends on the vendor the request is sent to.
+    ///
+    /// ```
+    /// # use openrtb2::privacy::Profile;
+    /// let req: openrtb2::BidRequest =
+    ///     serde_json::from_str(r#"{"id":"1","imp":[],"device":{"dnt":1},"regs":{"coppa":1}}"#)?;
+    /// assert_eq!(Profile::applicable(&req), vec![Profile::Coppa, Profile::Lmt]);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn applicable(req: &crate::BidRequest) -> Vec<Self> {
+        let mut profiles = Vec::new();
+        if req.regs.as_ref().is_some_and(|regs| crate::ext::is_set(regs.coppa)) {
+            profiles.push(Self::Coppa);
+        }
+        if req
+            .device
+            .as_ref()
+            .is_some_and(|device| crate::ext::is_set(device.lmt) || crate::ext::is_set(device.dnt))
+        {
+            profiles.push(Self::Lmt);
+        }
+        profiles
+    }
+
+    /// Scrubs `req` in place according to this profile.
+    pub fn scrub(&self, req: &mut crate::BidRequest) {
+        if let Some(ref mut device) = req.device {
+            device.ifa = None;
+            device.didsha1 = None;
+            device.didmd5 = None;
+            device.dpidsha1 = None;
+            device.dpidmd5 = None;
+            device.macsha1 = None;
+            device.macmd5 = None;
+            device.anonymize_ips(crate::ip::Ipv6Prefix::Bits48);
+            if let Some(ref mut geo) = device.geo {
+                round_geo(geo);
+            }
+        }
+
+        if let Some(ref mut user) = req.user {
+            user.id = None;
+            user.buyeruid = None;
+            user.customdata = None;
+            if matches!(self, Self::Coppa | Self::GdprNoConsent) {
+                user.yob = None;
+                user.gender = None;
+            }
+            if let Self::GdprNoConsent = self {
+                user.data = None;
+            }
+            if let Some(ref mut geo) = user.geo {
+                round_geo(geo);
+            }
+        }
+    }
+}
+
+/// Applies every profile whose signals are set in `req` and returns them.
+pub fn scrub(req: &mut crate::BidRequest) -> Vec<Profile> {
+    let profiles = Profile::applicable(req);
+    for profile in profiles.iter() {
+        profile.scrub(req);
+    }
+    profiles
+}
+
+/// The privacy signals of a request gathered from `regs` and its extensions.
+///
+/// Malformed US Privacy and GPP strings are kept as errors, and forbid selling or sharing.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PrivacySignals {
+    /// `regs.coppa` is set.
+    pub coppa: bool,
+    /// `regs.ext.gdpr`, None if absent.
+    pub gdpr: Option<bool>,
+    /// `regs.ext.us_privacy`, an error if malformed.
+    pub us_privacy: Option<Result<crate::us_privacy::UsPrivacy, crate::us_privacy::Error>>,
+    /// `regs.ext.gpp`, an error if malformed.
+    #[cfg(feature = "gpp")]
+    pub gpp: Option<Result<crate::gpp::GppString, crate::gpp::Error>>,
+    /// `regs.ext.gpp_sid`, the GPP sections applicable to the request. All sections apply if None.
+    pub gpp_sid: Option<Vec<u16>>,
+}
+
+impl PrivacySignals {
+    /// Gathers the privacy signals of `req`.
+    ///
+    /// ```
+    /// # use openrtb2::privacy::PrivacySignals;
+    /// let req: openrtb2::BidRequest =
+    ///     serde_json::from_str(r#"{"id":"1","imp":[],"regs":{"ext":{"us_privacy":"1YYN"}}}"#)?;
+    /// assert!(!PrivacySignals::from_request(&req).may_sell_or_share());
+    /// assert!(PrivacySignals::default().may_sell_or_share());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn from_request(req: &crate::BidRequest) -> Self {
+        let regs = match req.regs {
+            Some(ref regs) => regs,
+            None => return Self::default(),
+        };
+        Self {
+            coppa: crate::ext::is_set(regs.coppa),
+            gdpr: regs.gdpr(),
+            us_privacy: regs.us_privacy(),
+            #[cfg(feature = "gpp")]
+            gpp: regs.gpp(),
+            gpp_sid: regs.gpp_sid(),
+        }
+    }
+
+    /// Returns false if personal information of the user must not be sold or shared: the request
+    /// falls under COPPA, the user opted out through the US Privacy string or an applicable GPP
+    /// section, or one of these strings is malformed.
+    pub fn may_sell_or_share(&self) -> bool {
+        if self.coppa {
+            return false;
+        }
+        match self.us_privacy {
+            Some(Ok(usp)) if !usp.may_sell() => return false,
+            Some(Err(_)) => return false,
+            _ => {}
+        }
+        #[cfg(feature = "gpp")]
+        if let Some(ref gpp) = self.gpp {
+            let gpp = match gpp {
+                Ok(gpp) => gpp,
+                Err(_) => return false,
+            };
+            let applies = |id| self.gpp_sid.as_ref().is_none_or(|sid| sid.contains(&id));
+            if applies(crate::gpp::US_PRIVACY) && gpp.us_privacy().is_some_and(|usp| !usp.may_sell()) {
+                return false;
+            }
+            if applies(crate::gpp::US_NATIONAL) && gpp.us_national().is_some_and(|usnat| !usnat.may_sell_or_share()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Zeroes the host part of an IP address: the last octet of an IPv4 address, everything after the
+/// first 48 bits of an IPv6 address. Returns None if `ip` is not an IP address.
+///
+/// ```
+/// # use openrtb2::privacy::truncate_ip;
+/// assert_eq!(truncate_ip("123.145.167.10").as_deref(), Some("123.145.167.0"));
+/// assert_eq!(truncate_ip("2001:db8:85a3:8d3:1319:8a2e:370:7348").as_deref(), Some("2001:db8:85a3::"));
+/// assert_eq!(truncate_ip("localhost"), None);
+/// ```
+pub fn truncate_ip(ip: &str) -> Option<String> {
+    Some(match crate::ip::parse(ip)? {
+        std::net::IpAddr::V4(v4) => crate::ip::truncate_ipv4(v4).to_string(),
+        std::net::IpAddr::V6(v6) => crate::ip::truncate_ipv6(v6, crate::ip::Ipv6Prefix::Bits48).to_string(),
+    })
+}
+
+pub(crate) fn round_geo(geo: &mut crate::Geo) {
+    let factor = 10f32.powi(Profile::GEO_DECIMALS);
+    let round = |value: f32| (value * factor).round() / factor;
+    geo.lat = geo.lat.map(round);
+    geo.lon = geo.lon.map(round);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const JSON: &str = r#"{
+        "id": "1",
+        "imp": [],
+        "device": {
+            "ua": "ua",
+            "geo": {"lat": 35.012345, "lon": 139.987654, "country": "JPN"},
+            "lmt": 1,
+            "ip": "192.168.1.23",
+            "ipv6": "2001:db8:85a3:8d3:1319:8a2e:370:7348",
+            "make": "Apple",
+            "ifa": "ifa",
+            "didsha1": "didsha1",
+            "didmd5": "didmd5",
+            "dpidsha1": "dpidsha1",
+            "dpidmd5": "dpidmd5",
+            "macsha1": "macsha1",
+            "macmd5": "macmd5"
+        },
+        "user": {
+            "id": "id",
+            "buyeruid": "buyeruid",
+            "yob": 2000,
+            "gender": "F",
+            "keywords": "keywords",
+            "customdata": "customdata",
+            "geo": {"lat": 35.5, "lon": 139.5},
+            "data": [{"id": "data"}]
+        },
+        "regs": {"coppa": 1}
+    }"#;
+
+    fn scrubbed(profile: Profile) -> serde_json::Result<serde_json::Value> {
+        let mut req: crate::BidRequest = serde_json::from_str(JSON)?;
+        profile.scrub(&mut req);
+        serde_json::to_value(&req)
+    }
+
+    #[test]
+    fn coppa() -> serde_json::Result<()> {
+        let mut json = scrubbed(Profile::Coppa)?;
+        let geo = json["device"]["geo"].take();
+        let geo = <crate::Geo as serde::Deserialize>::deserialize(&geo)?;
+        assert_eq!((geo.lat, geo.lon), (Some(35.01), Some(139.99)));
+        assert_eq!(geo.country.as_deref(), Some("JPN"));
+        assert_eq!(
+            json["device"],
+            serde_json::json!({
+                "ua": "ua",
+                "geo": null,
+                "lmt": 1,
+                "ip": "192.168.1.0",
+                "ipv6": "2001:db8:85a3::",
+                "make": "Apple"
+            })
+        );
+        assert_eq!(
+            json["user"],
+            serde_json::json!({
+                "keywords": "keywords",
+                "geo": {"lat": 35.5, "lon": 139.5},
+                "data": [{"id": "data"}]
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn lmt() -> serde_json::Result<()> {
+        let json = scrubbed(Profile::Lmt)?;
+        assert_eq!(json["device"]["ifa"], serde_json::Value::Null);
+        assert_eq!(json["device"]["ip"], "192.168.1.0");
+        assert_eq!(
+            json["user"],
+            serde_json::json!({
+                "yob": 2000,
+                "gender": "F",
+                "keywords": "keywords",
+                "geo": {"lat": 35.5, "lon": 139.5},
+                "data": [{"id": "data"}]
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn gdpr_no_consent() -> serde_json::Result<()> {
+        let json = scrubbed(Profile::GdprNoConsent)?;
+        assert_eq!(json["device"]["macmd5"], serde_json::Value::Null);
+        assert_eq!(
+            json["user"],
+            serde_json::json!({
+                "keywords": "keywords",
+                "geo": {"lat": 35.5, "lon": 139.5}
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn applicable() -> serde_json::Result<()> {
+        let mut req: crate::BidRequest = serde_json::from_str(JSON)?;
+        assert_eq!(scrub(&mut req), vec![Profile::Coppa, Profile::Lmt]);
+        assert_eq!(req.user.unwrap().yob, None);
+
+        assert!(Profile::applicable(&crate::BidRequest::default()).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn signals() -> serde_json::Result<()> {
+        let req: crate::BidRequest = serde_json::from_str(JSON)?;
+        let signals = PrivacySignals::from_request(&req);
+        assert!(signals.coppa);
+        assert!(!signals.may_sell_or_share());
+
+        let req: crate::BidRequest =
+            serde_json::from_str(r#"{"id":"1","imp":[],"regs":{"ext":{"gdpr":0,"us_privacy":"1YNN","gpp_sid":[7]}}}"#)?;
+        let signals = PrivacySignals::from_request(&req);
+        assert_eq!(signals.gdpr, Some(false));
+        assert_eq!(signals.gpp_sid, Some(vec![7]));
+        assert!(signals.may_sell_or_share());
+
+        let req: crate::BidRequest =
+            serde_json::from_str(r#"{"id":"1","imp":[],"regs":{"ext":{"us_privacy":"bad"}}}"#)?;
+        let signals = PrivacySignals::from_request(&req);
+        assert!(signals.us_privacy.as_ref().unwrap().is_err());
+        assert!(!signals.may_sell_or_share());
+        Ok(())
+    }
+
+    #[cfg(feature = "gpp")]
+    #[test]
+    fn gpp_signals() -> serde_json::Result<()> {
+        for (sid, may) in [("[7]", false), ("[8]", true)] {
+            let json = format!(
+                r#"{{"id":"1","imp":[],"regs":{{"ext":{{"gpp":"DBACLY~BVQWAAAAAa.Y~BVQqAAAAAa","gpp_sid":{}}}}}}}"#,
+                sid
+            );
+            let req: crate::BidRequest = serde_json::from_str(&json)?;
+            assert_eq!(PrivacySignals::from_request(&req).may_sell_or_share(), may);
+        }
+
+        let req: crate::BidRequest =
+            serde_json::from_str(r#"{"id":"1","imp":[],"regs":{"ext":{"gpp":"garbage","gpp_sid":[7]}}}"#)?;
+        let signals = PrivacySignals::from_request(&req);
+        assert!(signals.gpp.as_ref().unwrap().is_err());
+        assert!(!signals.may_sell_or_share());
+        Ok(())
+    }
+}
@@ -4,6 +4,8 @@
 /// particularly useful when the content is syndicated and may be distributed through different
 /// publishers and thus when the producer and publisher are not necessarily the same entity.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Producer<'a> {
     /// string
     /// Content producer or originator ID. Useful if content is syndicated and may be posted on a
@@ -21,6 +23,12 @@ pub struct Producer<'a> {
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub cat: Option<Vec<std::borrow::Cow<'a, str>>>,
 
+    /// integer; default 1
+    /// The taxonomy in use. If no cattax field is supplied IAB Content Category Taxonomy 1.0 is
+    /// assumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
     /// string
     /// Highest level domain of the content producer (e.g., “producer.com”).
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
@@ -29,6 +37,8 @@ pub struct Producer<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
@@ -3,6 +3,8 @@
 /// The following table lists the options for content quality. These values are defined by the IAB;
 /// refer to www.iab.com/wp-content/uploads/2015/03/long-form-video-final.pdf for more information.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum ProductionQuality {
     /// Unknown
@@ -3,6 +3,8 @@
 /// The following table lists the options for the various bid response protocols that could be
 /// supported by an exchange.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum Protocol {
     /// VAST 1.0
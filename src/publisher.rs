@@ -3,6 +3,8 @@
 /// This object describes the publisher of the media in which the ad will be displayed. The
 /// publisher is typically the seller in an OpenRTB transaction.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Publisher<'a> {
     /// string
     /// Exchange-specific publisher ID.
@@ -19,6 +21,12 @@ pub struct Publisher<'a> {
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub cat: Option<Vec<std::borrow::Cow<'a, str>>>,
 
+    /// integer; default 1
+    /// The taxonomy in use. If no cattax field is supplied IAB Content Category Taxonomy 1.0 is
+    /// assumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
     /// string
     /// Highest level domain of the publisher (e.g., “publisher.com”).
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
@@ -27,6 +35,8 @@ pub struct Publisher<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
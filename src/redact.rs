@@ -0,0 +1,430 @@
+//! Redaction of personal data for logging.
+//!
+//! The derived `Debug` of the objects prints identifiers, addresses and consent strings in full.
+//! [`Redact::redacted`] wraps an object so that its `Debug`, `Display` (JSON) and `Serialize`
+//! output masks these fields according to a [`Policy`], while keeping the rest of the structure:
+//!
+//! | Field                                                         | Policy field  | Default  |
+//! |---------------------------------------------------------------|---------------|----------|
+//! | `device.ip`, `device.ipv6`                                    | `ip`          | truncate |
+//! | `device.ifa`, `did*`, `dpid*`, `mac*`                         | `device_ids`  | hash     |
+//! | `user.id`, `user.buyeruid`, `user.ext.eids[*].uids[*].id`     | `user_ids`    | hash     |
+//! | `lat` and `lon` of `device.geo` and `user.geo`                | `location`    | truncate |
+//! | `user.customdata`                                             | `custom_data` | drop     |
+//! | `user.ext.consent`, `regs.ext.gpp`                            | `consent`     | truncate |
+//!
+//! Hashed values are keyed digests: without [`Policy::key`], which is random for each process
+//! unless set, they cannot be matched against digests of candidate values such as every IPv4
+//! address. Without a key, e.g. if the operating system provides no random bytes, the fields of
+//! the `Hash` mode are dropped.
+//!
+//! This module requires the `redact` feature. On `wasm32-unknown-unknown`, the process key needs
+//! the `js` feature of the `getrandom` crate.
+//!
+//! ```
+//! # use openrtb2::redact::Redact;
+//! let req: openrtb2::BidRequest = serde_json::from_str(
+//!     r#"{"id":"1","imp":[],"device":{"ip":"192.0.2.17","ifa":"cd4e6d6a-9c69-4d92-9e6b-6dc1c4a1a0d9"}}"#,
+//! )?;
+//! let log = format!("{}", req.redacted());
+//! assert!(log.contains(r#""ip":"192.0.2.0""#));
+//! assert!(!log.contains("cd4e6d6a"));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`Redact::redacted`]: ./trait.Redact.html#method.redacted
+//! [`Policy`]: ./struct.Policy.html
+//! [`Policy::key`]: ./struct.Policy.html#structfield.key
+
+use crate::visit::VisitMut;
+
+/// How a personal field is masked.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+    /// The value is kept.
+    Keep,
+    /// The value is replaced by its keyed BLAKE3 digest under [`Policy::key`], so that equal values
+    /// can still be correlated across log lines but not recovered by hashing candidate values
+    /// without the key. Coordinates, which cannot hold a digest, are dropped.
+    ///
+    /// [`Policy::key`]: ./struct.Policy.html#structfield.key
+    Hash,
+    /// The value is coarsened: IP addresses to /24 and /48, coordinates to 2 decimals and other
+    /// strings to their first 4 characters.
+    Truncate,
+    /// The field is removed.
+    Drop,
+}
+
+/// The masking applied to each group of personal fields.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[serde(default)]
+pub struct Policy {
+    /// `device.ip` and `device.ipv6`.
+    pub ip: Mode,
+
+    /// `device.ifa` and the hashed device and MAC identifiers.
+    pub device_ids: Mode,
+
+    /// `user.id`, `user.buyeruid` and the ids of `user.ext.eids`.
+    pub user_ids: Mode,
+
+    /// `lat` and `lon` of `device.geo` and `user.geo`.
+    pub location: Mode,
+
+    /// `user.customdata`.
+    pub custom_data: Mode,
+
+    /// `user.ext.consent` and `regs.ext.gpp`.
+    pub consent: Mode,
+
+    /// Secret key of the `Hash` mode, the key of the process by default. Processes whose logs are
+    /// correlated must share a key. The fields of the `Hash` mode are dropped if None. Never
+    /// serialized.
+    #[serde(skip, default = "HashKey::process")]
+    pub key: Option<HashKey>,
+}
+
+/// A secret key of the `Hash` mode. Its `Debug` output does not show the key.
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
+pub struct HashKey([u8; 32]);
+
+impl HashKey {
+    /// Creates a key from 32 secret bytes.
+    pub fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the key generated at random for the process, None if the operating system provides
+    /// no random bytes.
+    pub fn process() -> Option<Self> {
+        static KEY: std::sync::OnceLock<Option<HashKey>> = std::sync::OnceLock::new();
+        *KEY.get_or_init(|| {
+            let mut bytes = [0; 32];
+            getrandom::getrandom(&mut bytes).ok()?;
+            Some(HashKey(bytes))
+        })
+    }
+}
+
+impl std::fmt::Debug for HashKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("HashKey(..)")
+    }
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            ip: Mode::Truncate,
+            device_ids: Mode::Hash,
+            user_ids: Mode::Hash,
+            location: Mode::Truncate,
+            custom_data: Mode::Drop,
+            consent: Mode::Truncate,
+            key: HashKey::process(),
+        }
+    }
+}
+
+/// Objects carrying personal fields.
+pub trait Redact: Clone {
+    /// Masks the personal fields in place.
+    fn redact(&mut self, policy: &Policy);
+
+    /// Wraps `self` for logging with the default policy.
+    fn redacted(&self) -> Redacted<'_, Self> {
+        self.redacted_with(Policy::default())
+    }
+
+    /// Wraps `self` for logging with `policy`.
+    fn redacted_with(&self, policy: Policy) -> Redacted<'_, Self> {
+        Redacted { value: self, policy }
+    }
+}
+
+/// An object whose `Debug`, `Display` and `Serialize` output is redacted.
+///
+/// The object is cloned and redacted each time it is formatted or serialized.
+#[derive(Clone, Copy)]
+pub struct Redacted<'r, T> {
+    value: &'r T,
+    policy: Policy,
+}
+
+impl<T: Redact> Redacted<'_, T> {
+    /// Returns a redacted copy of the object.
+    pub fn to_inner(&self) -> T {
+        let mut value = self.value.clone();
+        value.redact(&self.policy);
+        value
+    }
+}
+
+impl<T: Redact + std::fmt::Debug> std::fmt::Debug for Redacted<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_inner().fmt(f)
+    }
+}
+
+impl<T: Redact + serde::Serialize> std::fmt::Display for Redacted<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string(&self.to_inner()).map_err(|_| std::fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+impl<T: Redact + serde::Serialize> serde::Serialize for Redacted<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_inner().serialize(serializer)
+    }
+}
+
+macro_rules! impl_redact {
+    ($($ty:ident => $visit:ident),*) => {
+        $(
+            impl<'a> Redact for crate::$ty<'a> {
+                fn redact(&mut self, policy: &Policy) {
+                    Redactor(policy).$visit(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_redact!(
+    BidRequest => visit_bid_request_mut,
+    Device => visit_device_mut,
+    Geo => visit_geo_mut,
+    User => visit_user_mut,
+    Regs => visit_regs_mut
+);
+
+struct Redactor<'p>(&'p Policy);
+
+impl<'a> VisitMut<'a> for Redactor<'_> {
+    fn visit_device_mut(&mut self, device: &mut crate::Device<'a>) {
+        match self.0.ip {
+            Mode::Truncate => device.anonymize_ips(crate::ip::Ipv6Prefix::Bits48),
+            mode => {
+                mask(self.0.key.as_ref(), mode, &mut device.ip);
+                mask(self.0.key.as_ref(), mode, &mut device.ipv6);
+            }
+        }
+        for id in [
+            &mut device.ifa,
+            &mut device.didsha1,
+            &mut device.didmd5,
+            &mut device.dpidsha1,
+            &mut device.dpidmd5,
+            &mut device.macsha1,
+            &mut device.macmd5,
+        ] {
+            mask(self.0.key.as_ref(), self.0.device_ids, id);
+        }
+        crate::visit::visit_device_mut(self, device);
+    }
+
+    fn visit_geo_mut(&mut self, geo: &mut crate::Geo<'a>) {
+        match self.0.location {
+            Mode::Keep => {}
+            Mode::Truncate => crate::privacy::round_geo(geo),
+            Mode::Hash | Mode::Drop => {
+                geo.lat = None;
+                geo.lon = None;
+            }
+        }
+    }
+
+    fn visit_user_mut(&mut self, user: &mut crate::User<'a>) {
+        mask(self.0.key.as_ref(), self.0.user_ids, &mut user.id);
+        mask(self.0.key.as_ref(), self.0.user_ids, &mut user.buyeruid);
+        mask(self.0.key.as_ref(), self.0.custom_data, &mut user.customdata);
+        mask_ext(self.0.key.as_ref(), self.0.consent, &mut user.ext, "consent");
+        if let Some(mut eids) = crate::ext::remove(&mut user.ext, "eids") {
+            let ids = eids
+                .as_array_mut()
+                .into_iter()
+                .flatten()
+                .filter_map(|eid| eid.get_mut("uids")?.as_array_mut())
+                .flatten()
+                .filter_map(|uid| uid.as_object_mut());
+            for uid in ids {
+                if let Some(id) = uid.remove("id") {
+                    if let Some(id) = mask_value(self.0.key.as_ref(), self.0.user_ids, id) {
+                        uid.insert("id".into(), id);
+                    }
+                }
+            }
+            let _ = crate::ext::set(&mut user.ext, "eids", eids);
+        }
+        crate::visit::visit_user_mut(self, user);
+    }
+
+    fn visit_regs_mut(&mut self, regs: &mut crate::Regs<'a>) {
+        mask_ext(self.0.key.as_ref(), self.0.consent, &mut regs.ext, "gpp");
+    }
+}
+
+fn mask(key: Option<&HashKey>, mode: Mode, value: &mut Option<std::borrow::Cow<'_, str>>) {
+    *value = match (mode, value.take()) {
+        (Mode::Keep, value) => value,
+        (_, None) | (Mode::Drop, _) => None,
+        (Mode::Hash, Some(value)) => key.map(|key| hash(key, &value).into()),
+        (Mode::Truncate, Some(value)) => Some(truncate(&value).into()),
+    };
+}
+
+fn mask_ext(key: Option<&HashKey>, mode: Mode, ext: &mut Option<json_ext::Object>, field: &str) {
+    if mode == Mode::Keep {
+        return;
+    }
+    if let Some(value) = crate::ext::remove(ext, field).and_then(|value| mask_value(key, mode, value)) {
+        let _ = crate::ext::set(ext, field, value);
+    }
+}
+
+/// Masks a JSON value; values other than strings are dropped unless kept.
+fn mask_value(key: Option<&HashKey>, mode: Mode, value: serde_json::Value) -> Option<serde_json::Value> {
+    match (mode, value) {
+        (Mode::Keep, value) => Some(value),
+        (Mode::Hash, serde_json::Value::String(s)) => key.map(|key| hash(key, &s).into()),
+        (Mode::Truncate, serde_json::Value::String(s)) => Some(truncate(&s).into()),
+        _ => None,
+    }
+}
+
+/// Returns the first 128 bits of the keyed BLAKE3 digest of `value` in hexadecimal.
+fn hash(key: &HashKey, value: &str) -> String {
+    let digest = blake3::keyed_hash(&key.0, value.as_bytes());
+    digest.as_bytes()[..16]
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn truncate(value: &str) -> String {
+    match value.char_indices().nth(4) {
+        Some((end, _)) => format!("{}…", &value[..end]),
+        None => value.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const JSON: &str = r#"{
+        "id": "1",
+        "imp": [],
+        "device": {
+            "ip": "192.0.2.17",
+            "ipv6": "2001:db8:85a3:8d3:1319:8a2e:370:7348",
+            "ifa": "cd4e6d6a-9c69-4d92-9e6b-6dc1c4a1a0d9",
+            "ua": "Mozilla/5.0",
+            "geo": {"lat": 35.6895, "lon": 139.6917, "country": "JPN"}
+        },
+        "user": {
+            "id": "u1",
+            "buyeruid": "b1",
+            "customdata": "secret",
+            "geo": {"lat": 35.6895, "lon": 139.6917},
+            "ext": {"consent": "CPXxRfAPXxRfAAfKABENB", "eids": [{"source": "id5-sync.com", "uids": [{"id": "ID5*abc"}]}]}
+        },
+        "regs": {"ext": {"gdpr": 1, "gpp": "DBACNYA~CPXxRfAPXxRfAAfKABENB"}}
+    }"#;
+
+    #[test]
+    fn request() -> serde_json::Result<()> {
+        let req: crate::BidRequest = serde_json::from_str(JSON)?;
+        let redacted = req.redacted().to_inner();
+
+        let device = redacted.device.as_ref().unwrap();
+        assert_eq!(device.ip.as_deref(), Some("192.0.2.0"));
+        assert_eq!(device.ipv6.as_deref(), Some("2001:db8:85a3::"));
+        assert_eq!(
+            device.ifa.as_deref(),
+            Some(hash(&HashKey::process().unwrap(), "cd4e6d6a-9c69-4d92-9e6b-6dc1c4a1a0d9").as_str())
+        );
+        assert_eq!(device.ua.as_deref(), Some("Mozilla/5.0"));
+        let geo = device.geo.as_ref().unwrap();
+        assert_eq!(
+            (geo.lat, geo.lon, geo.country.as_deref()),
+            (Some(35.69), Some(139.69), Some("JPN"))
+        );
+
+        let user = redacted.user.as_ref().unwrap();
+        assert_eq!(
+            user.id.as_deref(),
+            Some(hash(&HashKey::process().unwrap(), "u1").as_str())
+        );
+        assert_eq!(user.customdata, None);
+        assert_eq!(user.geo.as_ref().unwrap().lat, Some(35.69));
+        assert_eq!(
+            crate::ext::get_str(user.ext.as_ref(), "consent").as_deref(),
+            Some("CPXx…")
+        );
+        assert_eq!(
+            crate::ext::get(user.ext.as_ref(), "eids"),
+            Some(
+                serde_json::json!([{"source": "id5-sync.com", "uids": [{"id": hash(&HashKey::process().unwrap(), "ID5*abc")}]}])
+            )
+        );
+        let regs = redacted.regs.as_ref().unwrap();
+        assert_eq!(crate::ext::get_str(regs.ext.as_ref(), "gpp").as_deref(), Some("DBAC…"));
+        assert_eq!(crate::ext::get(regs.ext.as_ref(), "gdpr"), Some(1.into()));
+
+        let debug = format!("{:?}", req.redacted());
+        assert!(debug.contains("Device {"));
+        assert!(!debug.contains("192.0.2.17") && !debug.contains("secret"));
+        assert_eq!(serde_json::to_string(&req.redacted())?, req.redacted().to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn policy() -> serde_json::Result<()> {
+        let req: crate::BidRequest = serde_json::from_str(JSON)?;
+        let policy = Policy {
+            ip: Mode::Drop,
+            device_ids: Mode::Keep,
+            user_ids: Mode::Drop,
+            location: Mode::Hash,
+            custom_data: Mode::Truncate,
+            consent: Mode::Drop,
+            ..Default::default()
+        };
+        let user = req.user.as_ref().unwrap().redacted_with(policy).to_inner();
+        assert_eq!((user.id, user.buyeruid), (None, None));
+        assert_eq!(user.customdata.as_deref(), Some("secr…"));
+        assert_eq!(user.geo.as_ref().unwrap().lat, None);
+        assert_eq!(
+            serde_json::to_string(&user.ext)?,
+            r#"{"eids":[{"source":"id5-sync.com","uids":[{}]}]}"#
+        );
+
+        let device = req.device.as_ref().unwrap().redacted_with(policy).to_inner();
+        assert_eq!((device.ip, device.ipv6), (None, None));
+        assert_eq!(device.ifa, req.device.as_ref().unwrap().ifa);
+
+        assert_eq!(serde_json::from_str::<Policy>(r#"{"ip":"keep"}"#)?.ip, Mode::Keep);
+        let key = HashKey::new([7; 32]);
+        assert_eq!(hash(&key, "u1"), hash(&HashKey::new([7; 32]), "u1"));
+        assert_ne!(hash(&key, "u1"), hash(&HashKey::new([8; 32]), "u1"));
+        assert_eq!(hash(&key, "u1").len(), 32);
+        assert_eq!(format!("{:?}", key), "HashKey(..)");
+        assert!(!serde_json::to_string(&Policy::default())?.contains("key"));
+        assert_eq!(serde_json::from_str::<Policy>("{}")?.key, HashKey::process());
+
+        let policy = Policy {
+            key: None,
+            ..Default::default()
+        };
+        let user = req.user.as_ref().unwrap().redacted_with(policy).to_inner();
+        assert_eq!((user.id, user.buyeruid), (None, None));
+        assert_eq!(truncate("abc"), "abc");
+
+        Ok(())
+    }
+}
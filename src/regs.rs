@@ -5,19 +5,75 @@
 /// Commission’s regulations for the United States Children’s Online Privacy Protection Act
 /// (“COPPA”).
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Regs<'a> {
     /// integer
     /// Flag indicating if this request is subject to the COPPA regulations established by the USA
     /// FTC, where 0 = no, 1 = yes. Refer to Section 7.5 for more information.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub coppa: Option<json_ext::Flag>,
 
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
+impl Regs<'_> {
+    /// Returns whether GDPR applies to the request according to the `gdpr` extension, None if the
+    /// extension is absent.
+    ///
+    /// ```
+    /// # use openrtb2::Regs;
+    /// let regs: Regs = serde_json::from_str(r#"{"ext":{"gdpr":1}}"#)?;
+    /// assert_eq!(regs.gdpr(), Some(true));
+    /// assert_eq!(Regs::default().gdpr(), None);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn gdpr(&self) -> Option<bool> {
+        crate::ext::get(self.ext.as_ref(), "gdpr")
+            .as_ref()
+            .and_then(crate::ext::as_flag)
+    }
+
+    /// Returns the US Privacy string of the `us_privacy` extension, None if the extension is
+    /// absent.
+    ///
+    /// ```
+    /// # use openrtb2::Regs;
+    /// let regs: Regs = serde_json::from_str(r#"{"ext":{"us_privacy":"1YYN"}}"#)?;
+    /// assert_eq!(regs.us_privacy().unwrap().unwrap().opt_out_sale, Some(true));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn us_privacy(&self) -> Option<Result<crate::us_privacy::UsPrivacy, crate::us_privacy::Error>> {
+        crate::ext::get_str(self.ext.as_ref(), "us_privacy").map(|usp| usp.parse())
+    }
+
+    /// Returns the GPP string of the `gpp` extension, None if the extension is absent.
+    #[cfg(feature = "gpp")]
+    pub fn gpp(&self) -> Option<Result<crate::gpp::GppString, crate::gpp::Error>> {
+        crate::ext::get_str(self.ext.as_ref(), "gpp").map(|gpp| gpp.parse())
+    }
+
+    /// Returns the GPP section ids applicable to the request according to the `gpp_sid`
+    /// extension.
+    ///
+    /// ```
+    /// # use openrtb2::Regs;
+    /// let regs: Regs = serde_json::from_str(r#"{"ext":{"gpp_sid":[7,8]}}"#)?;
+    /// assert_eq!(regs.gpp_sid(), Some(vec![7, 8]));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn gpp_sid(&self) -> Option<Vec<u16>> {
+        crate::ext::get(self.ext.as_ref(), "gpp_sid").and_then(|sid| serde_json::from_value(sid).ok())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,82 @@
+//! JSON Schemas of the OpenRTB objects.
+//!
+//! With the `schemars` feature every object and enum implements [`schemars::JsonSchema`], the
+//! descriptions being taken from the documentation of the types and fields. [`bid_request`] and
+//! [`bid_response`] return the root schemas to publish, with the nested objects as definitions:
+//!
+//! ```
+//! let schema = serde_json::to_value(openrtb2::schema::bid_request())?;
+//! assert_eq!(schema["title"], "BidRequest");
+//! assert_eq!(schema["required"], serde_json::json!(["id", "imp"]));
+//! assert_eq!(schema["$defs"]["Imp"]["properties"]["bidfloor"]["format"], "double");
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`schemars::JsonSchema`]: https://docs.rs/schemars/1/schemars/trait.JsonSchema.html
+//! [`bid_request`]: ./fn.bid_request.html
+//! [`bid_response`]: ./fn.bid_response.html
+
+/// Returns the schema of [`BidRequest`].
+///
+/// [`BidRequest`]: ../struct.BidRequest.html
+pub fn bid_request() -> schemars::Schema {
+    schemars::schema_for!(crate::BidRequest<'static>)
+}
+
+/// Returns the schema of [`BidResponse`].
+///
+/// [`BidResponse`]: ../struct.BidResponse.html
+pub fn bid_response() -> schemars::Schema {
+    schemars::schema_for!(crate::BidResponse<'static>)
+}
+
+/// The schema of `json_ext::Flag` fields.
+pub(crate) struct Flag;
+
+impl schemars::JsonSchema for Flag {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Flag".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Flag, where 0 = no, 1 = yes.",
+            "type": "integer",
+            "enum": [0, 1]
+        })
+    }
+}
+
+/// The schema of `json_ext::Object` fields.
+pub(crate) type Object = serde_json::Map<String, serde_json::Value>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn schema() -> serde_json::Result<()> {
+        let req = serde_json::to_value(bid_request())?;
+        let defs = &req["$defs"];
+        assert_eq!(req["properties"]["at"]["anyOf"][0]["$ref"], "#/$defs/AuctionType");
+        assert_eq!(defs["AuctionType"]["type"], "integer");
+        assert_eq!(defs["StartDelay"]["minimum"], -2);
+        assert_eq!(defs["MaxExtendedAdDuration"]["minimum"], -1);
+        assert_eq!(defs["Protocol"]["enum"][0], 1);
+        assert_eq!(defs["Gender"]["enum"], serde_json::json!(["M", "F", "O"]));
+        assert_eq!(defs["Flag"]["enum"], serde_json::json!([0, 1]));
+        assert_eq!(req["properties"]["test"]["anyOf"][0]["$ref"], "#/$defs/Flag");
+        assert!(req.to_string().contains(r#""site":{"#));
+        assert!(req.to_string().contains(r#""app":{"#));
+        assert!(defs["Imp"]["properties"]["id"]["description"]
+            .as_str()
+            .unwrap()
+            .starts_with("string; required"));
+
+        let res = serde_json::to_value(bid_response())?;
+        assert_eq!(res["required"], serde_json::json!(["id"]));
+        assert!(res["$defs"]["Bid"]["required"].is_array());
+
+        Ok(())
+    }
+}
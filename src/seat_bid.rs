@@ -6,11 +6,14 @@
 /// impressions that it can win (default) or if it is only interested in winning any if it can win
 /// them all as a group.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct SeatBid<'a> {
     /// object array; required
     /// Array of 1+ Bid objects (Section 4.2.3) each related to an impression. Multiple bids can
     /// relate to the same impression.
     #[serde(borrow)]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::non_empty))]
     pub bid: Vec<crate::Bid<'a>>,
 
     /// string
@@ -21,11 +24,15 @@ pub struct SeatBid<'a> {
     /// integer; default 0
     /// 0 = impressions can be won individually; 1 = impressions must be won or lost as a group.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub group: Option<json_ext::Flag>,
 
     /// object
     /// Placeholder for bidder-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
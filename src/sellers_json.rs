@@ -0,0 +1,169 @@
+//! sellers.json documents.
+//!
+//! Advertising systems publish the sellers they represent in a `sellers.json` file at the root of
+//! their domain. Together with ads.txt it lets buyers check every hop of a supply chain.
+
+/// A sellers.json document.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+pub struct SellersJson<'a> {
+    /// string; optional
+    /// An email address to use to contact the advertising system for questions or inquiries about
+    /// this file.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub contact_email: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; optional
+    /// The physical address of the advertising system.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub contact_address: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; required
+    /// The version of this specification used, "1.0".
+    #[serde(borrow)]
+    pub version: std::borrow::Cow<'a, str>,
+
+    /// object array; optional
+    /// Array of business identifiers of the advertising system, e.g. a DUNS number.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub identifiers: Option<Vec<Identifier<'a>>>,
+
+    /// object array; required
+    /// The list of all sellers and intermediaries the advertising system pays for inventory.
+    #[serde(borrow)]
+    pub sellers: Vec<Seller<'a>>,
+
+    /// object
+    /// Placeholder for advertising-system specific extensions to this object.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// A business identifier of an advertising system.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+pub struct Identifier<'a> {
+    /// string; required
+    /// The description of the identifier, e.g. "TAG-ID" or "DUNS".
+    #[serde(borrow)]
+    pub name: std::borrow::Cow<'a, str>,
+
+    /// string; required
+    /// The value of the identifier.
+    #[serde(borrow)]
+    pub value: std::borrow::Cow<'a, str>,
+}
+
+/// A seller or intermediary paid by the advertising system.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+pub struct Seller<'a> {
+    /// string; required
+    /// The identifier associated with the seller or intermediary account within the advertising
+    /// system, the `sid` of supply chain nodes and account ID of ads.txt records.
+    #[serde(borrow)]
+    pub seller_id: std::borrow::Cow<'a, str>,
+
+    /// integer; optional
+    /// Indicates whether the identity of the seller is confidential, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_confidential: Option<json_ext::Flag>,
+
+    /// string; required
+    /// Whether the account is a publisher, an intermediary or both.
+    pub seller_type: SellerType,
+
+    /// integer; optional
+    /// Indicates whether the advertising system passes the inventory through without being paid
+    /// by the seller, where 0 = no, 1 = yes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_passthrough: Option<json_ext::Flag>,
+
+    /// string; required unless confidential
+    /// The name of the company paid for inventory transacted under the seller_id.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; required unless confidential
+    /// The business domain name of the entity represented by the seller_id.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; optional
+    /// Description of the seller.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<std::borrow::Cow<'a, str>>,
+
+    /// object
+    /// Placeholder for advertising-system specific extensions to this object.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// The type of a seller account.
+///
+/// Serialized in upper case as the specification requires, deserialized case-insensitively as
+/// published files also use `"publisher"` or `"Intermediary"`.
+#[derive(serde::Serialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SellerType {
+    /// The inventory is owned by the seller.
+    Publisher,
+    /// The seller resells inventory owned by others.
+    Intermediary,
+    /// The account sells both owned and resold inventory.
+    Both,
+}
+
+impl<'de> serde::Deserialize<'de> for SellerType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value: std::borrow::Cow<str> = serde::Deserialize::deserialize(deserializer)?;
+        match value.trim().to_ascii_uppercase().as_str() {
+            "PUBLISHER" => Ok(Self::Publisher),
+            "INTERMEDIARY" => Ok(Self::Intermediary),
+            "BOTH" => Ok(Self::Both),
+            _ => Err(serde::de::Error::unknown_variant(
+                &value,
+                &["PUBLISHER", "INTERMEDIARY", "BOTH"],
+            )),
+        }
+    }
+}
+
+impl SellersJson<'static> {
+    /// Reads and parses a sellers.json file.
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> serde_json::Result<Self> {
+        let file = std::fs::File::open(path).map_err(serde_json::Error::io)?;
+        let mut de = serde_json::Deserializer::from_reader(std::io::BufReader::new(file));
+        let sellers_json = serde::Deserialize::deserialize(&mut de)?;
+        de.end()?;
+        Ok(sellers_json)
+    }
+}
+
+impl<'a> SellersJson<'a> {
+    /// Returns the seller with ID `seller_id`.
+    pub fn seller(&self, seller_id: &str) -> Option<&Seller<'a>> {
+        self.sellers.iter().find(|seller| seller.seller_id == seller_id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        let json = r#"{"contact_email":"adops@advertisingsystem.com","version":"1.0","identifiers":[{"name":"TAG-ID","value":"28cb65e5bbc0bd5f"}],"sellers":[{"seller_id":"1942009976","seller_type":"PUBLISHER","name":"Publisher1","domain":"publisher1.com"},{"seller_id":"1527469203","is_confidential":1,"seller_type":"BOTH"}]}"#;
+        let o1 = serde_json::from_str::<SellersJson>(json)?;
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1.seller("1942009976").unwrap().seller_type, SellerType::Publisher);
+        assert!(o1.seller("0").is_none());
+
+        let json = r#"{"version":"1.0","sellers":[{"seller_id":"1","seller_type":"publisher"},{"seller_id":"2","seller_type":"Intermediary"}]}"#;
+        let o1 = serde_json::from_str::<SellersJson>(json)?;
+        assert_eq!(o1.sellers[0].seller_type, SellerType::Publisher);
+        assert_eq!(o1.sellers[1].seller_type, SellerType::Intermediary);
+        assert_eq!(serde_json::to_string(&o1.sellers[1].seller_type)?, r#""INTERMEDIARY""#);
+        assert!(serde_json::from_str::<SellerType>(r#""RESELLER""#).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+//! Per-bidder request shaping.
+//!
+//! Before forwarding a bid request, an exchange removes what a bidder is not allowed to see or
+//! cannot handle. A [`Policy`] describes these restrictions declaratively and a [`RequestShaper`]
+//! applies it.
+//!
+//! [`Policy`]: ./struct.Policy.html
+//! [`RequestShaper`]: ./struct.RequestShaper.html
+
+/// Media type an impression can be offered as.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaType {
+    Banner,
+    Video,
+    Audio,
+    Native,
+}
+
+/// Restrictions applied to a bid request before it is sent to a bidder.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(default)]
+pub struct Policy {
+    /// Media types supported by the bidder; None means all. Unsupported media objects are removed
+    /// from every `Imp` and impressions left without any media object are dropped.
+    pub media_types: Option<Vec<MediaType>>,
+
+    /// Maximum number of impressions; None means no limit.
+    pub max_imps: Option<usize>,
+
+    /// Maximum length of `bcat`; None means no limit.
+    pub max_bcat: Option<usize>,
+
+    /// Maximum length of `badv`; None means no limit.
+    pub max_badv: Option<usize>,
+
+    /// Whether `user.data` may be sent, typically false for vendors without consent.
+    pub user_data: bool,
+
+    /// Whether `device.ifa` is removed when `device.lmt` is set.
+    pub strip_ifa_on_lmt: bool,
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            media_types: None,
+            max_imps: None,
+            max_bcat: None,
+            max_badv: None,
+            user_data: true,
+            strip_ifa_on_lmt: true,
+        }
+    }
+}
+
+/// Applies a [`Policy`] to bid requests.
+///
+/// Shaping clones the request, so strings the policy does not touch keep borrowing from the
+/// original input.
+///
+/// ```
+/// # use openrtb2::shaper::{MediaType, Policy, RequestShaper};
+/// let shaper = RequestShaper::new(Policy {
+///     media_types: Some(vec![MediaType::Banner]),
+///     ..Default::default()
+/// });
+/// let req: openrtb2::BidRequest = serde_json::from_str(
+///     r#"{"id":"1","imp":[{"id":"1","banner":{}},{"id":"2","video":{"mimes":[]}}]}"#,
+/// )?;
+/// let shaped = shaper.shape(&req);
+/// assert_eq!(shaped.imp.len(), 1);
+/// assert_eq!(shaped.imp[0].id, "1");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// [`Policy`]: ./struct.Policy.html
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RequestShaper {
+    policy: Policy,
+}
+
+impl RequestShaper {
+    /// Creates a shaper applying `policy`.
+    pub fn new(policy: Policy) -> Self {
+        Self { policy }
+    }
+
+    /// Returns the policy applied by this shaper.
+    pub fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    /// Returns a copy of `req` shaped according to the policy.
+    pub fn shape<'a>(&self, req: &crate::BidRequest<'a>) -> crate::BidRequest<'a> {
+        let mut req = req.clone();
+        self.shape_in_place(&mut req);
+        req
+    }
+
+    /// Shapes `req` in place according to the policy.
+    pub fn shape_in_place(&self, req: &mut crate::BidRequest) {
+        let policy = &self.policy;
+
+        if let Some(ref media_types) = policy.media_types {
+            let supports = |media_type| media_types.contains(&media_type);
+            for imp in req.imp.iter_mut() {
+                if !supports(MediaType::Banner) {
+                    imp.banner = None;
+                }
+                if !supports(MediaType::Video) {
+                    imp.video = None;
+                }
+                if !supports(MediaType::Audio) {
+                    imp.audio = None;
+                }
+                if !supports(MediaType::Native) {
+                    imp.native = None;
+                }
+            }
+            req.imp.retain(|imp| {
+                imp.banner.is_some() || imp.video.is_some() || imp.audio.is_some() || imp.native.is_some()
+            });
+        }
+        if let Some(max) = policy.max_imps {
+            req.imp.truncate(max);
+        }
+
+        if let (Some(max), Some(bcat)) = (policy.max_bcat, req.bcat.as_mut()) {
+            bcat.truncate(max);
+        }
+        if let (Some(max), Some(badv)) = (policy.max_badv, req.badv.as_mut()) {
+            badv.truncate(max);
+        }
+
+        if !policy.user_data {
+            if let Some(ref mut user) = req.user {
+                user.data = None;
+            }
+        }
+
+        if policy.strip_ifa_on_lmt {
+            if let Some(ref mut device) = req.device {
+                if crate::ext::is_set(device.lmt) {
+                    device.ifa = None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shape() -> serde_json::Result<()> {
+        let json = r#"{
+            "id": "1",
+            "imp": [
+                {"id": "1", "banner": {}, "native": {"request": ""}},
+                {"id": "2", "native": {"request": ""}},
+                {"id": "3", "banner": {}},
+                {"id": "4", "banner": {}}
+            ],
+            "device": {"lmt": 1, "ifa": "ifa"},
+            "user": {"id": "u", "data": [{"id": "d"}]},
+            "bcat": ["IAB1", "IAB2", "IAB3"],
+            "badv": ["a.com", "b.com"]
+        }"#;
+        let req: crate::BidRequest = serde_json::from_str(json)?;
+
+        assert_eq!(RequestShaper::default().shape(&req).device.unwrap().ifa, None);
+
+        let shaper = RequestShaper::new(Policy {
+            media_types: Some(vec![MediaType::Banner]),
+            max_imps: Some(2),
+            max_bcat: Some(2),
+            max_badv: Some(5),
+            user_data: false,
+            strip_ifa_on_lmt: false,
+        });
+        let shaped = shaper.shape(&req);
+
+        assert_eq!(shaped.imp.iter().map(|imp| &*imp.id).collect::<Vec<_>>(), ["1", "3"]);
+        assert!(shaped.imp[0].native.is_none());
+        assert_eq!(shaped.bcat.as_ref().unwrap().len(), 2);
+        assert_eq!(shaped.badv, req.badv);
+        assert_eq!(shaped.user.as_ref().unwrap().data, None);
+        assert_eq!(shaped.user.as_ref().unwrap().id, req.user.as_ref().unwrap().id);
+        assert_eq!(shaped.device.as_ref().unwrap().ifa.as_deref(), Some("ifa"));
+        assert!(matches!(shaped.id, std::borrow::Cow::Borrowed(_)));
+
+        // the original request is left untouched
+        assert_eq!(req.imp.len(), 4);
+
+        Ok(())
+    }
+}
@@ -4,6 +4,8 @@
 /// non-browser application. A bid request must not contain both a Site and an App object. At a
 /// minimum, it is useful to provide a site ID or page URL, but this is not strictly required.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Site<'a> {
     /// string; recommended
     /// Exchange-specific site ID.
@@ -37,6 +39,12 @@ pub struct Site<'a> {
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
     pub pagecat: Option<Vec<std::borrow::Cow<'a, str>>>,
 
+    /// integer; default 1
+    /// The taxonomy in use. If no cattax field is supplied IAB Content Category Taxonomy 1.0 is
+    /// assumed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cattax: Option<crate::CategoryTaxonomy>,
+
     /// string
     /// URL of the page where the impression will be shown.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
@@ -56,11 +64,15 @@ pub struct Site<'a> {
     /// Indicates if the site has been programmed to optimize layout when viewed on mobile devices,
     /// where 0 = no, 1 = yes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub mobile: Option<json_ext::Flag>,
 
     /// integer
     /// Indicates if the site has a privacy policy, where 0 = no, 1 = yes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub privacypolicy: Option<json_ext::Flag>,
 
     /// object
@@ -81,6 +93,8 @@ pub struct Site<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
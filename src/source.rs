@@ -7,6 +7,8 @@
 /// another RTB exchange, a mediation platform, or an ad server combines direct campaigns with 3rd
 /// party demand in decisioning.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Source<'a> {
     /// integer; recommended
     /// Entity responsible for the final impression sale decision, where 0 = exchange, 1 = upstream
@@ -29,10 +31,35 @@ pub struct Source<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
+impl Source<'_> {
+    /// Returns the SupplyChain object of the `schain` extension, None if the extension is absent.
+    ///
+    /// ```
+    /// # use openrtb2::Source;
+    /// let source: Source = serde_json::from_str(
+    ///     r#"{"ext":{"schain":{"complete":1,"nodes":[{"asi":"a.com","sid":"1","hp":1}],"ver":"1.0"}}}"#,
+    /// )?;
+    /// assert_eq!(source.supply_chain().unwrap()?.nodes[0].asi, "a.com");
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn supply_chain(&self) -> Option<serde_json::Result<crate::supply_chain::SupplyChain<'static>>> {
+        crate::ext::get(self.ext.as_ref(), "schain").map(serde::Deserialize::deserialize)
+    }
+
+    /// Sets the `schain` extension to `schain`, keeping the other extensions.
+    pub fn set_supply_chain(&mut self, schain: &crate::supply_chain::SupplyChain) -> serde_json::Result<()> {
+        crate::ext::set(&mut self.ext, "schain", serde_json::to_value(schain)?)
+    }
+}
+
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum SaleDecision {
     Exchange,
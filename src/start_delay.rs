@@ -47,6 +47,33 @@ impl<'de> serde::Deserialize<'de> for StartDelay {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for StartDelay {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(match u.int_in_range(0..=3u8)? {
+            0 => Self::PreRoll,
+            1 => Self::GenericMidRoll,
+            2 => Self::GenericPostRoll,
+            _ => Self::MidRoll(u.int_in_range(1..=i32::MAX)?),
+        })
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for StartDelay {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "StartDelay".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Start delay in seconds for mid-roll placements, or 0 = Pre-Roll, -1 = Generic Mid-Roll, -2 = Generic Post-Roll.",
+            "type": "integer",
+            "minimum": -2
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,318 @@
+//! SupplyChain object.
+//!
+//! The IAB Tech Lab SupplyChain object lists every entity involved in the sale of an impression,
+//! from the owner of the inventory to the seller of the bid request. OpenRTB 2.5 carries it in
+//! `source.ext.schain`, see [`Source::supply_chain`].
+//!
+//! Intermediaries reselling a request add their own node with [`append_node`] before forwarding
+//! it downstream.
+//!
+//! [`Source::supply_chain`]: ../struct.Source.html#method.supply_chain
+//! [`append_node`]: ./fn.append_node.html
+
+/// The supported version of the SupplyChain object.
+pub const VERSION: &str = "1.0";
+
+/// The chain of the entities selling an impression.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+pub struct SupplyChain<'a> {
+    /// integer; required
+    /// Flag indicating whether the chain contains all nodes involved in the transaction leading
+    /// back to the owner of the site, app or other medium of the inventory, where 0 = no, 1 = yes.
+    pub complete: json_ext::Flag,
+
+    /// object array; required
+    /// Array of SupplyChainNode objects in the order of the chain. In a complete supply chain, the
+    /// first node represents the initial advertising system and seller ID involved in the
+    /// transaction, i.e. the owner of the site, app, or other medium. In an incomplete supply
+    /// chain, it represents the first known node. The last node represents the entity sending this
+    /// bid request.
+    #[serde(borrow)]
+    pub nodes: Vec<SupplyChainNode<'a>>,
+
+    /// string; required
+    /// Version of the supply chain specification in use, in the format of "major.minor".
+    #[serde(borrow)]
+    pub ver: std::borrow::Cow<'a, str>,
+
+    /// object
+    /// Placeholder for advertising-system specific extensions to this object.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+/// One entity of a [`SupplyChain`].
+///
+/// [`SupplyChain`]: ./struct.SupplyChain.html
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
+pub struct SupplyChainNode<'a> {
+    /// string; required
+    /// The canonical domain name of the SSP, Exchange, Header Wrapper, etc system that bidders
+    /// connect to. This may be the operational domain of the system, if that is different than
+    /// the parent corporate domain, to facilitate WHOIS and reverse IP lookups to establish clear
+    /// ownership of the delegate system. This should be the same value as used to identify sellers
+    /// in an ads.txt file if one exists.
+    #[serde(borrow)]
+    pub asi: std::borrow::Cow<'a, str>,
+
+    /// string; required
+    /// The identifier associated with the seller or reseller account within the advertising
+    /// system. This must contain the same value used in transactions (i.e. OpenRTB bid requests)
+    /// in the field specified by the SSP/exchange. Typically, in OpenRTB, this is publisher.id.
+    #[serde(borrow)]
+    pub sid: std::borrow::Cow<'a, str>,
+
+    /// string; optional
+    /// The OpenRTB RequestId of the request as issued by this seller.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub rid: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; optional
+    /// The name of the company (the legal entity) that is paid for inventory transacted under the
+    /// given seller_id.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<std::borrow::Cow<'a, str>>,
+
+    /// string; optional
+    /// The business domain name of the entity represented by this node.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<std::borrow::Cow<'a, str>>,
+
+    /// integer; required
+    /// Indicates whether this node will be involved in the flow of payment for the inventory. When
+    /// set to 1, the advertising system in the asi field pays the seller in the sid field, who is
+    /// responsible for paying the previous node in the chain. When set to 0, this node is not
+    /// involved in the flow of payment for the inventory. For version 1.0 of SupplyChain, this
+    /// property should always be 1.
+    pub hp: json_ext::Flag,
+
+    /// object
+    /// Placeholder for advertising-system specific extensions to this object.
+    #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    pub ext: Option<json_ext::Object<'a>>,
+}
+
+impl<'a> SupplyChain<'a> {
+    /// Creates an empty chain of the supported version.
+    pub fn new(complete: bool) -> Self {
+        Self {
+            complete: crate::ext::flag(complete),
+            nodes: Vec::new(),
+            ver: VERSION.into(),
+            ext: None,
+        }
+    }
+
+    /// Returns true if the chain goes back to the owner of the inventory.
+    pub fn is_complete(&self) -> bool {
+        crate::ext::is_set(Some(self.complete))
+    }
+
+    /// Checks the chain against the SupplyChain 1.0 specification: the version is supported, there
+    /// is at least one node, and every node has an `asi` and a `sid` and is involved in the payment.
+    ///
+    /// A seller may appear several times in a chain, e.g. when an exchange buys back inventory it
+    /// sold, so repeated nodes are valid.
+    ///
+    /// ```
+    /// # use openrtb2::supply_chain::{Error, SupplyChain, SupplyChainNode};
+    /// let mut schain = SupplyChain::new(true);
+    /// assert!(matches!(schain.validate(), Err(Error::Empty)));
+    /// schain.nodes.push(SupplyChainNode::new("exchange1.com", "1234"));
+    /// assert!(schain.validate().is_ok());
+    /// schain.nodes.push(SupplyChainNode::new("exchange2.com", ""));
+    /// assert!(matches!(schain.validate(), Err(Error::Sid(1))));
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.ver != VERSION {
+            return Err(Error::Version(self.ver.to_string()));
+        }
+        if self.nodes.is_empty() {
+            return Err(Error::Empty);
+        }
+        for (i, node) in self.nodes.iter().enumerate() {
+            if node.asi.is_empty() || node.asi.contains(char::is_whitespace) {
+                return Err(Error::Asi(i));
+            }
+            if node.sid.is_empty() {
+                return Err(Error::Sid(i));
+            }
+            if !crate::ext::is_set(Some(node.hp)) {
+                return Err(Error::Payment(i));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> SupplyChainNode<'a> {
+    /// Creates a node involved in the payment for seller `sid` of advertising system `asi`.
+    pub fn new(asi: impl Into<std::borrow::Cow<'a, str>>, sid: impl Into<std::borrow::Cow<'a, str>>) -> Self {
+        Self {
+            asi: asi.into(),
+            sid: sid.into(),
+            rid: None,
+            name: None,
+            domain: None,
+            hp: crate::ext::flag(true),
+            ext: None,
+        }
+    }
+}
+
+/// Appends `node` to the supply chain of `req`, creating the chain if the request has none.
+///
+/// `complete` is the `complete` flag of a new chain, and is ignored if the request already carries
+/// one. Only the caller knows whether it is the first seller of the inventory, which makes the new
+/// chain complete, or whether upstream sellers are missing from it.
+///
+/// ```
+/// # use openrtb2::supply_chain::{append_node, SupplyChainNode};
+/// let mut req: openrtb2::BidRequest = serde_json::from_str(r#"{"id":"1","imp":[]}"#)?;
+/// append_node(&mut req, SupplyChainNode::new("exchange1.com", "1234"), true)?;
+/// append_node(&mut req, SupplyChainNode::new("exchange2.com", "abcd"), false)?;
+///
+/// let schain = req.source.unwrap().supply_chain().unwrap()?;
+/// assert!(schain.is_complete());
+/// assert_eq!(schain.nodes[1].asi, "exchange2.com");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn append_node(req: &mut crate::BidRequest, node: SupplyChainNode, complete: bool) -> Result<(), Error> {
+    let source = req.source.get_or_insert_with(Default::default);
+    let mut schain = match source.supply_chain() {
+        Some(schain) => schain?,
+        None => SupplyChain::new(complete),
+    };
+    schain.nodes.push(node);
+    source.set_supply_chain(&schain)?;
+    Ok(())
+}
+
+/// The error type for supply chains.
+#[derive(Debug)]
+pub enum Error {
+    /// The supply chain is not valid JSON.
+    Json(serde_json::Error),
+    /// The version is not supported.
+    Version(String),
+    /// The chain has no node.
+    Empty,
+    /// The node at this index has an empty or malformed `asi`.
+    Asi(usize),
+    /// The node at this index has an empty `sid`.
+    Sid(usize),
+    /// The node at this index is not involved in the payment.
+    Payment(usize),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json(e) => write!(f, "invalid supply chain: {}", e),
+            Self::Version(ver) => write!(f, "unsupported supply chain version: {}", ver),
+            Self::Empty => write!(f, "supply chain has no node"),
+            Self::Asi(i) => write!(f, "supply chain node {} has an invalid asi", i),
+            Self::Sid(i) => write!(f, "supply chain node {} has no sid", i),
+            Self::Payment(i) => write!(f, "supply chain node {} is not involved in the payment", i),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        let json =
+            r#"{"complete":1,"nodes":[{"asi":"exchange1.com","sid":"1234","rid":"bid-request-1","hp":1}],"ver":"1.0"}"#;
+        let mut o1 = SupplyChain::new(true);
+        let mut node = SupplyChainNode::new("exchange1.com", "1234");
+        node.rid = Some("bid-request-1".into());
+        o1.nodes.push(node);
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(o1, serde_json::from_str::<SupplyChain>(json)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate() -> serde_json::Result<()> {
+        let schain: SupplyChain =
+            serde_json::from_str(r#"{"complete":0,"nodes":[{"asi":"a.com","sid":"1","hp":1}],"ver":"2.0"}"#)?;
+        assert!(matches!(schain.validate(), Err(Error::Version(ref ver)) if ver == "2.0"));
+
+        let schain: SupplyChain = serde_json::from_str(
+            r#"{"complete":0,"nodes":[{"asi":"a.com","sid":"1","hp":1},{"asi":"b.com","sid":"","hp":1}],"ver":"1.0"}"#,
+        )?;
+        assert!(matches!(schain.validate(), Err(Error::Sid(1))));
+
+        let schain: SupplyChain =
+            serde_json::from_str(r#"{"complete":0,"nodes":[{"asi":"a com","sid":"1","hp":1}],"ver":"1.0"}"#)?;
+        assert!(matches!(schain.validate(), Err(Error::Asi(0))));
+
+        let schain: SupplyChain =
+            serde_json::from_str(r#"{"complete":0,"nodes":[{"asi":"a.com","sid":"1","hp":0}],"ver":"1.0"}"#)?;
+        assert!(matches!(schain.validate(), Err(Error::Payment(0))));
+
+        let schain: SupplyChain = serde_json::from_str(
+            r#"{"complete":1,"nodes":[{"asi":"a.com","sid":"1","hp":1},{"asi":"b.com","sid":"2","hp":1},{"asi":"a.com","sid":"1","hp":1}],"ver":"1.0"}"#,
+        )?;
+        assert!(schain.validate().is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn append() -> Result<(), Error> {
+        let mut req: crate::BidRequest = serde_json::from_str(r#"{"id":"1","imp":[]}"#)?;
+        append_node(&mut req, SupplyChainNode::new("exchange1.com", "1234"), false)?;
+        let schain = req.source.as_ref().unwrap().supply_chain().unwrap()?;
+        assert!(!schain.is_complete());
+        assert_eq!(schain.nodes.len(), 1);
+        schain.validate()?;
+
+        let mut req: crate::BidRequest = serde_json::from_str(
+            r#"{"id":"1","imp":[],"source":{"ext":{"schain":{"complete":1,"nodes":[{"asi":"a.com","sid":"1","hp":1}],"ver":"1.0"},"other":true}}}"#,
+        )?;
+        append_node(&mut req, SupplyChainNode::new("exchange1.com", "1234"), false)?;
+        assert_eq!(
+            serde_json::to_value(&req.source)?,
+            serde_json::json!({"ext": {
+                "schain": {
+                    "complete": 1,
+                    "nodes": [
+                        {"asi": "a.com", "sid": "1", "hp": 1},
+                        {"asi": "exchange1.com", "sid": "1234", "hp": 1}
+                    ],
+                    "ver": "1.0"
+                },
+                "other": true
+            }})
+        );
+
+        let mut req: crate::BidRequest =
+            serde_json::from_str(r#"{"id":"1","imp":[],"source":{"ext":{"schain":{"complete":1}}}}"#)?;
+        assert!(matches!(
+            append_node(&mut req, SupplyChainNode::new("exchange1.com", "1234"), true),
+            Err(Error::Json(_))
+        ));
+
+        Ok(())
+    }
+}
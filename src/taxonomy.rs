@@ -0,0 +1,720 @@
+//! IAB content categories.
+//!
+//! The `cat`, `sectioncat`, `pagecat` and `bcat` fields of requests and the `cat` of bids hold
+//! codes of the IAB Content Taxonomy 1.0 (List 5.1): tier-1 categories such as `IAB25` and their
+//! tier-2 subcategories such as `IAB25-3`. [`IabCategory`] is validated against the embedded
+//! taxonomy, [`validate`] reports the unknown codes of a request, and a [`BlockList`] checks the
+//! categories of bids against `bcat`, a blocked tier-1 category blocking its subcategories too.
+//!
+//! Objects may use other taxonomies, e.g. the numeric IDs of Content Taxonomy 2.x and 3.0,
+//! signalled by their `cattax`. A [`Translator`] rewrites their codes in the taxonomy a bidder
+//! understands. Only the Content Taxonomy tables are embedded: the Ad Product Taxonomy 1.0 and 2.0
+//! are not mapped out of the box, and their codes are reported as unmapped unless tables are
+//! inserted in the translator. Neither are the 1.0 categories without a Content Taxonomy 2.x
+//! equivalent: `IAB14` (Society), whose subcategories are spread over several 2.x categories, and
+//! `IAB24` (Uncategorized), `IAB25` (Non-Standard Content) and `IAB26` (Illegal Content), which
+//! 2.x leaves to brand safety signals. A list holding one of them, typically `bcat`, is not
+//! translated out of 1.0.
+//!
+//! [`IabCategory`]: ./struct.IabCategory.html
+//! [`validate`]: ./fn.validate.html
+//! [`BlockList`]: ./struct.BlockList.html
+//! [`Translator`]: ./struct.Translator.html
+
+mod content_1_0;
+mod mapping;
+
+use content_1_0::CATEGORIES;
+
+/// A category of the IAB Content Taxonomy 1.0.
+///
+/// ```
+/// # use openrtb2::taxonomy::IabCategory;
+/// let category: IabCategory = "iab25-3".parse()?;
+/// assert_eq!(category.code(), "IAB25-3");
+/// assert_eq!(category.name(), "Pornography");
+/// assert_eq!(category.parent().unwrap().name(), "Non-Standard Content");
+/// assert!(IabCategory::parse("IAB25-42").is_err());
+/// # Ok::<(), openrtb2::taxonomy::Error>(())
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct IabCategory(u16);
+
+impl IabCategory {
+    /// Parses a category code, ignoring case and surrounding whitespace.
+    pub fn parse(code: &str) -> Result<Self, Error> {
+        let trimmed = code.trim();
+        CATEGORIES
+            .iter()
+            .position(|(c, _)| c.eq_ignore_ascii_case(trimmed))
+            .map(|i| Self(i as u16))
+            .ok_or_else(|| Error(code.to_owned()))
+    }
+
+    /// Returns every category in taxonomy order, each tier-1 category followed by its
+    /// subcategories.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..CATEGORIES.len()).map(|i| Self(i as u16))
+    }
+
+    /// Returns the code, e.g. `IAB1-6`.
+    pub fn code(&self) -> &'static str {
+        CATEGORIES[self.0 as usize].0
+    }
+
+    /// Returns the name, e.g. `Music`.
+    pub fn name(&self) -> &'static str {
+        CATEGORIES[self.0 as usize].1
+    }
+
+    /// Returns true for the tier-1 categories.
+    pub fn is_tier1(&self) -> bool {
+        !self.code().contains('-')
+    }
+
+    /// Returns the tier-1 category of a subcategory, None for a tier-1 category.
+    pub fn parent(&self) -> Option<Self> {
+        let (parent, _) = self.code().split_once('-')?;
+        Self::parse(parent).ok()
+    }
+
+    /// Returns the tier-1 category of a subcategory, itself for a tier-1 category.
+    pub fn tier1(&self) -> Self {
+        self.parent().unwrap_or(*self)
+    }
+
+    /// Returns the subcategories of a tier-1 category.
+    pub fn children(&self) -> impl Iterator<Item = Self> {
+        let tier1 = self.is_tier1();
+        Self::all()
+            .skip(self.0 as usize + 1)
+            .take_while(move |category| tier1 && !category.is_tier1())
+    }
+
+    /// Returns true if `other` is this category or one of its subcategories.
+    ///
+    /// ```
+    /// # use openrtb2::taxonomy::IabCategory;
+    /// let iab25 = IabCategory::parse("IAB25")?;
+    /// assert!(iab25.contains(IabCategory::parse("IAB25-3")?));
+    /// assert!(!iab25.contains(IabCategory::parse("IAB2-5")?));
+    /// # Ok::<(), openrtb2::taxonomy::Error>(())
+    /// ```
+    pub fn contains(&self, other: Self) -> bool {
+        *self == other || other.parent() == Some(*self)
+    }
+}
+
+impl std::fmt::Display for IabCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl std::fmt::Debug for IabCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("IabCategory").field(&self.code()).finish()
+    }
+}
+
+impl std::str::FromStr for IabCategory {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl serde::Serialize for IabCategory {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for IabCategory {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code: std::borrow::Cow<str> = serde::Deserialize::deserialize(deserializer)?;
+        Self::parse(&code).map_err(serde::de::Error::custom)
+    }
+}
+
+/// The blocked categories of a request.
+///
+/// A blocked tier-1 category blocks its subcategories. Codes outside the taxonomy, e.g. of
+/// exchange specific lists, only block identical codes.
+///
+/// ```
+/// # use openrtb2::taxonomy::BlockList;
+/// let req: openrtb2::BidRequest = serde_json::from_str(r#"{"id":"1","imp":[],"bcat":["IAB25","IAB26-3","X1"]}"#)?;
+/// let blocklist = BlockList::from_request(&req);
+/// assert!(blocklist.blocks("IAB25-3"));
+/// assert!(blocklist.blocks("x1"));
+/// assert!(!blocklist.blocks("IAB26"));
+/// assert!(!blocklist.blocks("IAB26-2"));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct BlockList {
+    categories: Vec<IabCategory>,
+    other: Vec<String>,
+}
+
+impl BlockList {
+    /// Creates a block list from category codes.
+    pub fn new<S: AsRef<str>>(bcat: impl IntoIterator<Item = S>) -> Self {
+        let mut blocklist = Self::default();
+        for code in bcat {
+            match IabCategory::parse(code.as_ref()) {
+                Ok(category) => blocklist.categories.push(category),
+                Err(_) => blocklist.other.push(code.as_ref().trim().to_ascii_uppercase()),
+            }
+        }
+        blocklist
+    }
+
+    /// Creates the block list of `req.bcat`.
+    pub fn from_request(req: &crate::BidRequest) -> Self {
+        Self::new(req.bcat.iter().flatten())
+    }
+
+    /// Returns true if there is no blocked category.
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty() && self.other.is_empty()
+    }
+
+    /// Returns true if category `code` is blocked.
+    pub fn blocks(&self, code: &str) -> bool {
+        match IabCategory::parse(code) {
+            Ok(category) => self.categories.iter().any(|blocked| blocked.contains(category)),
+            Err(_) => self
+                .other
+                .iter()
+                .any(|blocked| blocked.eq_ignore_ascii_case(code.trim())),
+        }
+    }
+
+    /// Returns the categories of `bid` which are blocked.
+    pub fn blocked<'b>(&self, bid: &'b crate::Bid) -> Vec<&'b str> {
+        bid.cat
+            .iter()
+            .flatten()
+            .map(AsRef::as_ref)
+            .filter(|code| self.blocks(code))
+            .collect()
+    }
+
+    /// Returns true if one of the categories of `bid` is blocked.
+    pub fn blocks_bid(&self, bid: &crate::Bid) -> bool {
+        !self.blocked(bid).is_empty()
+    }
+}
+
+/// A category code outside the taxonomy.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Issue {
+    /// Path of the code, e.g. `site.cat[1]`.
+    pub path: String,
+    /// The unknown code.
+    pub error: Error,
+}
+
+/// Returns the category codes of `req` which are not in the IAB Content Taxonomy 1.0. Codes of
+/// objects whose `cattax` is another taxonomy are not checked.
+///
+/// ```
+/// let req: openrtb2::BidRequest = serde_json::from_str(
+///     r#"{"id":"1","imp":[],"bcat":["IAB25"],"site":{"cat":["IAB1","IAB1-99"],"content":{"cat":["music"]}}}"#,
+/// )?;
+/// let paths: Vec<_> = openrtb2::taxonomy::validate(&req).into_iter().map(|issue| issue.path).collect();
+/// assert_eq!(paths, vec!["site.cat[1]", "site.content.cat[0]"]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn validate(req: &crate::BidRequest) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    walk_request(req, &mut |cattax, lists| check(*cattax, lists, &mut issues));
+    issues
+}
+
+/// Returns the `seatbid[].bid[].cat` codes of `res` which are not in the IAB Content Taxonomy
+/// 1.0.
+pub fn validate_response(res: &crate::BidResponse) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    walk_response(res, &mut |cattax, lists| check(*cattax, lists, &mut issues));
+    issues
+}
+
+fn check(
+    cattax: Option<crate::CategoryTaxonomy>,
+    lists: Vec<(String, &Vec<std::borrow::Cow<str>>)>,
+    issues: &mut Vec<Issue>,
+) {
+    if cattax.unwrap_or_default() != crate::CategoryTaxonomy::IabContent1_0 {
+        return;
+    }
+    for (path, codes) in lists {
+        for (i, code) in codes.iter().enumerate() {
+            if let Err(error) = IabCategory::parse(code) {
+                issues.push(Issue {
+                    path: format!("{}[{}]", path, i),
+                    error,
+                });
+            }
+        }
+    }
+}
+
+/// Translates category codes between taxonomies.
+///
+/// Codes are kept between the Content Taxonomy 2.x and 3.0, which share IDs, and otherwise
+/// translated through their IAB Content Taxonomy 1.0 equivalent, using one table from
+/// each taxonomy to 1.0 and one from 1.0 to each taxonomy. A 1.0 subcategory missing from the
+/// latter translates like its tier-1 category. The [`bundled`] translator maps the numeric IDs of
+/// the Content Taxonomy 2.x and 3.0 to and from 1.0, rolling every 2.x category up to the 1.0
+/// equivalent of its tier-1 category.
+///
+/// No Ad Product Taxonomy 1.0 or 2.0 tables are embedded, nor 2.x equivalents of the 1.0 `IAB14`,
+/// `IAB24`, `IAB25` and `IAB26` subtrees. Their codes are reported as unmapped until tables, e.g.
+/// built from the IAB Tech Lab mappings, are inserted with [`insert_to_v1`] and [`insert_from_v1`].
+///
+/// ```
+/// # use openrtb2::CategoryTaxonomy;
+/// # use openrtb2::taxonomy::Translator;
+/// let translator = Translator::bundled();
+/// let mut req: openrtb2::BidRequest =
+///     serde_json::from_str(r#"{"id":"1","imp":[],"bcat":["1","33"],"cattax":6}"#)?;
+/// assert!(translator.translate_request(&mut req, CategoryTaxonomy::IabContent1_0).is_empty());
+/// assert_eq!(req.bcat.unwrap(), vec!["IAB2"]);
+/// assert_eq!(req.cattax, None);
+///
+/// let mut req: openrtb2::BidRequest =
+///     serde_json::from_str(r#"{"id":"1","imp":[],"bcat":["1","150"],"cattax":6}"#)?;
+/// let unmapped = translator.translate_request(&mut req, CategoryTaxonomy::IabContent1_0);
+/// assert_eq!(unmapped[0].path, "bcat[1]");
+/// assert_eq!(req.bcat.unwrap(), vec!["1", "150"]);
+/// assert_eq!(req.cattax, Some(CategoryTaxonomy::IabContent2_2));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// [`bundled`]: #method.bundled
+/// [`insert_to_v1`]: #method.insert_to_v1
+/// [`insert_from_v1`]: #method.insert_from_v1
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Translator {
+    to_v1: std::collections::HashMap<crate::CategoryTaxonomy, std::collections::HashMap<String, IabCategory>>,
+    from_v1: std::collections::HashMap<crate::CategoryTaxonomy, std::collections::HashMap<IabCategory, String>>,
+}
+
+impl Translator {
+    /// Creates a translator without tables, which only translates 1.0 codes to 1.0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the translator of the embedded Content Taxonomy 2.x and 3.0 tables.
+    pub fn bundled() -> &'static Self {
+        static BUNDLED: std::sync::OnceLock<Translator> = std::sync::OnceLock::new();
+        BUNDLED.get_or_init(|| {
+            let mut translator = Self::new();
+            let parse = |code: &str| IabCategory::parse(code).expect("mapped codes are in the taxonomy");
+            let ends = mapping::CONTENT_2_TIER1
+                .iter()
+                .skip(1)
+                .map(|(first, _)| *first)
+                .chain([mapping::CONTENT_2_END]);
+            for taxonomy in [
+                crate::CategoryTaxonomy::IabContent2_0,
+                crate::CategoryTaxonomy::IabContent2_1,
+                crate::CategoryTaxonomy::IabContent2_2,
+                crate::CategoryTaxonomy::IabContent3_0,
+            ] {
+                for (&(first, code), end) in mapping::CONTENT_2_TIER1.iter().zip(ends.clone()) {
+                    if let Some(code) = code {
+                        for id in first..end {
+                            translator.insert_to_v1(taxonomy, &id.to_string(), parse(code));
+                        }
+                    }
+                }
+                for (code, id) in mapping::V1_TO_CONTENT_2 {
+                    translator.insert_from_v1(taxonomy, parse(code), id);
+                }
+            }
+            translator
+        })
+    }
+
+    /// Maps `code` of `taxonomy` to the 1.0 category `category`.
+    pub fn insert_to_v1(&mut self, taxonomy: crate::CategoryTaxonomy, code: &str, category: IabCategory) {
+        self.to_v1
+            .entry(taxonomy)
+            .or_default()
+            .insert(code.trim().to_owned(), category);
+    }
+
+    /// Maps the 1.0 category `category` to `code` of `taxonomy`.
+    pub fn insert_from_v1(&mut self, taxonomy: crate::CategoryTaxonomy, category: IabCategory, code: &str) {
+        self.from_v1
+            .entry(taxonomy)
+            .or_default()
+            .insert(category, code.trim().to_owned());
+    }
+
+    /// Translates `code` from taxonomy `from` to taxonomy `to`, None if there is no mapping.
+    ///
+    /// ```
+    /// # use openrtb2::CategoryTaxonomy::*;
+    /// let translator = openrtb2::taxonomy::Translator::bundled();
+    /// assert_eq!(translator.translate(IabContent2_2, IabContent1_0, "483").as_deref(), Some("IAB17"));
+    /// assert_eq!(translator.translate(IabContent1_0, IabContent3_0, "IAB17-12").as_deref(), Some("483"));
+    /// assert_eq!(translator.translate(IabContent1_0, IabContent3_0, "IAB25"), None);
+    /// ```
+    pub fn translate(&self, from: crate::CategoryTaxonomy, to: crate::CategoryTaxonomy, code: &str) -> Option<String> {
+        if from == to || (is_content_2(from) && is_content_2(to)) {
+            return Some(code.trim().to_owned());
+        }
+        let category = match from {
+            crate::CategoryTaxonomy::IabContent1_0 => IabCategory::parse(code).ok()?,
+            _ => *self.to_v1.get(&from)?.get(code.trim())?,
+        };
+        match to {
+            crate::CategoryTaxonomy::IabContent1_0 => Some(category.code().to_owned()),
+            _ => {
+                let table = self.from_v1.get(&to)?;
+                table.get(&category).or_else(|| table.get(&category.parent()?)).cloned()
+            }
+        }
+    }
+
+    /// Rewrites the category lists of `req` in taxonomy `to` and sets their `cattax`, omitted for
+    /// the default 1.0. Duplicates are removed. The lists of an object are only rewritten if all
+    /// their codes have a mapping: otherwise they and their `cattax` are left as they are and the
+    /// codes without a mapping are returned, so that the caller decides what to do with them.
+    pub fn translate_request(&self, req: &mut crate::BidRequest, to: crate::CategoryTaxonomy) -> Vec<Unmapped> {
+        let mut unmapped = Vec::new();
+        walk_request_mut(req, &mut |cattax, lists| {
+            self.translate_lists(cattax, lists, to, &mut unmapped)
+        });
+        unmapped
+    }
+
+    /// Rewrites `seatbid[].bid[].cat` of `res` in taxonomy `to` like [`translate_request`].
+    ///
+    /// [`translate_request`]: #method.translate_request
+    pub fn translate_response(&self, res: &mut crate::BidResponse, to: crate::CategoryTaxonomy) -> Vec<Unmapped> {
+        let mut unmapped = Vec::new();
+        walk_response_mut(res, &mut |cattax, lists| {
+            self.translate_lists(cattax, lists, to, &mut unmapped)
+        });
+        unmapped
+    }
+
+    fn translate_lists(
+        &self,
+        cattax: &mut Option<crate::CategoryTaxonomy>,
+        lists: Vec<(String, &mut Vec<std::borrow::Cow<str>>)>,
+        to: crate::CategoryTaxonomy,
+        unmapped: &mut Vec<Unmapped>,
+    ) {
+        let from = cattax.unwrap_or_default();
+        if from == to {
+            return;
+        }
+        let count = unmapped.len();
+        let mut translated_lists = Vec::with_capacity(lists.len());
+        for (path, codes) in &lists {
+            let mut translated = Vec::with_capacity(codes.len());
+            for (i, code) in codes.iter().enumerate() {
+                match self.translate(from, to, code) {
+                    Some(code) if translated.contains(&code) => {}
+                    Some(code) => translated.push(code),
+                    None => unmapped.push(Unmapped {
+                        path: format!("{}[{}]", path, i),
+                        code: code.to_string(),
+                        from,
+                    }),
+                }
+            }
+            translated_lists.push(translated);
+        }
+        // The lists of an object share its cattax, so they are all translated or all kept.
+        if unmapped.len() > count {
+            return;
+        }
+        for ((_, codes), translated) in lists.into_iter().zip(translated_lists) {
+            *codes = translated.into_iter().map(Into::into).collect();
+        }
+        *cattax = Some(to).filter(|to| *to != crate::CategoryTaxonomy::default());
+    }
+}
+
+/// Returns true for the Content Taxonomy versions sharing the IDs of 2.0.
+fn is_content_2(taxonomy: crate::CategoryTaxonomy) -> bool {
+    use crate::CategoryTaxonomy::*;
+    matches!(taxonomy, IabContent2_0 | IabContent2_1 | IabContent2_2 | IabContent3_0)
+}
+
+/// A category code without a mapping to the target taxonomy.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Unmapped {
+    /// Path of the code before translation, e.g. `bcat[2]`.
+    pub path: String,
+    /// The code.
+    pub code: String,
+    /// The taxonomy of the code.
+    pub from: crate::CategoryTaxonomy,
+}
+
+/// Defines walkers calling `f` with the taxonomy and the present category lists of every object
+/// of a request or response, through shared or mutable references.
+macro_rules! walkers {
+    ($visitor:ident, $visit:ident, $walk_request:ident, $walk_channel:ident, $walk_response:ident $(, $mut:tt)?) => {
+        type $visitor<'f, 'a> = dyn FnMut(
+                &$($mut)? Option<crate::CategoryTaxonomy>,
+                Vec<(String, &$($mut)? Vec<std::borrow::Cow<'a, str>>)>,
+            ) + 'f;
+
+        /// Calls `f` with the taxonomy and the present lists of an object.
+        fn $visit<'a>(
+            cattax: &$($mut)? Option<crate::CategoryTaxonomy>,
+            lists: Vec<(String, &$($mut)? Option<Vec<std::borrow::Cow<'a, str>>>)>,
+            f: &mut $visitor<'_, 'a>,
+        ) {
+            let lists: Vec<_> = lists
+                .into_iter()
+                .filter_map(|(path, list)| Some((path, list.into_iter().next()?)))
+                .collect();
+            if !lists.is_empty() {
+                f(cattax, lists);
+            }
+        }
+
+        /// Calls `f` with the taxonomy and the category lists of every object of `req`.
+        fn $walk_request<'a>(req: &$($mut)? crate::BidRequest<'a>, f: &mut $visitor<'_, 'a>) {
+            $visit(&$($mut)? req.cattax, vec![("bcat".into(), &$($mut)? req.bcat)], f);
+            match &$($mut)? req.channel {
+                Some(crate::DistributionChannel::Site(site)) => {
+                    let lists = vec![
+                        ("site.cat".into(), &$($mut)? site.cat),
+                        ("site.sectioncat".into(), &$($mut)? site.sectioncat),
+                        ("site.pagecat".into(), &$($mut)? site.pagecat),
+                    ];
+                    $visit(&$($mut)? site.cattax, lists, f);
+                    $walk_channel("site", &$($mut)? site.publisher, &$($mut)? site.content, f);
+                }
+                Some(crate::DistributionChannel::App(app)) => {
+                    let lists = vec![
+                        ("app.cat".into(), &$($mut)? app.cat),
+                        ("app.sectioncat".into(), &$($mut)? app.sectioncat),
+                        ("app.pagecat".into(), &$($mut)? app.pagecat),
+                    ];
+                    $visit(&$($mut)? app.cattax, lists, f);
+                    $walk_channel("app", &$($mut)? app.publisher, &$($mut)? app.content, f);
+                }
+                None => {}
+            }
+        }
+
+        fn $walk_channel<'a>(
+            channel: &str,
+            publisher: &$($mut)? Option<crate::Publisher<'a>>,
+            content: &$($mut)? Option<crate::Content<'a>>,
+            f: &mut $visitor<'_, 'a>,
+        ) {
+            if let Some(publisher) = publisher {
+                let path = format!("{}.publisher.cat", channel);
+                $visit(&$($mut)? publisher.cattax, vec![(path, &$($mut)? publisher.cat)], f);
+            }
+            if let Some(content) = content {
+                let path = format!("{}.content.cat", channel);
+                $visit(&$($mut)? content.cattax, vec![(path, &$($mut)? content.cat)], f);
+                if let Some(producer) = &$($mut)? content.producer {
+                    let path = format!("{}.content.producer.cat", channel);
+                    $visit(&$($mut)? producer.cattax, vec![(path, &$($mut)? producer.cat)], f);
+                }
+            }
+        }
+
+        /// Calls `f` with the taxonomy and the category list of every bid of `res`.
+        fn $walk_response<'a>(res: &$($mut)? crate::BidResponse<'a>, f: &mut $visitor<'_, 'a>) {
+            for (i, seatbid) in (&$($mut)? res.seatbid).into_iter().flatten().enumerate() {
+                for (j, bid) in (&$($mut)? seatbid.bid).into_iter().enumerate() {
+                    let path = format!("seatbid[{}].bid[{}].cat", i, j);
+                    $visit(&$($mut)? bid.cattax, vec![(path, &$($mut)? bid.cat)], f);
+                }
+            }
+        }
+    };
+}
+
+walkers!(Visitor, visit, walk_request, walk_channel, walk_response);
+walkers!(
+    VisitorMut,
+    visit_mut,
+    walk_request_mut,
+    walk_channel_mut,
+    walk_response_mut,
+    mut
+);
+
+/// The error returned for a code outside the IAB Content Taxonomy 1.0.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Error(pub String);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown IAB content category: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn taxonomy() {
+        assert_eq!(IabCategory::all().filter(IabCategory::is_tier1).count(), 26);
+        for category in IabCategory::all() {
+            assert_eq!(IabCategory::parse(category.code()), Ok(category));
+            match category.parent() {
+                Some(parent) => {
+                    assert!(parent.is_tier1());
+                    assert!(parent.children().any(|child| child == category));
+                }
+                None => assert!(category.is_tier1()),
+            }
+        }
+        let iab24 = IabCategory::parse("IAB24").unwrap();
+        assert_eq!(iab24.children().count(), 0);
+        let iab2_5 = IabCategory::parse(" iab2-5 ").unwrap();
+        assert_eq!(iab2_5.children().count(), 0);
+        assert_eq!(iab2_5.tier1().children().count(), 23);
+    }
+
+    #[test]
+    fn json() -> serde_json::Result<()> {
+        let json = r#"["IAB1","IAB17-44"]"#;
+        let o1: Vec<IabCategory> = serde_json::from_str(r#"["iab1","IAB17-44"]"#)?;
+        assert_eq!(serde_json::to_string(&o1)?, json);
+        assert_eq!(format!("{:?}", o1[0]), r#"IabCategory("IAB1")"#);
+        assert!(serde_json::from_str::<IabCategory>(r#""IAB27""#).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn blocking() -> serde_json::Result<()> {
+        let req: crate::BidRequest = serde_json::from_str(
+            r#"{"id":"1","imp":[],"bcat":["IAB7-39","IAB25"],"app":{"cat":["IAB9"],"publisher":{"cat":["IAB99"]}}}"#,
+        )?;
+        let blocklist = BlockList::from_request(&req);
+        let res: crate::BidResponse = serde_json::from_str(
+            r#"{"id":"1","seatbid":[{"bid":[
+                {"id":"1","impid":"1","price":1,"cat":["IAB7","IAB25-3"]},
+                {"id":"2","impid":"1","price":1,"cat":["IAB7-38"]},
+                {"id":"3","impid":"1","price":1}
+            ]}]}"#,
+        )?;
+        let bids = &res.seatbid.as_ref().unwrap()[0].bid;
+        assert_eq!(blocklist.blocked(&bids[0]), vec!["IAB25-3"]);
+        assert!(!blocklist.blocks_bid(&bids[1]));
+        assert!(!blocklist.blocks_bid(&bids[2]));
+        assert!(BlockList::default().is_empty());
+
+        let issues = validate(&req);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, "app.publisher.cat[0]");
+        assert_eq!(issues[0].error, Error("IAB99".into()));
+        assert!(validate_response(&res).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn translation() -> serde_json::Result<()> {
+        use crate::CategoryTaxonomy::*;
+
+        let translator = Translator::bundled();
+        assert_eq!(
+            translator.translate(IabContent2_0, IabContent3_0, "597").as_deref(),
+            Some("597")
+        );
+        assert_eq!(
+            translator.translate(IabContent2_1, IabContent1_0, "698").as_deref(),
+            Some("IAB9-30")
+        );
+        assert_eq!(translator.translate(IabContent2_1, IabContent1_0, "699"), None);
+        assert_eq!(
+            translator.translate(IabContent2_1, IabContent2_2, "1000").as_deref(),
+            Some("1000")
+        );
+        for (_, id) in mapping::V1_TO_CONTENT_2 {
+            assert!(translator.translate(IabContent2_2, IabContent1_0, id).is_some());
+        }
+
+        let mut req: crate::BidRequest = serde_json::from_str(
+            r#"{"id":"1","imp":[],"bcat":["IAB25-3"],"site":{"cat":["596","597","9999"],"cattax":7,"content":{"cat":["IAB1-6"],"producer":{"cat":["99"],"cattax":501}}}}"#,
+        )?;
+        let unmapped = translator.translate_request(&mut req, IabContent1_0);
+        assert_eq!(
+            unmapped,
+            vec![
+                Unmapped {
+                    path: "site.cat[2]".into(),
+                    code: "9999".into(),
+                    from: IabContent3_0
+                },
+                Unmapped {
+                    path: "site.content.producer.cat[0]".into(),
+                    code: "99".into(),
+                    from: VendorSpecific(501)
+                },
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&req)?,
+            r#"{"id":"1","imp":[],"site":{"cat":["596","597","9999"],"cattax":7,"content":{"producer":{"cat":["99"],"cattax":501},"cat":["IAB1-6"]}},"bcat":["IAB25-3"]}"#
+        );
+
+        let mut req: crate::BidRequest = serde_json::from_str(
+            r#"{"id":"1","imp":[],"bcat":["IAB7-39","IAB25"],"site":{"cat":["596","597"],"cattax":7}}"#,
+        )?;
+        let unmapped = translator.translate_request(&mut req, IabContent3_0);
+        assert_eq!(
+            unmapped,
+            vec![Unmapped {
+                path: "bcat[1]".into(),
+                code: "IAB25".into(),
+                from: IabContent1_0
+            }]
+        );
+        assert_eq!(
+            serde_json::to_string(&req)?,
+            r#"{"id":"1","imp":[],"site":{"cat":["596","597"],"cattax":7},"bcat":["IAB7-39","IAB25"]}"#
+        );
+        assert!(translator.translate_request(&mut req, IabContent1_0).is_empty());
+        assert_eq!(
+            req.channel.unwrap().as_site().unwrap().cat.as_ref().unwrap(),
+            &["IAB19"]
+        );
+
+        let mut res: crate::BidResponse = serde_json::from_str(
+            r#"{"id":"1","seatbid":[{"bid":[{"id":"1","impid":"1","price":1,"cat":["IAB17-12","IAB17"]}]}]}"#,
+        )?;
+        assert!(translator.translate_response(&mut res, IabContent2_2).is_empty());
+        assert_eq!(
+            serde_json::to_string(&res)?,
+            r#"{"id":"1","seatbid":[{"bid":[{"id":"1","impid":"1","price":1.0,"cat":["483"],"cattax":6}]}]}"#
+        );
+
+        let mut translator = Translator::new();
+        translator.insert_to_v1(IabAdProduct2_0, "1203", IabCategory::parse("IAB8-5").unwrap());
+        assert_eq!(
+            translator.translate(IabAdProduct2_0, IabContent1_0, "1203").as_deref(),
+            Some("IAB8-5")
+        );
+        assert_eq!(translator.translate(IabContent1_0, IabAdProduct2_0, "IAB8-5"), None);
+
+        Ok(())
+    }
+}
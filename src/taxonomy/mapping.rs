@@ -0,0 +1,75 @@
+//! Mappings between the IAB Content Taxonomy 1.0 and the numeric IDs of Content Taxonomy 2.x/3.0.
+//!
+//! 3.0 keeps the IDs of the 2.x categories it retains, so both versions share these tables.
+
+/// First ID of each tier-1 category of Content Taxonomy 2.0, which is numbered depth-first, with
+/// the 1.0 category its whole subtree maps to, None if 1.0 has no equivalent.
+#[rustfmt::skip]
+pub(super) const CONTENT_2_TIER1: &[(u32, Option<&str>)] = &[
+    (1, Some("IAB2")),      // Automotive
+    (42, Some("IAB1-1")),   // Books and Literature
+    (52, Some("IAB3")),     // Business and Finance
+    (123, Some("IAB4")),    // Careers
+    (132, Some("IAB5")),    // Education
+    (150, None),            // Events and Attractions
+    (186, Some("IAB6")),    // Family and Relationships
+    (201, Some("IAB1-3")),  // Fine Art
+    (210, Some("IAB8")),    // Food & Drink
+    (223, Some("IAB7")),    // Healthy Living
+    (239, Some("IAB9")),    // Hobbies & Interests
+    (274, Some("IAB10")),   // Home & Garden
+    (286, Some("IAB7")),    // Medical Health
+    (324, Some("IAB1-5")),  // Movies
+    (338, Some("IAB1-6")),  // Music and Audio
+    (379, Some("IAB12")),   // News and Politics
+    (391, Some("IAB13")),   // Personal Finance
+    (422, Some("IAB16")),   // Pets
+    (432, Some("IAB1-2")),  // Pop Culture
+    (441, Some("IAB21")),   // Real Estate
+    (453, Some("IAB23")),   // Religion & Spirituality
+    (464, Some("IAB15")),   // Science
+    (473, Some("IAB22")),   // Shopping
+    (483, Some("IAB17")),   // Sports
+    (552, Some("IAB18")),   // Style & Fashion
+    (596, Some("IAB19")),   // Technology & Computing
+    (640, Some("IAB1-7")),  // Television
+    (653, Some("IAB20")),   // Travel
+    (680, Some("IAB9-30")), // Video Gaming
+];
+
+/// One past the last numeric ID of Content Taxonomy 2.0.
+pub(super) const CONTENT_2_END: u32 = 699;
+
+/// The Content Taxonomy 2.x/3.0 category of 1.0 categories. A subcategory missing from this table
+/// maps like its tier-1 category.
+#[rustfmt::skip]
+pub(super) const V1_TO_CONTENT_2: &[(&str, &str)] = &[
+    ("IAB1-1", "42"),
+    ("IAB1-2", "432"),
+    ("IAB1-3", "201"),
+    ("IAB1-5", "324"),
+    ("IAB1-6", "338"),
+    ("IAB1-7", "640"),
+    ("IAB2", "1"),
+    ("IAB3", "52"),
+    ("IAB4", "123"),
+    ("IAB5", "132"),
+    ("IAB6", "186"),
+    ("IAB7", "223"),
+    ("IAB8", "210"),
+    ("IAB9", "239"),
+    ("IAB9-30", "680"),
+    ("IAB10", "274"),
+    ("IAB11", "379"),
+    ("IAB12", "379"),
+    ("IAB13", "391"),
+    ("IAB15", "464"),
+    ("IAB16", "422"),
+    ("IAB17", "483"),
+    ("IAB18", "552"),
+    ("IAB19", "596"),
+    ("IAB20", "653"),
+    ("IAB21", "441"),
+    ("IAB22", "473"),
+    ("IAB23", "453"),
+];
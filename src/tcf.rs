@@ -0,0 +1,513 @@
+//! IAB Transparency and Consent Framework v2 consent strings.
+//!
+//! Under GDPR the exchange signals in `regs.ext.gdpr` whether the regulation applies and forwards
+//! the TC string of the user in `user.ext.consent`. This module decodes the core segment of the
+//! string so the consent of a given vendor can be checked before personal data in `User` or
+//! `Device` is sent to it.
+
+use std::collections::BTreeSet;
+
+/// Maximum number of vendor IDs the ranges of a TC string may cover, which bounds the work spent
+/// on untrusted strings. Genuine strings cover each vendor at most once per vendor section and
+/// restriction.
+const MAX_RANGE_IDS: usize = 1 << 18;
+
+/// A decoded TC string (core segment).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct TcString {
+    /// Version of the TC string format, 2.
+    pub version: u8,
+    /// Time the string was created, in deciseconds since the Unix epoch.
+    pub created: u64,
+    /// Time the string was last updated, in deciseconds since the Unix epoch.
+    pub last_updated: u64,
+    /// Consent Management Platform ID.
+    pub cmp_id: u16,
+    /// Consent Management Platform version.
+    pub cmp_version: u16,
+    /// Screen number in the CMP where consent was given.
+    pub consent_screen: u8,
+    /// Two-letter ISO 639-1 language code of the CMP UI.
+    pub consent_language: String,
+    /// Version of the Global Vendor List used.
+    pub vendor_list_version: u16,
+    /// Version of the TCF policy used.
+    pub policy_version: u8,
+    /// Whether the signals are specific to the service rather than global.
+    pub is_service_specific: bool,
+    /// Whether non-standard texts were used to describe purposes.
+    pub use_non_standard_texts: bool,
+    /// Special features the user opted in to.
+    pub special_feature_opt_ins: BTreeSet<u8>,
+    /// Purposes the user consented to.
+    pub purpose_consents: BTreeSet<u8>,
+    /// Purposes for which legitimate interest was transparently established.
+    pub purpose_legitimate_interests: BTreeSet<u8>,
+    /// Whether purpose 1 was not disclosed, as allowed in some countries.
+    pub purpose_one_treatment: bool,
+    /// Two-letter ISO 3166-1 country code of the publisher.
+    pub publisher_cc: String,
+    /// Vendors the user consented to.
+    pub vendor_consents: BTreeSet<u16>,
+    /// Vendors for which legitimate interest was transparently established.
+    pub vendor_legitimate_interests: BTreeSet<u16>,
+    /// Restrictions of the publisher on the legal basis of vendors.
+    pub publisher_restrictions: Vec<PublisherRestriction>,
+}
+
+/// A restriction of the publisher on the legal basis a vendor may use for a purpose.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PublisherRestriction {
+    /// The restricted purpose.
+    pub purpose_id: u8,
+    /// The kind of restriction.
+    pub restriction_type: RestrictionType,
+    /// The vendors the restriction applies to.
+    pub vendors: BTreeSet<u16>,
+}
+
+/// Kind of publisher restriction.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RestrictionType {
+    /// The purpose is flatly not allowed.
+    NotAllowed,
+    /// Consent is required.
+    RequireConsent,
+    /// Legitimate interest is required.
+    RequireLegitimateInterest,
+    /// Reserved value.
+    Undefined,
+}
+
+impl TcString {
+    /// Decodes a TC string. Only the core segment is decoded, other segments are ignored.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let core = s.trim().split('.').next().unwrap_or_default();
+        let bytes = decode_base64(core)?;
+        let mut r = BitReader::new(&bytes);
+        let mut budget = MAX_RANGE_IDS;
+
+        let version = r.read(6)? as u8;
+        if version != 2 {
+            return Err(Error::Version(version));
+        }
+
+        let mut tc = Self {
+            version,
+            created: r.read(36)?,
+            last_updated: r.read(36)?,
+            cmp_id: r.read(12)? as u16,
+            cmp_version: r.read(12)? as u16,
+            consent_screen: r.read(6)? as u8,
+            consent_language: r.read_letters()?,
+            vendor_list_version: r.read(12)? as u16,
+            policy_version: r.read(6)? as u8,
+            is_service_specific: r.read_bool()?,
+            use_non_standard_texts: r.read_bool()?,
+            special_feature_opt_ins: r.read_bitfield(12)?,
+            purpose_consents: r.read_bitfield(24)?,
+            purpose_legitimate_interests: r.read_bitfield(24)?,
+            purpose_one_treatment: r.read_bool()?,
+            publisher_cc: r.read_letters()?,
+            ..Default::default()
+        };
+        tc.vendor_consents = r.read_vendors(&mut budget)?;
+        tc.vendor_legitimate_interests = r.read_vendors(&mut budget)?;
+
+        let restrictions = r.read(12)?;
+        for _ in 0..restrictions {
+            let purpose_id = r.read(6)? as u8;
+            let restriction_type = match r.read(2)? {
+                0 => RestrictionType::NotAllowed,
+                1 => RestrictionType::RequireConsent,
+                2 => RestrictionType::RequireLegitimateInterest,
+                _ => RestrictionType::Undefined,
+            };
+            tc.publisher_restrictions.push(PublisherRestriction {
+                purpose_id,
+                restriction_type,
+                vendors: r.read_ranges(&mut budget)?,
+            });
+        }
+
+        Ok(tc)
+    }
+
+    /// Returns the restriction of the publisher on `purpose_id` for `vendor_id`, if any.
+    pub fn restriction(&self, vendor_id: u16, purpose_id: u8) -> Option<RestrictionType> {
+        self.publisher_restrictions
+            .iter()
+            .find(|r| r.purpose_id == purpose_id && r.vendors.contains(&vendor_id))
+            .map(|r| r.restriction_type)
+    }
+
+    /// Returns true if `vendor_id` may process personal data for all of `purposes` based on the
+    /// consent of the user, honouring publisher restrictions.
+    pub fn vendor_has_consent(&self, vendor_id: u16, purposes: &[u8]) -> bool {
+        self.vendor_consents.contains(&vendor_id)
+            && purposes
+                .iter()
+                .all(|purpose| match self.restriction(vendor_id, *purpose) {
+                    Some(RestrictionType::NotAllowed) => false,
+                    Some(RestrictionType::RequireLegitimateInterest) => {
+                        self.has_legitimate_interest(vendor_id, *purpose)
+                    }
+                    _ => self.purpose_consents.contains(purpose),
+                })
+    }
+
+    /// Returns true if `vendor_id` may process personal data for all of `purposes` based on
+    /// legitimate interest, honouring publisher restrictions.
+    pub fn vendor_has_legitimate_interest(&self, vendor_id: u16, purposes: &[u8]) -> bool {
+        purposes
+            .iter()
+            .all(|purpose| match self.restriction(vendor_id, *purpose) {
+                Some(RestrictionType::NotAllowed) => false,
+                Some(RestrictionType::RequireConsent) => {
+                    self.vendor_consents.contains(&vendor_id) && self.purpose_consents.contains(purpose)
+                }
+                _ => self.has_legitimate_interest(vendor_id, *purpose),
+            })
+    }
+
+    fn has_legitimate_interest(&self, vendor_id: u16, purpose: u8) -> bool {
+        self.vendor_legitimate_interests.contains(&vendor_id) && self.purpose_legitimate_interests.contains(&purpose)
+    }
+}
+
+impl std::str::FromStr for TcString {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl crate::BidRequest<'_> {
+    /// Returns the decoded TC string of `user.ext.consent`, None if there is none.
+    pub fn tc_string(&self) -> Option<Result<TcString, Error>> {
+        self.user.as_ref()?.consent().map(|consent| TcString::parse(&consent))
+    }
+
+    /// Returns true if personal data may be sent to `vendor_id` for all of `purposes`.
+    ///
+    /// This is the case when GDPR does not apply according to `regs.ext.gdpr`, or when the TC
+    /// string in `user.ext.consent` grants consent to the vendor and purposes. A missing or
+    /// malformed TC string grants nothing.
+    pub fn vendor_has_consent(&self, vendor_id: u16, purposes: &[u8]) -> bool {
+        if !self.regs.as_ref().and_then(crate::Regs::gdpr).unwrap_or(false) {
+            return true;
+        }
+        match self.tc_string() {
+            Some(Ok(tc)) => tc.vendor_has_consent(vendor_id, purposes),
+            _ => false,
+        }
+    }
+}
+
+/// Decodes a base64url segment. Encoders pad the bits to a multiple of 6 rather than 8, so the
+/// segment is padded with zero sextets to keep its last bits.
+pub(crate) fn decode_base64(segment: &str) -> Result<Vec<u8>, Error> {
+    use base64::Engine as _;
+
+    const ENGINE: base64::engine::GeneralPurpose = base64::engine::GeneralPurpose::new(
+        &base64::alphabet::URL_SAFE,
+        base64::engine::GeneralPurposeConfig::new()
+            .with_decode_padding_mode(base64::engine::DecodePaddingMode::Indifferent),
+    );
+
+    let mut segment = segment.trim_end_matches('=').to_owned();
+    while !segment.len().is_multiple_of(4) {
+        segment.push('A');
+    }
+    ENGINE.decode(segment).map_err(|_| Error::Base64)
+}
+
+/// Reads big-endian bit fields from a byte string.
+pub(crate) struct BitReader<'b> {
+    bytes: &'b [u8],
+    pos: usize,
+}
+
+impl<'b> BitReader<'b> {
+    pub(crate) fn new(bytes: &'b [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    pub(crate) fn read(&mut self, bits: usize) -> Result<u64, Error> {
+        if self.pos + bits > self.bytes.len() * 8 {
+            return Err(Error::UnexpectedEnd);
+        }
+        let mut value = 0;
+        for _ in 0..bits {
+            let bit = (self.bytes[self.pos / 8] >> (7 - self.pos % 8)) & 1;
+            value = (value << 1) | bit as u64;
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+
+    pub(crate) fn read_bool(&mut self) -> Result<bool, Error> {
+        Ok(self.read(1)? == 1)
+    }
+
+    fn read_letters(&mut self) -> Result<String, Error> {
+        let first = (b'A' + self.read(6)? as u8) as char;
+        let second = (b'A' + self.read(6)? as u8) as char;
+        Ok([first, second].iter().collect())
+    }
+
+    fn read_bitfield<T>(&mut self, bits: usize) -> Result<BTreeSet<T>, Error>
+    where
+        T: TryFrom<usize> + Ord,
+    {
+        let mut set = BTreeSet::new();
+        for id in 1..=bits {
+            if self.read_bool()? {
+                set.extend(T::try_from(id).ok());
+            }
+        }
+        Ok(set)
+    }
+
+    fn read_vendors(&mut self, budget: &mut usize) -> Result<BTreeSet<u16>, Error> {
+        let max_vendor_id = self.read(16)? as usize;
+        if self.read_bool()? {
+            self.read_ranges(budget)
+        } else {
+            self.read_bitfield(max_vendor_id)
+        }
+    }
+
+    /// Reads range entries, taking the number of IDs they cover from `budget`.
+    fn read_ranges(&mut self, budget: &mut usize) -> Result<BTreeSet<u16>, Error> {
+        let mut set = BTreeSet::new();
+        let entries = self.read(12)?;
+        for _ in 0..entries {
+            let is_range = self.read_bool()?;
+            let start = self.read(16)? as u16;
+            let end = if is_range { self.read(16)? as u16 } else { start };
+            if end < start {
+                return Err(Error::Range);
+            }
+            *budget = budget.checked_sub(usize::from(end - start) + 1).ok_or(Error::Range)?;
+            set.extend(start..=end);
+        }
+        Ok(set)
+    }
+}
+
+/// The error type for TC string decoding.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Error {
+    /// The string is not valid base64url.
+    Base64,
+    /// The string uses an unsupported version.
+    Version(u8),
+    /// The string ends before all fields were read.
+    UnexpectedEnd,
+    /// A vendor range is reversed, or the ranges cover too many vendors.
+    Range,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Base64 => f.write_str("invalid base64url encoding"),
+            Self::Version(version) => write!(f, "unsupported tc string version: {}", version),
+            Self::UnexpectedEnd => f.write_str("unexpected end of tc string"),
+            Self::Range => f.write_str("invalid vendor range in tc string"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Default)]
+    struct BitWriter {
+        bits: Vec<bool>,
+    }
+
+    impl BitWriter {
+        fn write(&mut self, bits: usize, value: u64) -> &mut Self {
+            for i in (0..bits).rev() {
+                self.bits.push((value >> i) & 1 == 1);
+            }
+            self
+        }
+
+        /// Encodes the bits as sextets, like the IAB reference encoder, which leaves non-zero bits
+        /// in the last character when the bit count is not a multiple of 8.
+        fn finish(&self) -> String {
+            const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+            self.bits
+                .chunks(6)
+                .map(|chunk| {
+                    let sextet = (0..6).fold(0, |b, i| b << 1 | chunk.get(i).copied().unwrap_or(false) as usize);
+                    ALPHABET[sextet] as char
+                })
+                .collect()
+        }
+    }
+
+    fn encode() -> String {
+        let mut w = BitWriter::default();
+        w.write(6, 2)
+            .write(36, 15_000_000_000)
+            .write(36, 15_000_000_001)
+            .write(12, 7)
+            .write(12, 1)
+            .write(6, 1)
+            .write(6, 4)
+            .write(6, 13) // "EN"
+            .write(12, 100)
+            .write(6, 2)
+            .write(1, 1)
+            .write(1, 0)
+            .write(12, 0b1000_0000_0000) // special feature 1
+            .write(24, 0b1110_0000_0000_0000_0000_0000) // purposes 1, 2, 3
+            .write(24, 0b0001_0000_0000_0000_0000_0000) // purpose 4
+            .write(1, 0)
+            .write(6, 3)
+            .write(6, 4); // "DE"
+
+        // vendor consents as bitfield: vendors 2 and 5
+        w.write(16, 5).write(1, 0).write(5, 0b01001);
+        // vendor legitimate interests as ranges: 10..=12
+        w.write(16, 12)
+            .write(1, 1)
+            .write(12, 1)
+            .write(1, 1)
+            .write(16, 10)
+            .write(16, 12);
+        // publisher restriction: purpose 2 not allowed for vendor 5
+        w.write(12, 1)
+            .write(6, 2)
+            .write(2, 0)
+            .write(12, 1)
+            .write(1, 0)
+            .write(16, 5);
+        w.finish()
+    }
+
+    #[test]
+    fn parse() -> Result<(), Error> {
+        let tc: TcString = format!("{}.YAAAAAAAAAAA", encode()).parse()?;
+
+        assert_eq!(tc.version, 2);
+        assert_eq!(tc.created, 15_000_000_000);
+        assert_eq!(tc.cmp_id, 7);
+        assert_eq!(tc.consent_language, "EN");
+        assert_eq!(tc.vendor_list_version, 100);
+        assert!(tc.is_service_specific);
+        assert_eq!(tc.special_feature_opt_ins, [1].into());
+        assert_eq!(tc.purpose_consents, [1, 2, 3].into());
+        assert_eq!(tc.purpose_legitimate_interests, [4].into());
+        assert_eq!(tc.publisher_cc, "DE");
+        assert_eq!(tc.vendor_consents, [2, 5].into());
+        assert_eq!(tc.vendor_legitimate_interests, [10, 11, 12].into());
+        assert_eq!(tc.restriction(5, 2), Some(RestrictionType::NotAllowed));
+
+        assert!(tc.vendor_has_consent(2, &[1, 2]));
+        assert!(tc.vendor_has_consent(5, &[1]));
+        assert!(!tc.vendor_has_consent(5, &[1, 2]));
+        assert!(!tc.vendor_has_consent(3, &[1]));
+        assert!(!tc.vendor_has_consent(2, &[4]));
+        assert!(tc.vendor_has_legitimate_interest(11, &[4]));
+
+        assert_eq!(
+            TcString::parse("BOEFEAyOEFEAyAHABDENAI4AAAB9vABAASA"),
+            Err(Error::Version(1))
+        );
+        assert_eq!(TcString::parse("CA"), Err(Error::UnexpectedEnd));
+        assert_eq!(TcString::parse("!"), Err(Error::Base64));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ranges() {
+        let header = |w: &mut BitWriter| {
+            w.write(6, 2);
+            for _ in 6..213 {
+                w.write(1, 0);
+            }
+            // no vendor consents, vendor legitimate interests as a reversed range
+            w.write(16, 0).write(1, 0);
+        };
+
+        let mut w = BitWriter::default();
+        header(&mut w);
+        w.write(16, 12)
+            .write(1, 1)
+            .write(12, 1)
+            .write(1, 1)
+            .write(16, 12)
+            .write(16, 10);
+        assert_eq!(TcString::parse(&w.finish()), Err(Error::Range));
+
+        // every restriction covers all vendors
+        let mut w = BitWriter::default();
+        header(&mut w);
+        w.write(16, 0).write(1, 0).write(12, 4095);
+        for _ in 0..4095 {
+            w.write(6, 1)
+                .write(2, 0)
+                .write(12, 1)
+                .write(1, 1)
+                .write(16, 0)
+                .write(16, u16::MAX as u64);
+        }
+        assert_eq!(TcString::parse(&w.finish()), Err(Error::Range));
+    }
+
+    #[test]
+    fn cmp() -> Result<(), Error> {
+        // example string of the TCF v2 specification, with a publisher purposes segment
+        let tc = TcString::parse(
+            "COtybn4PA_zT4KjACBENAPCIAEBAAECAAIAAAAAAAAAA.IFoEUQQgAIQwgIwQABAEAAAAOIAACAIAAAAQAIAgEAACEAAAAAgAQBAAAAAAAGBAAgAAAAAAAFAAECAAAgAAQARAEQAAAAAJAAIAAgAAAYQEAAAQmAgBC3ZAYzUw",
+        )?;
+        assert_eq!(tc.created, 15_800_580_600);
+        assert_eq!(tc.last_updated, 16_122_852_600);
+        assert_eq!((tc.cmp_id, tc.cmp_version, tc.consent_screen), (675, 2, 1));
+        assert_eq!(tc.consent_language, "EN");
+        assert_eq!((tc.vendor_list_version, tc.policy_version), (15, 2));
+        assert_eq!(tc.special_feature_opt_ins, [1].into());
+        assert_eq!(tc.purpose_consents, [2, 10].into());
+        assert_eq!(tc.purpose_legitimate_interests, [2, 9].into());
+        assert!(tc.purpose_one_treatment);
+        assert!(tc.vendor_consents.is_empty());
+
+        // strings produced by CMPs are not padded to a byte boundary
+        assert_eq!(encode().len() % 4, 2);
+        use base64::Engine as _;
+        assert!(base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(encode())
+            .is_err());
+        assert_eq!(TcString::parse(&encode())?.vendor_consents, [2, 5].into());
+
+        Ok(())
+    }
+
+    #[test]
+    fn bid_request() -> serde_json::Result<()> {
+        let json = format!(
+            r#"{{"id":"1","imp":[],"user":{{"ext":{{"consent":"{}"}}}},"regs":{{"ext":{{"gdpr":1}}}}}}"#,
+            encode()
+        );
+        let req: crate::BidRequest = serde_json::from_str(&json)?;
+        assert!(req.vendor_has_consent(2, &[1]));
+        assert!(!req.vendor_has_consent(3, &[1]));
+
+        let req: crate::BidRequest = serde_json::from_str(r#"{"id":"1","imp":[],"regs":{"ext":{"gdpr":1}}}"#)?;
+        assert!(!req.vendor_has_consent(2, &[1]));
+
+        assert!(crate::BidRequest::default().vendor_has_consent(3, &[1]));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+//! IAB US Privacy String (CCPA).
+//!
+//! The exchange forwards the US Privacy string of the user in `regs.ext.us_privacy`, e.g. "1YNN":
+//! a version followed by three signals, each `Y` (yes), `N` (no) or `-` (not applicable).
+
+/// A parsed US Privacy string. Signals are None when not applicable (`-`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct UsPrivacy {
+    /// Specification version, 1.
+    pub version: u8,
+    /// Whether explicit notice and the opportunity to opt out was provided.
+    pub notice: Option<bool>,
+    /// Whether the user opted out of the sale of their personal information.
+    pub opt_out_sale: Option<bool>,
+    /// Whether the publisher is a signatory of the IAB Limited Service Provider Agreement.
+    pub lspa_covered: Option<bool>,
+}
+
+impl UsPrivacy {
+    /// Parses a US Privacy string.
+    ///
+    /// ```
+    /// # use openrtb2::us_privacy::UsPrivacy;
+    /// let usp = UsPrivacy::parse("1YYN")?;
+    /// assert_eq!(usp.opt_out_sale, Some(true));
+    /// assert!(!usp.may_sell());
+    /// assert!(UsPrivacy::parse("1---")?.may_sell());
+    /// # Ok::<(), openrtb2::us_privacy::Error>(())
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let bytes = s.trim().as_bytes();
+        if bytes.len() != 4 {
+            return Err(Error(s.to_owned()));
+        }
+        let signal = |b: u8| match b.to_ascii_uppercase() {
+            b'Y' => Ok(Some(true)),
+            b'N' => Ok(Some(false)),
+            b'-' => Ok(None),
+            _ => Err(Error(s.to_owned())),
+        };
+        let version = match bytes[0] {
+            b'1' => 1,
+            _ => return Err(Error(s.to_owned())),
+        };
+        Ok(Self {
+            version,
+            notice: signal(bytes[1])?,
+            opt_out_sale: signal(bytes[2])?,
+            lspa_covered: signal(bytes[3])?,
+        })
+    }
+
+    /// Returns false if the user opted out of the sale of their personal information.
+    pub fn may_sell(&self) -> bool {
+        self.opt_out_sale != Some(true)
+    }
+}
+
+impl std::str::FromStr for UsPrivacy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl std::fmt::Display for UsPrivacy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let signal = |s: Option<bool>| match s {
+            Some(true) => 'Y',
+            Some(false) => 'N',
+            None => '-',
+        };
+        write!(
+            f,
+            "{}{}{}{}",
+            self.version,
+            signal(self.notice),
+            signal(self.opt_out_sale),
+            signal(self.lspa_covered)
+        )
+    }
+}
+
+/// The error returned for a malformed US Privacy string.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Error(pub String);
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid us privacy string: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let usp: UsPrivacy = "1YNy".parse().unwrap();
+        assert_eq!(
+            usp,
+            UsPrivacy {
+                version: 1,
+                notice: Some(true),
+                opt_out_sale: Some(false),
+                lspa_covered: Some(true),
+            }
+        );
+        assert_eq!(usp.to_string(), "1YNY");
+        assert_eq!(UsPrivacy::parse("1---").unwrap().to_string(), "1---");
+
+        assert!(UsPrivacy::parse("").is_err());
+        assert!(UsPrivacy::parse("2YNN").is_err());
+        assert!(UsPrivacy::parse("1YNX").is_err());
+        assert!(UsPrivacy::parse("1YNNN").is_err());
+    }
+}
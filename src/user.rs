@@ -5,6 +5,8 @@
 /// other privacy policies. However, this user ID must be stable long enough to serve reasonably as
 /// the basis for frequency capping and retargeting.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct User<'a> {
     /// string; recommended
     /// Exchange-specific ID for the user. At least one of id or buyeruid is recommended.
@@ -53,8 +55,25 @@ pub struct User<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
+
+impl User<'_> {
+    /// Returns the GDPR consent string of the `consent` extension.
+    ///
+    /// ```
+    /// # use openrtb2::User;
+    /// let user: User = serde_json::from_str(r#"{"ext":{"consent":"CPc"}}"#)?;
+    /// assert_eq!(user.consent().as_deref(), Some("CPc"));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn consent(&self) -> Option<String> {
+        crate::ext::get_str(self.ext.as_ref(), "consent")
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
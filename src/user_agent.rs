@@ -0,0 +1,372 @@
+//! User agent parsing.
+//!
+//! Exchanges often receive only `device.ua` and have to fill the other `Device` fields
+//! themselves. A [`Parser`] matches the user agent against an ordered list of [`Rule`]s and fills
+//! the fields the publisher did not provide.
+//!
+//! The bundled rule set covers common phones, tablets, computers, connected TVs and set top boxes.
+//! It is plain JSON, so an updated set can be loaded with [`Parser::from_json`] without a new
+//! release of this crate.
+//!
+//! ```
+//! # use openrtb2::user_agent::Parser;
+//! let mut device: openrtb2::Device = serde_json::from_str(
+//!     r#"{"ua":"Mozilla/5.0 (Linux; Android 13; SM-S911B) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/116.0.0.0 Mobile Safari/537.36","os":"android"}"#,
+//! )?;
+//! assert!(Parser::bundled().enrich(&mut device));
+//! assert_eq!(device.make.as_deref(), Some("Samsung"));
+//! assert_eq!(device.model.as_deref(), Some("SM-S911B"));
+//! assert_eq!(device.os.as_deref(), Some("android"));
+//! assert_eq!(device.osv.as_deref(), Some("13"));
+//! assert_eq!(device.devicetype, Some(openrtb2::DeviceType::Phone));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`Parser`]: ./struct.Parser.html
+//! [`Rule`]: ./struct.Rule.html
+//! [`Parser::from_json`]: ./struct.Parser.html#method.from_json
+
+/// The bundled rule set.
+pub const RULES: &str = include_str!("user_agent/rules.json");
+
+/// A rule matching user agents which contain all of the `all` tokens and none of the `none`
+/// tokens, case-insensitively. Each field of the result is taken from the first matching rule
+/// providing it.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    /// Tokens the user agent must contain.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub all: Vec<String>,
+    /// Tokens the user agent must not contain.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub none: Vec<String>,
+    /// Device make.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub make: Option<Field>,
+    /// Device model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<Field>,
+    /// Operating system.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub os: Option<Field>,
+    /// Operating system version. Captures keep the leading digits and dots, `_` is read as `.`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub osv: Option<Field>,
+    /// Device type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub devicetype: Option<crate::DeviceType>,
+    /// JavaScript support.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub js: Option<bool>,
+}
+
+/// A value set by a rule.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+pub enum Field {
+    /// A fixed value.
+    Value(String),
+    /// A part of the user agent.
+    Capture(Capture),
+}
+
+/// A part of the user agent: the text following `after`, then `skip` if set, up to the first of
+/// the `until` tokens. Tokens are case-insensitive.
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Capture {
+    /// Token preceding the value.
+    pub after: String,
+    /// Token skipped after `after`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip: Option<String>,
+    /// Tokens ending the value.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub until: Vec<String>,
+    /// Captured values to ignore, e.g. placeholders of reduced user agents.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
+
+/// The device properties found in a user agent.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct UserAgent {
+    /// Device make (e.g., "Apple").
+    pub make: Option<String>,
+    /// Device model (e.g., "iPhone").
+    pub model: Option<String>,
+    /// Device operating system (e.g., "iOS").
+    pub os: Option<String>,
+    /// Device operating system version (e.g., "17.0").
+    pub osv: Option<String>,
+    /// The general type of device.
+    pub devicetype: Option<crate::DeviceType>,
+    /// Support for JavaScript.
+    pub js: Option<bool>,
+}
+
+/// Parses user agents with a rule set.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Parser {
+    rules: Vec<Rule>,
+}
+
+impl Parser {
+    /// Creates a parser applying `rules` in order.
+    pub fn new(rules: Vec<Rule>) -> Self {
+        let lowercase = |tokens: &mut Vec<String>| tokens.iter_mut().for_each(|t| *t = t.to_ascii_lowercase());
+        let mut rules = rules;
+        for rule in rules.iter_mut() {
+            lowercase(&mut rule.all);
+            lowercase(&mut rule.none);
+            for field in [&mut rule.make, &mut rule.model, &mut rule.os, &mut rule.osv] {
+                if let Some(Field::Capture(capture)) = field {
+                    capture.after = capture.after.to_ascii_lowercase();
+                    capture.skip = capture.skip.as_ref().map(|skip| skip.to_ascii_lowercase());
+                    lowercase(&mut capture.until);
+                    lowercase(&mut capture.exclude);
+                }
+            }
+        }
+        Self { rules }
+    }
+
+    /// Creates a parser from a JSON array of rules.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json).map(Self::new)
+    }
+
+    /// Returns the parser of the bundled rule set.
+    pub fn bundled() -> &'static Self {
+        static BUNDLED: std::sync::OnceLock<Parser> = std::sync::OnceLock::new();
+        BUNDLED.get_or_init(|| Self::from_json(RULES).expect("bundled user agent rules are valid"))
+    }
+
+    /// Returns the rules of this parser.
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Parses `ua`.
+    ///
+    /// ```
+    /// # use openrtb2::user_agent::Parser;
+    /// let ua = Parser::bundled().parse(
+    ///     "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Mobile/15E148 Safari/604.1",
+    /// );
+    /// assert_eq!(ua.model.as_deref(), Some("iPhone"));
+    /// assert_eq!(ua.osv.as_deref(), Some("17.0"));
+    /// ```
+    pub fn parse(&self, ua: &str) -> UserAgent {
+        // ASCII lowercasing keeps byte offsets, so captures can slice the original
+        let lower = ua.to_ascii_lowercase();
+        let mut res = UserAgent::default();
+        for rule in self.rules.iter() {
+            if !rule.all.iter().all(|t| lower.contains(t.as_str()))
+                || rule.none.iter().any(|t| lower.contains(t.as_str()))
+            {
+                continue;
+            }
+            fill(&mut res.make, &rule.make, ua, &lower);
+            fill(&mut res.model, &rule.model, ua, &lower);
+            fill(&mut res.os, &rule.os, ua, &lower);
+            if res.osv.is_none() {
+                res.osv = rule
+                    .osv
+                    .as_ref()
+                    .and_then(|field| field.get(ua, &lower))
+                    .and_then(version);
+            }
+            res.devicetype = res.devicetype.or(rule.devicetype);
+            res.js = res.js.or(rule.js);
+        }
+        res
+    }
+
+    /// Fills the missing fields of `device` from its user agent. Returns true if a field was set.
+    pub fn enrich(&self, device: &mut crate::Device) -> bool {
+        let ua = match device.ua {
+            Some(ref ua) => self.parse(ua),
+            None => return false,
+        };
+        let mut changed = false;
+        let mut set = |field: &mut Option<std::borrow::Cow<str>>, value: Option<String>| {
+            if field.is_none() && value.is_some() {
+                *field = value.map(Into::into);
+                changed = true;
+            }
+        };
+        set(&mut device.make, ua.make);
+        set(&mut device.model, ua.model);
+        set(&mut device.os, ua.os);
+        set(&mut device.osv, ua.osv);
+        if device.devicetype.is_none() && ua.devicetype.is_some() {
+            device.devicetype = ua.devicetype;
+            changed = true;
+        }
+        if device.js.is_none() && ua.js.is_some() {
+            device.js = ua.js.map(crate::ext::flag);
+            changed = true;
+        }
+        changed
+    }
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Self::bundled().clone()
+    }
+}
+
+impl Field {
+    fn get(&self, ua: &str, lower: &str) -> Option<String> {
+        match self {
+            Self::Value(value) => Some(value.clone()),
+            Self::Capture(capture) => capture.get(ua, lower),
+        }
+    }
+}
+
+impl Capture {
+    fn get(&self, ua: &str, lower: &str) -> Option<String> {
+        let mut start = lower.find(self.after.as_str())? + self.after.len();
+        if let Some(ref skip) = self.skip {
+            start += lower[start..].find(skip.as_str())? + skip.len();
+        }
+        let end = self
+            .until
+            .iter()
+            .filter_map(|t| lower[start..].find(t.as_str()))
+            .min()
+            .map_or(ua.len(), |end| start + end);
+        let value = ua[start..end].trim();
+        if value.is_empty() || self.exclude.iter().any(|e| e.as_str() == value.to_ascii_lowercase()) {
+            return None;
+        }
+        Some(value.to_owned())
+    }
+}
+
+fn fill(value: &mut Option<String>, field: &Option<Field>, ua: &str, lower: &str) {
+    if value.is_none() {
+        *value = field.as_ref().and_then(|field| field.get(ua, lower));
+    }
+}
+
+fn version(s: String) -> Option<String> {
+    let v: String = s
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.' || *c == '_')
+        .map(|c| if c == '_' { '.' } else { c })
+        .collect();
+    let v = v.trim_end_matches('.');
+    if v.is_empty() {
+        None
+    } else {
+        Some(v.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DeviceType;
+
+    type Fields = (
+        Option<String>,
+        Option<String>,
+        Option<String>,
+        Option<String>,
+        Option<DeviceType>,
+    );
+
+    fn parse(ua: &str) -> Fields {
+        let ua = Parser::bundled().parse(ua);
+        (ua.make, ua.model, ua.os, ua.osv, ua.devicetype)
+    }
+
+    fn s(s: &str) -> Option<String> {
+        Some(s.to_owned())
+    }
+
+    #[test]
+    fn bundled() {
+        assert_eq!(
+            parse("Mozilla/5.0 (iPad; CPU OS 16_6 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.6 Mobile/15E148 Safari/604.1"),
+            (s("Apple"), s("iPad"), s("iOS"), s("16.6"), Some(DeviceType::Tablet))
+        );
+        assert_eq!(
+            parse("Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/116.0.0.0 Mobile Safari/537.36"),
+            (None, None, s("Android"), s("10"), Some(DeviceType::Phone))
+        );
+        assert_eq!(
+            parse("Mozilla/5.0 (Linux; Android 12; Pixel Tablet Build/TQ3A.230805.001) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/116.0.0.0 Safari/537.36"),
+            (s("Google"), s("Pixel Tablet"), s("Android"), s("12"), Some(DeviceType::Tablet))
+        );
+        assert_eq!(
+            parse("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/116.0.0.0 Safari/537.36"),
+            (None, None, s("Windows"), s("10.0"), Some(DeviceType::PersonalComputer))
+        );
+        assert_eq!(
+            parse("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.6 Safari/605.1.15"),
+            (s("Apple"), s("Mac"), s("macOS"), s("10.15.7"), Some(DeviceType::PersonalComputer))
+        );
+        assert_eq!(
+            parse("Mozilla/5.0 (SMART-TV; LINUX; Tizen 6.0) AppleWebKit/537.36 (KHTML, like Gecko) 76.0.3809.146/6.0 TV Safari/537.36"),
+            (s("Samsung"), s("Smart TV"), s("Tizen"), s("6.0"), Some(DeviceType::ConnectedTv))
+        );
+        assert_eq!(
+            parse("Mozilla/5.0 (Linux; Android 9; AFTMM Build/PS7233; wv) AppleWebKit/537.36 (KHTML, like Gecko) Version/4.0 Chrome/70.0.3538.110 Mobile Safari/537.36"),
+            (s("Amazon"), s("AFTMM"), s("Android"), s("9"), Some(DeviceType::SetTopBox))
+        );
+        assert_eq!(
+            parse("Roku/DVP-9.10 (519.10E04111A)"),
+            (s("Roku"), s("Roku"), s("Roku OS"), None, Some(DeviceType::SetTopBox))
+        );
+        assert_eq!(parse("curl/8.0.1"), (None, None, None, None, None));
+        assert_eq!(
+            Parser::bundled().parse("Mozilla/5.0 (X11; Linux x86_64)").js,
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn enrich() -> serde_json::Result<()> {
+        let mut device: crate::Device = serde_json::from_str(
+            r#"{"ua":"Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)","model":"iPhone15,2","devicetype":1}"#,
+        )?;
+        assert!(Parser::bundled().enrich(&mut device));
+        assert_eq!(
+            serde_json::to_value(&device)?,
+            serde_json::json!({
+                "ua": "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)",
+                "devicetype": 1,
+                "make": "Apple",
+                "model": "iPhone15,2",
+                "os": "iOS",
+                "osv": "17.0",
+                "js": 1
+            })
+        );
+        assert!(!Parser::bundled().enrich(&mut device));
+        assert!(!Parser::bundled().enrich(&mut crate::Device::default()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom() -> serde_json::Result<()> {
+        let parser = Parser::from_json(
+            r#"[{"all":["MyBox"],"none":["Emulator"],"make":"Acme","model":{"after":"mybox/","until":[" "]},"devicetype":7}]"#,
+        )?;
+        let ua = parser.parse("MyBox/X200 Firmware/3");
+        assert_eq!(ua.make.as_deref(), Some("Acme"));
+        assert_eq!(ua.model.as_deref(), Some("X200"));
+        assert_eq!(ua.devicetype, Some(DeviceType::SetTopBox));
+        assert_eq!(parser.parse("MyBox/X200 Emulator"), UserAgent::default());
+        assert!(Parser::from_json(r#"[{"unknown":1}]"#).is_err());
+
+        Ok(())
+    }
+}
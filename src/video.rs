@@ -11,6 +11,8 @@
 /// be offered as banner, audio, and/or native by also including as Imp subordinates objects of
 /// those types. However, any given bid for the impression must conform to one of the offered types.
 #[derive(serde::Serialize, serde::Deserialize, Default, Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Video<'a> {
     /// string array; required
     /// Content MIME types supported (e.g., “video/x-ms-wmv”, “video/mp4”).
@@ -65,6 +67,8 @@ pub struct Video<'a> {
     /// If a bidder sends markup/creative that is itself skippable, the Bid object should include
     /// the attr array with an element of 16 indicating skippable video. Refer to List 5.3.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub skip: Option<json_ext::Flag>,
 
     /// integer; default 0
@@ -112,6 +116,8 @@ pub struct Video<'a> {
     /// Indicates if letter-boxing of 4:3 content into a 16:9 window is allowed, where 0 = no, 1 =
     /// yes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Flag>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_flag))]
     pub boxingallowed: Option<json_ext::Flag>,
 
     /// integer array
@@ -160,6 +166,8 @@ pub struct Video<'a> {
     /// object
     /// Placeholder for exchange-specific extensions to OpenRTB.
     #[serde(borrow, default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<crate::schema::Object>"))]
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::arbitrary::option_object))]
     pub ext: Option<json_ext::Object<'a>>,
 }
 
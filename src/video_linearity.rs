@@ -8,6 +8,8 @@
 /// This OpenRTB table has values derived from the Inventory Quality Guidelines (IQG). Practitioners
 /// should keep in sync with updates to the IQG values.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum VideoLinearity {
     /// Linear / In-Stream
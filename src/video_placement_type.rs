@@ -3,6 +3,8 @@
 /// The following table lists the various types of video placements derived largely from the IAB
 /// Digital Video Guidelines.
 #[derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[repr(i8)]
 pub enum VideoPlacementType {
     /// In-Stream
@@ -0,0 +1,2481 @@
+//! Traversal of bid requests and responses.
+//!
+//! [`Visit`] and [`VisitMut`] have a hook per object type, from `BidRequest` down to `Segment` and
+//! from `BidResponse` down to `Bid`, plus hooks for every string and every `ext` object. The
+//! default implementation of each object hook calls the function of the same name in this module,
+//! which visits the fields of the object; a pass overrides the hooks it needs and calls that
+//! function to keep descending.
+//!
+//! The functions destructure every object exhaustively, so adding a field to a type fails to
+//! compile here rather than silently being skipped by every pass.
+//!
+//! ```
+//! # use openrtb2::visit::{self, Visit, VisitMut};
+//! struct Urls(Vec<String>);
+//!
+//! impl<'a> Visit<'a> for Urls {
+//!     fn visit_str(&mut self, name: &'static str, value: &str) {
+//!         if value.starts_with("http") {
+//!             self.0.push(format!("{}={}", name, value));
+//!         }
+//!     }
+//! }
+//!
+//! struct Lowercase;
+//!
+//! impl<'a> VisitMut<'a> for Lowercase {
+//!     fn visit_site_mut(&mut self, site: &mut openrtb2::Site<'a>) {
+//!         if let Some(domain) = &mut site.domain {
+//!             *domain = domain.to_lowercase().into();
+//!         }
+//!         visit::visit_site_mut(self, site);
+//!     }
+//! }
+//!
+//! let mut req: openrtb2::BidRequest = serde_json::from_str(
+//!     r#"{"id":"1","imp":[],"site":{"domain":"Example.COM","page":"https://example.com/a"}}"#,
+//! )?;
+//! Lowercase.visit_bid_request_mut(&mut req);
+//! assert_eq!(req.channel.as_ref().and_then(|c| c.as_site()).unwrap().domain.as_deref(), Some("example.com"));
+//!
+//! let mut urls = Urls(Vec::new());
+//! urls.visit_bid_request(&req);
+//! assert_eq!(urls.0, ["page=https://example.com/a"]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`Visit`]: ./trait.Visit.html
+//! [`VisitMut`]: ./trait.VisitMut.html
+
+/// Read-only traversal of bid requests and responses.
+pub trait Visit<'a> {
+    /// Visits a `BidRequest`; the default visits its fields.
+    fn visit_bid_request(&mut self, node: &crate::BidRequest<'a>) {
+        visit_bid_request(self, node);
+    }
+
+    /// Visits a `Source`; the default visits its fields.
+    fn visit_source(&mut self, node: &crate::Source<'a>) {
+        visit_source(self, node);
+    }
+
+    /// Visits a `Regs`; the default visits its fields.
+    fn visit_regs(&mut self, node: &crate::Regs<'a>) {
+        visit_regs(self, node);
+    }
+
+    /// Visits an `Imp`; the default visits its fields.
+    fn visit_imp(&mut self, node: &crate::Imp<'a>) {
+        visit_imp(self, node);
+    }
+
+    /// Visits a `Metric`; the default visits its fields.
+    fn visit_metric(&mut self, node: &crate::Metric<'a>) {
+        visit_metric(self, node);
+    }
+
+    /// Visits a `Banner`; the default visits its fields.
+    fn visit_banner(&mut self, node: &crate::Banner<'a>) {
+        visit_banner(self, node);
+    }
+
+    /// Visits a `Format`; the default visits its fields.
+    fn visit_format(&mut self, node: &crate::Format<'a>) {
+        visit_format(self, node);
+    }
+
+    /// Visits a `Video`; the default visits its fields.
+    fn visit_video(&mut self, node: &crate::Video<'a>) {
+        visit_video(self, node);
+    }
+
+    /// Visits an `Audio`; the default visits its fields.
+    fn visit_audio(&mut self, node: &crate::Audio<'a>) {
+        visit_audio(self, node);
+    }
+
+    /// Visits a `Native`; the default visits its fields.
+    fn visit_native(&mut self, node: &crate::Native<'a>) {
+        visit_native(self, node);
+    }
+
+    /// Visits a `Pmp`; the default visits its fields.
+    fn visit_pmp(&mut self, node: &crate::Pmp<'a>) {
+        visit_pmp(self, node);
+    }
+
+    /// Visits a `Deal`; the default visits its fields.
+    fn visit_deal(&mut self, node: &crate::Deal<'a>) {
+        visit_deal(self, node);
+    }
+
+    /// Visits a `Site`; the default visits its fields.
+    fn visit_site(&mut self, node: &crate::Site<'a>) {
+        visit_site(self, node);
+    }
+
+    /// Visits an `App`; the default visits its fields.
+    fn visit_app(&mut self, node: &crate::App<'a>) {
+        visit_app(self, node);
+    }
+
+    /// Visits a `Publisher`; the default visits its fields.
+    fn visit_publisher(&mut self, node: &crate::Publisher<'a>) {
+        visit_publisher(self, node);
+    }
+
+    /// Visits a `Content`; the default visits its fields.
+    fn visit_content(&mut self, node: &crate::Content<'a>) {
+        visit_content(self, node);
+    }
+
+    /// Visits a `Producer`; the default visits its fields.
+    fn visit_producer(&mut self, node: &crate::Producer<'a>) {
+        visit_producer(self, node);
+    }
+
+    /// Visits a `Device`; the default visits its fields.
+    fn visit_device(&mut self, node: &crate::Device<'a>) {
+        visit_device(self, node);
+    }
+
+    /// Visits a `Geo`; the default visits its fields.
+    fn visit_geo(&mut self, node: &crate::Geo<'a>) {
+        visit_geo(self, node);
+    }
+
+    /// Visits a `User`; the default visits its fields.
+    fn visit_user(&mut self, node: &crate::User<'a>) {
+        visit_user(self, node);
+    }
+
+    /// Visits a `Data`; the default visits its fields.
+    fn visit_data(&mut self, node: &crate::Data<'a>) {
+        visit_data(self, node);
+    }
+
+    /// Visits a `Segment`; the default visits its fields.
+    fn visit_segment(&mut self, node: &crate::Segment<'a>) {
+        visit_segment(self, node);
+    }
+
+    /// Visits a `BidResponse`; the default visits its fields.
+    fn visit_bid_response(&mut self, node: &crate::BidResponse<'a>) {
+        visit_bid_response(self, node);
+    }
+
+    /// Visits a `SeatBid`; the default visits its fields.
+    fn visit_seat_bid(&mut self, node: &crate::SeatBid<'a>) {
+        visit_seat_bid(self, node);
+    }
+
+    /// Visits a `Bid`; the default visits its fields.
+    fn visit_bid(&mut self, node: &crate::Bid<'a>) {
+        visit_bid(self, node);
+    }
+
+    /// Visits a string field named `name`, or an element of an array of strings.
+    fn visit_str(&mut self, name: &'static str, value: &str) {
+        let _ = (name, value);
+    }
+
+    /// Visits an `ext` object.
+    fn visit_ext(&mut self, ext: &json_ext::Object<'a>) {
+        let _ = ext;
+    }
+}
+
+/// Mutable traversal of bid requests and responses.
+pub trait VisitMut<'a> {
+    /// Visits a `BidRequest`; the default visits its fields.
+    fn visit_bid_request_mut(&mut self, node: &mut crate::BidRequest<'a>) {
+        visit_bid_request_mut(self, node);
+    }
+
+    /// Visits a `Source`; the default visits its fields.
+    fn visit_source_mut(&mut self, node: &mut crate::Source<'a>) {
+        visit_source_mut(self, node);
+    }
+
+    /// Visits a `Regs`; the default visits its fields.
+    fn visit_regs_mut(&mut self, node: &mut crate::Regs<'a>) {
+        visit_regs_mut(self, node);
+    }
+
+    /// Visits an `Imp`; the default visits its fields.
+    fn visit_imp_mut(&mut self, node: &mut crate::Imp<'a>) {
+        visit_imp_mut(self, node);
+    }
+
+    /// Visits a `Metric`; the default visits its fields.
+    fn visit_metric_mut(&mut self, node: &mut crate::Metric<'a>) {
+        visit_metric_mut(self, node);
+    }
+
+    /// Visits a `Banner`; the default visits its fields.
+    fn visit_banner_mut(&mut self, node: &mut crate::Banner<'a>) {
+        visit_banner_mut(self, node);
+    }
+
+    /// Visits a `Format`; the default visits its fields.
+    fn visit_format_mut(&mut self, node: &mut crate::Format<'a>) {
+        visit_format_mut(self, node);
+    }
+
+    /// Visits a `Video`; the default visits its fields.
+    fn visit_video_mut(&mut self, node: &mut crate::Video<'a>) {
+        visit_video_mut(self, node);
+    }
+
+    /// Visits an `Audio`; the default visits its fields.
+    fn visit_audio_mut(&mut self, node: &mut crate::Audio<'a>) {
+        visit_audio_mut(self, node);
+    }
+
+    /// Visits a `Native`; the default visits its fields.
+    fn visit_native_mut(&mut self, node: &mut crate::Native<'a>) {
+        visit_native_mut(self, node);
+    }
+
+    /// Visits a `Pmp`; the default visits its fields.
+    fn visit_pmp_mut(&mut self, node: &mut crate::Pmp<'a>) {
+        visit_pmp_mut(self, node);
+    }
+
+    /// Visits a `Deal`; the default visits its fields.
+    fn visit_deal_mut(&mut self, node: &mut crate::Deal<'a>) {
+        visit_deal_mut(self, node);
+    }
+
+    /// Visits a `Site`; the default visits its fields.
+    fn visit_site_mut(&mut self, node: &mut crate::Site<'a>) {
+        visit_site_mut(self, node);
+    }
+
+    /// Visits an `App`; the default visits its fields.
+    fn visit_app_mut(&mut self, node: &mut crate::App<'a>) {
+        visit_app_mut(self, node);
+    }
+
+    /// Visits a `Publisher`; the default visits its fields.
+    fn visit_publisher_mut(&mut self, node: &mut crate::Publisher<'a>) {
+        visit_publisher_mut(self, node);
+    }
+
+    /// Visits a `Content`; the default visits its fields.
+    fn visit_content_mut(&mut self, node: &mut crate::Content<'a>) {
+        visit_content_mut(self, node);
+    }
+
+    /// Visits a `Producer`; the default visits its fields.
+    fn visit_producer_mut(&mut self, node: &mut crate::Producer<'a>) {
+        visit_producer_mut(self, node);
+    }
+
+    /// Visits a `Device`; the default visits its fields.
+    fn visit_device_mut(&mut self, node: &mut crate::Device<'a>) {
+        visit_device_mut(self, node);
+    }
+
+    /// Visits a `Geo`; the default visits its fields.
+    fn visit_geo_mut(&mut self, node: &mut crate::Geo<'a>) {
+        visit_geo_mut(self, node);
+    }
+
+    /// Visits a `User`; the default visits its fields.
+    fn visit_user_mut(&mut self, node: &mut crate::User<'a>) {
+        visit_user_mut(self, node);
+    }
+
+    /// Visits a `Data`; the default visits its fields.
+    fn visit_data_mut(&mut self, node: &mut crate::Data<'a>) {
+        visit_data_mut(self, node);
+    }
+
+    /// Visits a `Segment`; the default visits its fields.
+    fn visit_segment_mut(&mut self, node: &mut crate::Segment<'a>) {
+        visit_segment_mut(self, node);
+    }
+
+    /// Visits a `BidResponse`; the default visits its fields.
+    fn visit_bid_response_mut(&mut self, node: &mut crate::BidResponse<'a>) {
+        visit_bid_response_mut(self, node);
+    }
+
+    /// Visits a `SeatBid`; the default visits its fields.
+    fn visit_seat_bid_mut(&mut self, node: &mut crate::SeatBid<'a>) {
+        visit_seat_bid_mut(self, node);
+    }
+
+    /// Visits a `Bid`; the default visits its fields.
+    fn visit_bid_mut(&mut self, node: &mut crate::Bid<'a>) {
+        visit_bid_mut(self, node);
+    }
+
+    /// Visits a string field named `name`, or an element of an array of strings.
+    fn visit_str_mut(&mut self, name: &'static str, value: &mut std::borrow::Cow<'a, str>) {
+        let _ = (name, value);
+    }
+
+    /// Visits an `ext` object.
+    fn visit_ext_mut(&mut self, ext: &mut json_ext::Object<'a>) {
+        let _ = ext;
+    }
+}
+
+/// Visits the fields of a `BidRequest`.
+pub fn visit_bid_request<'a, V>(v: &mut V, node: &crate::BidRequest<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::BidRequest {
+        id,
+        imp,
+        channel,
+        device,
+        user,
+        test: _,
+        at: _,
+        tmax: _,
+        wseat,
+        bseat,
+        allimps: _,
+        cur,
+        wlang,
+        bcat,
+        cattax: _,
+        badv,
+        bapp,
+        source,
+        regs,
+        ext,
+    } = node;
+    v.visit_str("id", id);
+    for node in imp {
+        v.visit_imp(node);
+    }
+    match channel {
+        Some(crate::DistributionChannel::Site(site)) => v.visit_site(site),
+        Some(crate::DistributionChannel::App(app)) => v.visit_app(app),
+        None => {}
+    }
+    if let Some(node) = device {
+        v.visit_device(node);
+    }
+    if let Some(node) = user {
+        v.visit_user(node);
+    }
+    for value in wseat.iter().flatten() {
+        v.visit_str("wseat", value);
+    }
+    for value in bseat.iter().flatten() {
+        v.visit_str("bseat", value);
+    }
+    for value in cur.iter().flatten() {
+        v.visit_str("cur", value);
+    }
+    for value in wlang.iter().flatten() {
+        v.visit_str("wlang", value);
+    }
+    for value in bcat.iter().flatten() {
+        v.visit_str("bcat", value);
+    }
+    for value in badv.iter().flatten() {
+        v.visit_str("badv", value);
+    }
+    for value in bapp.iter().flatten() {
+        v.visit_str("bapp", value);
+    }
+    if let Some(node) = source {
+        v.visit_source(node);
+    }
+    if let Some(node) = regs {
+        v.visit_regs(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Source`.
+pub fn visit_source<'a, V>(v: &mut V, node: &crate::Source<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Source {
+        fd: _,
+        tid,
+        pchain,
+        ext,
+    } = node;
+    if let Some(value) = tid {
+        v.visit_str("tid", value);
+    }
+    if let Some(value) = pchain {
+        v.visit_str("pchain", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Regs`.
+pub fn visit_regs<'a, V>(v: &mut V, node: &crate::Regs<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Regs { coppa: _, ext } = node;
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of an `Imp`.
+pub fn visit_imp<'a, V>(v: &mut V, node: &crate::Imp<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Imp {
+        id,
+        metric,
+        banner,
+        video,
+        audio,
+        native,
+        pmp,
+        displaymanager,
+        displaymanagerver,
+        instl: _,
+        tagid,
+        bidfloor: _,
+        bidfloorcur,
+        clickbrowser: _,
+        secure: _,
+        iframebuster,
+        exp: _,
+        ext,
+    } = node;
+    v.visit_str("id", id);
+    for node in metric.iter().flatten() {
+        v.visit_metric(node);
+    }
+    if let Some(node) = banner {
+        v.visit_banner(node);
+    }
+    if let Some(node) = video {
+        v.visit_video(node);
+    }
+    if let Some(node) = audio {
+        v.visit_audio(node);
+    }
+    if let Some(node) = native {
+        v.visit_native(node);
+    }
+    if let Some(node) = pmp {
+        v.visit_pmp(node);
+    }
+    if let Some(value) = displaymanager {
+        v.visit_str("displaymanager", value);
+    }
+    if let Some(value) = displaymanagerver {
+        v.visit_str("displaymanagerver", value);
+    }
+    if let Some(value) = tagid {
+        v.visit_str("tagid", value);
+    }
+    if let Some(value) = bidfloorcur {
+        v.visit_str("bidfloorcur", value);
+    }
+    for value in iframebuster.iter().flatten() {
+        v.visit_str("iframebuster", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Metric`.
+pub fn visit_metric<'a, V>(v: &mut V, node: &crate::Metric<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Metric {
+        r#type: type_,
+        value: _,
+        vendor,
+        ext,
+    } = node;
+    v.visit_str("type", type_);
+    if let Some(value) = vendor {
+        v.visit_str("vendor", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Banner`.
+#[allow(deprecated)]
+pub fn visit_banner<'a, V>(v: &mut V, node: &crate::Banner<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Banner {
+        format,
+        w: _,
+        h: _,
+        wmax: _,
+        hmax: _,
+        wmin: _,
+        hmin: _,
+        btype: _,
+        battr: _,
+        pos: _,
+        mimes,
+        topframe: _,
+        expdir: _,
+        api: _,
+        id,
+        vcm: _,
+        ext,
+    } = node;
+    for node in format.iter().flatten() {
+        v.visit_format(node);
+    }
+    for value in mimes.iter().flatten() {
+        v.visit_str("mimes", value);
+    }
+    if let Some(value) = id {
+        v.visit_str("id", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Format`.
+pub fn visit_format<'a, V>(v: &mut V, node: &crate::Format<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Format {
+        w: _,
+        h: _,
+        wratio: _,
+        hratio: _,
+        wmin: _,
+        ext,
+    } = node;
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Video`.
+pub fn visit_video<'a, V>(v: &mut V, node: &crate::Video<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Video {
+        mimes,
+        minduration: _,
+        maxduration: _,
+        protocols: _,
+        w: _,
+        h: _,
+        startdelay: _,
+        placement: _,
+        linearity: _,
+        skip: _,
+        skipmin: _,
+        skipafter: _,
+        sequence: _,
+        battr: _,
+        maxextended: _,
+        minbitrate: _,
+        maxbitrate: _,
+        boxingallowed: _,
+        playbackmethod: _,
+        playbackend: _,
+        delivery: _,
+        pos: _,
+        companionad,
+        api: _,
+        companiontype: _,
+        ext,
+    } = node;
+    for value in mimes {
+        v.visit_str("mimes", value);
+    }
+    for node in companionad.iter().flatten() {
+        v.visit_banner(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of an `Audio`.
+pub fn visit_audio<'a, V>(v: &mut V, node: &crate::Audio<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Audio {
+        mimes,
+        minduration: _,
+        maxduration: _,
+        protocols: _,
+        startdelay: _,
+        sequence: _,
+        battr: _,
+        maxextended: _,
+        minbitrate: _,
+        maxbitrate: _,
+        delivery: _,
+        companionad,
+        api: _,
+        companiontype: _,
+        maxseq: _,
+        feed: _,
+        stitched: _,
+        nvol: _,
+        ext,
+    } = node;
+    for value in mimes {
+        v.visit_str("mimes", value);
+    }
+    for node in companionad.iter().flatten() {
+        v.visit_banner(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Native`.
+pub fn visit_native<'a, V>(v: &mut V, node: &crate::Native<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Native {
+        request,
+        ver,
+        api: _,
+        battr: _,
+        ext,
+    } = node;
+    v.visit_str("request", request);
+    if let Some(value) = ver {
+        v.visit_str("ver", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Pmp`.
+pub fn visit_pmp<'a, V>(v: &mut V, node: &crate::Pmp<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Pmp {
+        private_auction: _,
+        deals,
+        ext,
+    } = node;
+    for node in deals.iter().flatten() {
+        v.visit_deal(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Deal`.
+pub fn visit_deal<'a, V>(v: &mut V, node: &crate::Deal<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Deal {
+        id,
+        bidfloor: _,
+        bidfloorcur,
+        at: _,
+        wseat,
+        wadomain,
+        ext,
+    } = node;
+    v.visit_str("id", id);
+    if let Some(value) = bidfloorcur {
+        v.visit_str("bidfloorcur", value);
+    }
+    for value in wseat.iter().flatten() {
+        v.visit_str("wseat", value);
+    }
+    for value in wadomain.iter().flatten() {
+        v.visit_str("wadomain", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Site`.
+pub fn visit_site<'a, V>(v: &mut V, node: &crate::Site<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Site {
+        id,
+        name,
+        domain,
+        cat,
+        sectioncat,
+        pagecat,
+        cattax: _,
+        page,
+        r#ref: type_,
+        search,
+        mobile: _,
+        privacypolicy: _,
+        publisher,
+        content,
+        keywords,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str("name", value);
+    }
+    if let Some(value) = domain {
+        v.visit_str("domain", value);
+    }
+    for value in cat.iter().flatten() {
+        v.visit_str("cat", value);
+    }
+    for value in sectioncat.iter().flatten() {
+        v.visit_str("sectioncat", value);
+    }
+    for value in pagecat.iter().flatten() {
+        v.visit_str("pagecat", value);
+    }
+    if let Some(value) = page {
+        v.visit_str("page", value);
+    }
+    if let Some(value) = type_ {
+        v.visit_str("ref", value);
+    }
+    if let Some(value) = search {
+        v.visit_str("search", value);
+    }
+    if let Some(node) = publisher {
+        v.visit_publisher(node);
+    }
+    if let Some(node) = content {
+        v.visit_content(node);
+    }
+    if let Some(value) = keywords {
+        v.visit_str("keywords", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of an `App`.
+pub fn visit_app<'a, V>(v: &mut V, node: &crate::App<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::App {
+        id,
+        name,
+        bundle,
+        domain,
+        storeurl,
+        cat,
+        sectioncat,
+        pagecat,
+        cattax: _,
+        ver,
+        privacypolicy: _,
+        paid: _,
+        publisher,
+        content,
+        keywords,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str("name", value);
+    }
+    if let Some(value) = bundle {
+        v.visit_str("bundle", value);
+    }
+    if let Some(value) = domain {
+        v.visit_str("domain", value);
+    }
+    if let Some(value) = storeurl {
+        v.visit_str("storeurl", value);
+    }
+    for value in cat.iter().flatten() {
+        v.visit_str("cat", value);
+    }
+    for value in sectioncat.iter().flatten() {
+        v.visit_str("sectioncat", value);
+    }
+    for value in pagecat.iter().flatten() {
+        v.visit_str("pagecat", value);
+    }
+    if let Some(value) = ver {
+        v.visit_str("ver", value);
+    }
+    if let Some(node) = publisher {
+        v.visit_publisher(node);
+    }
+    if let Some(node) = content {
+        v.visit_content(node);
+    }
+    if let Some(value) = keywords {
+        v.visit_str("keywords", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Publisher`.
+pub fn visit_publisher<'a, V>(v: &mut V, node: &crate::Publisher<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Publisher {
+        id,
+        name,
+        cat,
+        cattax: _,
+        domain,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str("name", value);
+    }
+    for value in cat.iter().flatten() {
+        v.visit_str("cat", value);
+    }
+    if let Some(value) = domain {
+        v.visit_str("domain", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Content`.
+#[allow(deprecated)]
+pub fn visit_content<'a, V>(v: &mut V, node: &crate::Content<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Content {
+        id,
+        episode: _,
+        title,
+        series,
+        season,
+        artist,
+        genre,
+        album,
+        isrc,
+        producer,
+        url,
+        cat,
+        cattax: _,
+        prodq: _,
+        videoquality: _,
+        context: _,
+        contentrating,
+        userrating,
+        qagmediarating: _,
+        keywords,
+        livestream: _,
+        sourcerelationship: _,
+        len: _,
+        language,
+        embeddable: _,
+        data,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str("id", value);
+    }
+    if let Some(value) = title {
+        v.visit_str("title", value);
+    }
+    if let Some(value) = series {
+        v.visit_str("series", value);
+    }
+    if let Some(value) = season {
+        v.visit_str("season", value);
+    }
+    if let Some(value) = artist {
+        v.visit_str("artist", value);
+    }
+    if let Some(value) = genre {
+        v.visit_str("genre", value);
+    }
+    if let Some(value) = album {
+        v.visit_str("album", value);
+    }
+    if let Some(value) = isrc {
+        v.visit_str("isrc", value);
+    }
+    if let Some(node) = producer {
+        v.visit_producer(node);
+    }
+    if let Some(value) = url {
+        v.visit_str("url", value);
+    }
+    for value in cat.iter().flatten() {
+        v.visit_str("cat", value);
+    }
+    if let Some(value) = contentrating {
+        v.visit_str("contentrating", value);
+    }
+    if let Some(value) = userrating {
+        v.visit_str("userrating", value);
+    }
+    if let Some(value) = keywords {
+        v.visit_str("keywords", value);
+    }
+    if let Some(value) = language {
+        v.visit_str("language", value);
+    }
+    for node in data.iter().flatten() {
+        v.visit_data(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Producer`.
+pub fn visit_producer<'a, V>(v: &mut V, node: &crate::Producer<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Producer {
+        id,
+        name,
+        cat,
+        cattax: _,
+        domain,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str("name", value);
+    }
+    for value in cat.iter().flatten() {
+        v.visit_str("cat", value);
+    }
+    if let Some(value) = domain {
+        v.visit_str("domain", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Device`.
+pub fn visit_device<'a, V>(v: &mut V, node: &crate::Device<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Device {
+        ua,
+        geo,
+        dnt: _,
+        lmt: _,
+        ip,
+        ipv6,
+        devicetype: _,
+        make,
+        model,
+        os,
+        osv,
+        hwv,
+        h: _,
+        w: _,
+        ppi: _,
+        pxratio: _,
+        js: _,
+        geofetch: _,
+        flashver,
+        language,
+        carrier,
+        mccmnc,
+        connectiontype: _,
+        ifa,
+        didsha1,
+        didmd5,
+        dpidsha1,
+        dpidmd5,
+        macsha1,
+        macmd5,
+        ext,
+    } = node;
+    if let Some(value) = ua {
+        v.visit_str("ua", value);
+    }
+    if let Some(node) = geo {
+        v.visit_geo(node);
+    }
+    if let Some(value) = ip {
+        v.visit_str("ip", value);
+    }
+    if let Some(value) = ipv6 {
+        v.visit_str("ipv6", value);
+    }
+    if let Some(value) = make {
+        v.visit_str("make", value);
+    }
+    if let Some(value) = model {
+        v.visit_str("model", value);
+    }
+    if let Some(value) = os {
+        v.visit_str("os", value);
+    }
+    if let Some(value) = osv {
+        v.visit_str("osv", value);
+    }
+    if let Some(value) = hwv {
+        v.visit_str("hwv", value);
+    }
+    if let Some(value) = flashver {
+        v.visit_str("flashver", value);
+    }
+    if let Some(value) = language {
+        v.visit_str("language", value);
+    }
+    if let Some(value) = carrier {
+        v.visit_str("carrier", value);
+    }
+    if let Some(value) = mccmnc {
+        v.visit_str("mccmnc", value);
+    }
+    if let Some(value) = ifa {
+        v.visit_str("ifa", value);
+    }
+    if let Some(value) = didsha1 {
+        v.visit_str("didsha1", value);
+    }
+    if let Some(value) = didmd5 {
+        v.visit_str("didmd5", value);
+    }
+    if let Some(value) = dpidsha1 {
+        v.visit_str("dpidsha1", value);
+    }
+    if let Some(value) = dpidmd5 {
+        v.visit_str("dpidmd5", value);
+    }
+    if let Some(value) = macsha1 {
+        v.visit_str("macsha1", value);
+    }
+    if let Some(value) = macmd5 {
+        v.visit_str("macmd5", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Geo`.
+pub fn visit_geo<'a, V>(v: &mut V, node: &crate::Geo<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Geo {
+        lat: _,
+        lon: _,
+        r#type: _,
+        accuracy: _,
+        lastfix: _,
+        ipservice: _,
+        country,
+        region,
+        regionfips104,
+        metro,
+        city,
+        zip,
+        utcoffset: _,
+        ext,
+    } = node;
+    if let Some(value) = country {
+        v.visit_str("country", value);
+    }
+    if let Some(value) = region {
+        v.visit_str("region", value);
+    }
+    if let Some(value) = regionfips104 {
+        v.visit_str("regionfips104", value);
+    }
+    if let Some(value) = metro {
+        v.visit_str("metro", value);
+    }
+    if let Some(value) = city {
+        v.visit_str("city", value);
+    }
+    if let Some(value) = zip {
+        v.visit_str("zip", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `User`.
+pub fn visit_user<'a, V>(v: &mut V, node: &crate::User<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::User {
+        id,
+        buyeruid,
+        yob: _,
+        gender: _,
+        keywords,
+        customdata,
+        geo,
+        data,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str("id", value);
+    }
+    if let Some(value) = buyeruid {
+        v.visit_str("buyeruid", value);
+    }
+    if let Some(value) = keywords {
+        v.visit_str("keywords", value);
+    }
+    if let Some(value) = customdata {
+        v.visit_str("customdata", value);
+    }
+    if let Some(node) = geo {
+        v.visit_geo(node);
+    }
+    for node in data.iter().flatten() {
+        v.visit_data(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Data`.
+pub fn visit_data<'a, V>(v: &mut V, node: &crate::Data<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Data { id, name, segment, ext } = node;
+    if let Some(value) = id {
+        v.visit_str("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str("name", value);
+    }
+    for node in segment.iter().flatten() {
+        v.visit_segment(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Segment`.
+pub fn visit_segment<'a, V>(v: &mut V, node: &crate::Segment<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Segment { id, name, value, ext } = node;
+    if let Some(value) = id {
+        v.visit_str("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str("name", value);
+    }
+    if let Some(value) = value {
+        v.visit_str("value", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `BidResponse`.
+pub fn visit_bid_response<'a, V>(v: &mut V, node: &crate::BidResponse<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::BidResponse {
+        id,
+        seatbid,
+        bidid,
+        cur,
+        customdata,
+        nbr: _,
+        ext,
+    } = node;
+    v.visit_str("id", id);
+    for node in seatbid.iter().flatten() {
+        v.visit_seat_bid(node);
+    }
+    if let Some(value) = bidid {
+        v.visit_str("bidid", value);
+    }
+    if let Some(value) = cur {
+        v.visit_str("cur", value);
+    }
+    if let Some(value) = customdata {
+        v.visit_str("customdata", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `SeatBid`.
+pub fn visit_seat_bid<'a, V>(v: &mut V, node: &crate::SeatBid<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::SeatBid {
+        bid,
+        seat,
+        group: _,
+        ext,
+    } = node;
+    for node in bid {
+        v.visit_bid(node);
+    }
+    if let Some(value) = seat {
+        v.visit_str("seat", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `Bid`.
+pub fn visit_bid<'a, V>(v: &mut V, node: &crate::Bid<'a>)
+where
+    V: Visit<'a> + ?Sized,
+{
+    let crate::Bid {
+        id,
+        impid,
+        price: _,
+        nurl,
+        burl,
+        lurl,
+        adm,
+        adid,
+        adomain,
+        bundle,
+        iurl,
+        cid,
+        crid,
+        tactic,
+        cat,
+        cattax: _,
+        attr: _,
+        api: _,
+        protocol: _,
+        qagmediarating: _,
+        language,
+        dealid,
+        w: _,
+        h: _,
+        wratio: _,
+        hratio: _,
+        exp: _,
+        ext,
+    } = node;
+    v.visit_str("id", id);
+    v.visit_str("impid", impid);
+    if let Some(value) = nurl {
+        v.visit_str("nurl", value);
+    }
+    if let Some(value) = burl {
+        v.visit_str("burl", value);
+    }
+    if let Some(value) = lurl {
+        v.visit_str("lurl", value);
+    }
+    if let Some(value) = adm {
+        v.visit_str("adm", value);
+    }
+    if let Some(value) = adid {
+        v.visit_str("adid", value);
+    }
+    for value in adomain.iter().flatten() {
+        v.visit_str("adomain", value);
+    }
+    if let Some(value) = bundle {
+        v.visit_str("bundle", value);
+    }
+    if let Some(value) = iurl {
+        v.visit_str("iurl", value);
+    }
+    if let Some(value) = cid {
+        v.visit_str("cid", value);
+    }
+    if let Some(value) = crid {
+        v.visit_str("crid", value);
+    }
+    if let Some(value) = tactic {
+        v.visit_str("tactic", value);
+    }
+    for value in cat.iter().flatten() {
+        v.visit_str("cat", value);
+    }
+    if let Some(value) = language {
+        v.visit_str("language", value);
+    }
+    if let Some(value) = dealid {
+        v.visit_str("dealid", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext(ext);
+    }
+}
+
+/// Visits the fields of a `BidRequest`.
+pub fn visit_bid_request_mut<'a, V>(v: &mut V, node: &mut crate::BidRequest<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::BidRequest {
+        id,
+        imp,
+        channel,
+        device,
+        user,
+        test: _,
+        at: _,
+        tmax: _,
+        wseat,
+        bseat,
+        allimps: _,
+        cur,
+        wlang,
+        bcat,
+        cattax: _,
+        badv,
+        bapp,
+        source,
+        regs,
+        ext,
+    } = node;
+    v.visit_str_mut("id", id);
+    for node in imp {
+        v.visit_imp_mut(node);
+    }
+    match channel {
+        Some(crate::DistributionChannel::Site(site)) => v.visit_site_mut(site),
+        Some(crate::DistributionChannel::App(app)) => v.visit_app_mut(app),
+        None => {}
+    }
+    if let Some(node) = device {
+        v.visit_device_mut(node);
+    }
+    if let Some(node) = user {
+        v.visit_user_mut(node);
+    }
+    for value in wseat.iter_mut().flatten() {
+        v.visit_str_mut("wseat", value);
+    }
+    for value in bseat.iter_mut().flatten() {
+        v.visit_str_mut("bseat", value);
+    }
+    for value in cur.iter_mut().flatten() {
+        v.visit_str_mut("cur", value);
+    }
+    for value in wlang.iter_mut().flatten() {
+        v.visit_str_mut("wlang", value);
+    }
+    for value in bcat.iter_mut().flatten() {
+        v.visit_str_mut("bcat", value);
+    }
+    for value in badv.iter_mut().flatten() {
+        v.visit_str_mut("badv", value);
+    }
+    for value in bapp.iter_mut().flatten() {
+        v.visit_str_mut("bapp", value);
+    }
+    if let Some(node) = source {
+        v.visit_source_mut(node);
+    }
+    if let Some(node) = regs {
+        v.visit_regs_mut(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Source`.
+pub fn visit_source_mut<'a, V>(v: &mut V, node: &mut crate::Source<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Source {
+        fd: _,
+        tid,
+        pchain,
+        ext,
+    } = node;
+    if let Some(value) = tid {
+        v.visit_str_mut("tid", value);
+    }
+    if let Some(value) = pchain {
+        v.visit_str_mut("pchain", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Regs`.
+pub fn visit_regs_mut<'a, V>(v: &mut V, node: &mut crate::Regs<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Regs { coppa: _, ext } = node;
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of an `Imp`.
+pub fn visit_imp_mut<'a, V>(v: &mut V, node: &mut crate::Imp<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Imp {
+        id,
+        metric,
+        banner,
+        video,
+        audio,
+        native,
+        pmp,
+        displaymanager,
+        displaymanagerver,
+        instl: _,
+        tagid,
+        bidfloor: _,
+        bidfloorcur,
+        clickbrowser: _,
+        secure: _,
+        iframebuster,
+        exp: _,
+        ext,
+    } = node;
+    v.visit_str_mut("id", id);
+    for node in metric.iter_mut().flatten() {
+        v.visit_metric_mut(node);
+    }
+    if let Some(node) = banner {
+        v.visit_banner_mut(node);
+    }
+    if let Some(node) = video {
+        v.visit_video_mut(node);
+    }
+    if let Some(node) = audio {
+        v.visit_audio_mut(node);
+    }
+    if let Some(node) = native {
+        v.visit_native_mut(node);
+    }
+    if let Some(node) = pmp {
+        v.visit_pmp_mut(node);
+    }
+    if let Some(value) = displaymanager {
+        v.visit_str_mut("displaymanager", value);
+    }
+    if let Some(value) = displaymanagerver {
+        v.visit_str_mut("displaymanagerver", value);
+    }
+    if let Some(value) = tagid {
+        v.visit_str_mut("tagid", value);
+    }
+    if let Some(value) = bidfloorcur {
+        v.visit_str_mut("bidfloorcur", value);
+    }
+    for value in iframebuster.iter_mut().flatten() {
+        v.visit_str_mut("iframebuster", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Metric`.
+pub fn visit_metric_mut<'a, V>(v: &mut V, node: &mut crate::Metric<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Metric {
+        r#type: type_,
+        value: _,
+        vendor,
+        ext,
+    } = node;
+    v.visit_str_mut("type", type_);
+    if let Some(value) = vendor {
+        v.visit_str_mut("vendor", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Banner`.
+#[allow(deprecated)]
+pub fn visit_banner_mut<'a, V>(v: &mut V, node: &mut crate::Banner<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Banner {
+        format,
+        w: _,
+        h: _,
+        wmax: _,
+        hmax: _,
+        wmin: _,
+        hmin: _,
+        btype: _,
+        battr: _,
+        pos: _,
+        mimes,
+        topframe: _,
+        expdir: _,
+        api: _,
+        id,
+        vcm: _,
+        ext,
+    } = node;
+    for node in format.iter_mut().flatten() {
+        v.visit_format_mut(node);
+    }
+    for value in mimes.iter_mut().flatten() {
+        v.visit_str_mut("mimes", value);
+    }
+    if let Some(value) = id {
+        v.visit_str_mut("id", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Format`.
+pub fn visit_format_mut<'a, V>(v: &mut V, node: &mut crate::Format<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Format {
+        w: _,
+        h: _,
+        wratio: _,
+        hratio: _,
+        wmin: _,
+        ext,
+    } = node;
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Video`.
+pub fn visit_video_mut<'a, V>(v: &mut V, node: &mut crate::Video<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Video {
+        mimes,
+        minduration: _,
+        maxduration: _,
+        protocols: _,
+        w: _,
+        h: _,
+        startdelay: _,
+        placement: _,
+        linearity: _,
+        skip: _,
+        skipmin: _,
+        skipafter: _,
+        sequence: _,
+        battr: _,
+        maxextended: _,
+        minbitrate: _,
+        maxbitrate: _,
+        boxingallowed: _,
+        playbackmethod: _,
+        playbackend: _,
+        delivery: _,
+        pos: _,
+        companionad,
+        api: _,
+        companiontype: _,
+        ext,
+    } = node;
+    for value in mimes {
+        v.visit_str_mut("mimes", value);
+    }
+    for node in companionad.iter_mut().flatten() {
+        v.visit_banner_mut(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of an `Audio`.
+pub fn visit_audio_mut<'a, V>(v: &mut V, node: &mut crate::Audio<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Audio {
+        mimes,
+        minduration: _,
+        maxduration: _,
+        protocols: _,
+        startdelay: _,
+        sequence: _,
+        battr: _,
+        maxextended: _,
+        minbitrate: _,
+        maxbitrate: _,
+        delivery: _,
+        companionad,
+        api: _,
+        companiontype: _,
+        maxseq: _,
+        feed: _,
+        stitched: _,
+        nvol: _,
+        ext,
+    } = node;
+    for value in mimes {
+        v.visit_str_mut("mimes", value);
+    }
+    for node in companionad.iter_mut().flatten() {
+        v.visit_banner_mut(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Native`.
+pub fn visit_native_mut<'a, V>(v: &mut V, node: &mut crate::Native<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Native {
+        request,
+        ver,
+        api: _,
+        battr: _,
+        ext,
+    } = node;
+    v.visit_str_mut("request", request);
+    if let Some(value) = ver {
+        v.visit_str_mut("ver", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Pmp`.
+pub fn visit_pmp_mut<'a, V>(v: &mut V, node: &mut crate::Pmp<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Pmp {
+        private_auction: _,
+        deals,
+        ext,
+    } = node;
+    for node in deals.iter_mut().flatten() {
+        v.visit_deal_mut(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Deal`.
+pub fn visit_deal_mut<'a, V>(v: &mut V, node: &mut crate::Deal<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Deal {
+        id,
+        bidfloor: _,
+        bidfloorcur,
+        at: _,
+        wseat,
+        wadomain,
+        ext,
+    } = node;
+    v.visit_str_mut("id", id);
+    if let Some(value) = bidfloorcur {
+        v.visit_str_mut("bidfloorcur", value);
+    }
+    for value in wseat.iter_mut().flatten() {
+        v.visit_str_mut("wseat", value);
+    }
+    for value in wadomain.iter_mut().flatten() {
+        v.visit_str_mut("wadomain", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Site`.
+pub fn visit_site_mut<'a, V>(v: &mut V, node: &mut crate::Site<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Site {
+        id,
+        name,
+        domain,
+        cat,
+        sectioncat,
+        pagecat,
+        cattax: _,
+        page,
+        r#ref: type_,
+        search,
+        mobile: _,
+        privacypolicy: _,
+        publisher,
+        content,
+        keywords,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str_mut("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str_mut("name", value);
+    }
+    if let Some(value) = domain {
+        v.visit_str_mut("domain", value);
+    }
+    for value in cat.iter_mut().flatten() {
+        v.visit_str_mut("cat", value);
+    }
+    for value in sectioncat.iter_mut().flatten() {
+        v.visit_str_mut("sectioncat", value);
+    }
+    for value in pagecat.iter_mut().flatten() {
+        v.visit_str_mut("pagecat", value);
+    }
+    if let Some(value) = page {
+        v.visit_str_mut("page", value);
+    }
+    if let Some(value) = type_ {
+        v.visit_str_mut("ref", value);
+    }
+    if let Some(value) = search {
+        v.visit_str_mut("search", value);
+    }
+    if let Some(node) = publisher {
+        v.visit_publisher_mut(node);
+    }
+    if let Some(node) = content {
+        v.visit_content_mut(node);
+    }
+    if let Some(value) = keywords {
+        v.visit_str_mut("keywords", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of an `App`.
+pub fn visit_app_mut<'a, V>(v: &mut V, node: &mut crate::App<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::App {
+        id,
+        name,
+        bundle,
+        domain,
+        storeurl,
+        cat,
+        sectioncat,
+        pagecat,
+        cattax: _,
+        ver,
+        privacypolicy: _,
+        paid: _,
+        publisher,
+        content,
+        keywords,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str_mut("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str_mut("name", value);
+    }
+    if let Some(value) = bundle {
+        v.visit_str_mut("bundle", value);
+    }
+    if let Some(value) = domain {
+        v.visit_str_mut("domain", value);
+    }
+    if let Some(value) = storeurl {
+        v.visit_str_mut("storeurl", value);
+    }
+    for value in cat.iter_mut().flatten() {
+        v.visit_str_mut("cat", value);
+    }
+    for value in sectioncat.iter_mut().flatten() {
+        v.visit_str_mut("sectioncat", value);
+    }
+    for value in pagecat.iter_mut().flatten() {
+        v.visit_str_mut("pagecat", value);
+    }
+    if let Some(value) = ver {
+        v.visit_str_mut("ver", value);
+    }
+    if let Some(node) = publisher {
+        v.visit_publisher_mut(node);
+    }
+    if let Some(node) = content {
+        v.visit_content_mut(node);
+    }
+    if let Some(value) = keywords {
+        v.visit_str_mut("keywords", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Publisher`.
+pub fn visit_publisher_mut<'a, V>(v: &mut V, node: &mut crate::Publisher<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Publisher {
+        id,
+        name,
+        cat,
+        cattax: _,
+        domain,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str_mut("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str_mut("name", value);
+    }
+    for value in cat.iter_mut().flatten() {
+        v.visit_str_mut("cat", value);
+    }
+    if let Some(value) = domain {
+        v.visit_str_mut("domain", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Content`.
+#[allow(deprecated)]
+pub fn visit_content_mut<'a, V>(v: &mut V, node: &mut crate::Content<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Content {
+        id,
+        episode: _,
+        title,
+        series,
+        season,
+        artist,
+        genre,
+        album,
+        isrc,
+        producer,
+        url,
+        cat,
+        cattax: _,
+        prodq: _,
+        videoquality: _,
+        context: _,
+        contentrating,
+        userrating,
+        qagmediarating: _,
+        keywords,
+        livestream: _,
+        sourcerelationship: _,
+        len: _,
+        language,
+        embeddable: _,
+        data,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str_mut("id", value);
+    }
+    if let Some(value) = title {
+        v.visit_str_mut("title", value);
+    }
+    if let Some(value) = series {
+        v.visit_str_mut("series", value);
+    }
+    if let Some(value) = season {
+        v.visit_str_mut("season", value);
+    }
+    if let Some(value) = artist {
+        v.visit_str_mut("artist", value);
+    }
+    if let Some(value) = genre {
+        v.visit_str_mut("genre", value);
+    }
+    if let Some(value) = album {
+        v.visit_str_mut("album", value);
+    }
+    if let Some(value) = isrc {
+        v.visit_str_mut("isrc", value);
+    }
+    if let Some(node) = producer {
+        v.visit_producer_mut(node);
+    }
+    if let Some(value) = url {
+        v.visit_str_mut("url", value);
+    }
+    for value in cat.iter_mut().flatten() {
+        v.visit_str_mut("cat", value);
+    }
+    if let Some(value) = contentrating {
+        v.visit_str_mut("contentrating", value);
+    }
+    if let Some(value) = userrating {
+        v.visit_str_mut("userrating", value);
+    }
+    if let Some(value) = keywords {
+        v.visit_str_mut("keywords", value);
+    }
+    if let Some(value) = language {
+        v.visit_str_mut("language", value);
+    }
+    for node in data.iter_mut().flatten() {
+        v.visit_data_mut(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Producer`.
+pub fn visit_producer_mut<'a, V>(v: &mut V, node: &mut crate::Producer<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Producer {
+        id,
+        name,
+        cat,
+        cattax: _,
+        domain,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str_mut("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str_mut("name", value);
+    }
+    for value in cat.iter_mut().flatten() {
+        v.visit_str_mut("cat", value);
+    }
+    if let Some(value) = domain {
+        v.visit_str_mut("domain", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Device`.
+pub fn visit_device_mut<'a, V>(v: &mut V, node: &mut crate::Device<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Device {
+        ua,
+        geo,
+        dnt: _,
+        lmt: _,
+        ip,
+        ipv6,
+        devicetype: _,
+        make,
+        model,
+        os,
+        osv,
+        hwv,
+        h: _,
+        w: _,
+        ppi: _,
+        pxratio: _,
+        js: _,
+        geofetch: _,
+        flashver,
+        language,
+        carrier,
+        mccmnc,
+        connectiontype: _,
+        ifa,
+        didsha1,
+        didmd5,
+        dpidsha1,
+        dpidmd5,
+        macsha1,
+        macmd5,
+        ext,
+    } = node;
+    if let Some(value) = ua {
+        v.visit_str_mut("ua", value);
+    }
+    if let Some(node) = geo {
+        v.visit_geo_mut(node);
+    }
+    if let Some(value) = ip {
+        v.visit_str_mut("ip", value);
+    }
+    if let Some(value) = ipv6 {
+        v.visit_str_mut("ipv6", value);
+    }
+    if let Some(value) = make {
+        v.visit_str_mut("make", value);
+    }
+    if let Some(value) = model {
+        v.visit_str_mut("model", value);
+    }
+    if let Some(value) = os {
+        v.visit_str_mut("os", value);
+    }
+    if let Some(value) = osv {
+        v.visit_str_mut("osv", value);
+    }
+    if let Some(value) = hwv {
+        v.visit_str_mut("hwv", value);
+    }
+    if let Some(value) = flashver {
+        v.visit_str_mut("flashver", value);
+    }
+    if let Some(value) = language {
+        v.visit_str_mut("language", value);
+    }
+    if let Some(value) = carrier {
+        v.visit_str_mut("carrier", value);
+    }
+    if let Some(value) = mccmnc {
+        v.visit_str_mut("mccmnc", value);
+    }
+    if let Some(value) = ifa {
+        v.visit_str_mut("ifa", value);
+    }
+    if let Some(value) = didsha1 {
+        v.visit_str_mut("didsha1", value);
+    }
+    if let Some(value) = didmd5 {
+        v.visit_str_mut("didmd5", value);
+    }
+    if let Some(value) = dpidsha1 {
+        v.visit_str_mut("dpidsha1", value);
+    }
+    if let Some(value) = dpidmd5 {
+        v.visit_str_mut("dpidmd5", value);
+    }
+    if let Some(value) = macsha1 {
+        v.visit_str_mut("macsha1", value);
+    }
+    if let Some(value) = macmd5 {
+        v.visit_str_mut("macmd5", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Geo`.
+pub fn visit_geo_mut<'a, V>(v: &mut V, node: &mut crate::Geo<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Geo {
+        lat: _,
+        lon: _,
+        r#type: _,
+        accuracy: _,
+        lastfix: _,
+        ipservice: _,
+        country,
+        region,
+        regionfips104,
+        metro,
+        city,
+        zip,
+        utcoffset: _,
+        ext,
+    } = node;
+    if let Some(value) = country {
+        v.visit_str_mut("country", value);
+    }
+    if let Some(value) = region {
+        v.visit_str_mut("region", value);
+    }
+    if let Some(value) = regionfips104 {
+        v.visit_str_mut("regionfips104", value);
+    }
+    if let Some(value) = metro {
+        v.visit_str_mut("metro", value);
+    }
+    if let Some(value) = city {
+        v.visit_str_mut("city", value);
+    }
+    if let Some(value) = zip {
+        v.visit_str_mut("zip", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `User`.
+pub fn visit_user_mut<'a, V>(v: &mut V, node: &mut crate::User<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::User {
+        id,
+        buyeruid,
+        yob: _,
+        gender: _,
+        keywords,
+        customdata,
+        geo,
+        data,
+        ext,
+    } = node;
+    if let Some(value) = id {
+        v.visit_str_mut("id", value);
+    }
+    if let Some(value) = buyeruid {
+        v.visit_str_mut("buyeruid", value);
+    }
+    if let Some(value) = keywords {
+        v.visit_str_mut("keywords", value);
+    }
+    if let Some(value) = customdata {
+        v.visit_str_mut("customdata", value);
+    }
+    if let Some(node) = geo {
+        v.visit_geo_mut(node);
+    }
+    for node in data.iter_mut().flatten() {
+        v.visit_data_mut(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Data`.
+pub fn visit_data_mut<'a, V>(v: &mut V, node: &mut crate::Data<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Data { id, name, segment, ext } = node;
+    if let Some(value) = id {
+        v.visit_str_mut("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str_mut("name", value);
+    }
+    for node in segment.iter_mut().flatten() {
+        v.visit_segment_mut(node);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Segment`.
+pub fn visit_segment_mut<'a, V>(v: &mut V, node: &mut crate::Segment<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Segment { id, name, value, ext } = node;
+    if let Some(value) = id {
+        v.visit_str_mut("id", value);
+    }
+    if let Some(value) = name {
+        v.visit_str_mut("name", value);
+    }
+    if let Some(value) = value {
+        v.visit_str_mut("value", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `BidResponse`.
+pub fn visit_bid_response_mut<'a, V>(v: &mut V, node: &mut crate::BidResponse<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::BidResponse {
+        id,
+        seatbid,
+        bidid,
+        cur,
+        customdata,
+        nbr: _,
+        ext,
+    } = node;
+    v.visit_str_mut("id", id);
+    for node in seatbid.iter_mut().flatten() {
+        v.visit_seat_bid_mut(node);
+    }
+    if let Some(value) = bidid {
+        v.visit_str_mut("bidid", value);
+    }
+    if let Some(value) = cur {
+        v.visit_str_mut("cur", value);
+    }
+    if let Some(value) = customdata {
+        v.visit_str_mut("customdata", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `SeatBid`.
+pub fn visit_seat_bid_mut<'a, V>(v: &mut V, node: &mut crate::SeatBid<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::SeatBid {
+        bid,
+        seat,
+        group: _,
+        ext,
+    } = node;
+    for node in bid {
+        v.visit_bid_mut(node);
+    }
+    if let Some(value) = seat {
+        v.visit_str_mut("seat", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+/// Visits the fields of a `Bid`.
+pub fn visit_bid_mut<'a, V>(v: &mut V, node: &mut crate::Bid<'a>)
+where
+    V: VisitMut<'a> + ?Sized,
+{
+    let crate::Bid {
+        id,
+        impid,
+        price: _,
+        nurl,
+        burl,
+        lurl,
+        adm,
+        adid,
+        adomain,
+        bundle,
+        iurl,
+        cid,
+        crid,
+        tactic,
+        cat,
+        cattax: _,
+        attr: _,
+        api: _,
+        protocol: _,
+        qagmediarating: _,
+        language,
+        dealid,
+        w: _,
+        h: _,
+        wratio: _,
+        hratio: _,
+        exp: _,
+        ext,
+    } = node;
+    v.visit_str_mut("id", id);
+    v.visit_str_mut("impid", impid);
+    if let Some(value) = nurl {
+        v.visit_str_mut("nurl", value);
+    }
+    if let Some(value) = burl {
+        v.visit_str_mut("burl", value);
+    }
+    if let Some(value) = lurl {
+        v.visit_str_mut("lurl", value);
+    }
+    if let Some(value) = adm {
+        v.visit_str_mut("adm", value);
+    }
+    if let Some(value) = adid {
+        v.visit_str_mut("adid", value);
+    }
+    for value in adomain.iter_mut().flatten() {
+        v.visit_str_mut("adomain", value);
+    }
+    if let Some(value) = bundle {
+        v.visit_str_mut("bundle", value);
+    }
+    if let Some(value) = iurl {
+        v.visit_str_mut("iurl", value);
+    }
+    if let Some(value) = cid {
+        v.visit_str_mut("cid", value);
+    }
+    if let Some(value) = crid {
+        v.visit_str_mut("crid", value);
+    }
+    if let Some(value) = tactic {
+        v.visit_str_mut("tactic", value);
+    }
+    for value in cat.iter_mut().flatten() {
+        v.visit_str_mut("cat", value);
+    }
+    if let Some(value) = language {
+        v.visit_str_mut("language", value);
+    }
+    if let Some(value) = dealid {
+        v.visit_str_mut("dealid", value);
+    }
+    if let Some(ext) = ext {
+        v.visit_ext_mut(ext);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Default)]
+    struct Counter {
+        strs: usize,
+        exts: usize,
+        segments: usize,
+        bids: usize,
+    }
+
+    impl<'a> Visit<'a> for Counter {
+        fn visit_segment(&mut self, node: &crate::Segment<'a>) {
+            self.segments += 1;
+            visit_segment(self, node);
+        }
+
+        fn visit_bid(&mut self, node: &crate::Bid<'a>) {
+            self.bids += 1;
+            visit_bid(self, node);
+        }
+
+        fn visit_str(&mut self, _: &'static str, _: &str) {
+            self.strs += 1;
+        }
+
+        fn visit_ext(&mut self, _: &json_ext::Object<'a>) {
+            self.exts += 1;
+        }
+    }
+
+    struct Scrub;
+
+    impl<'a> VisitMut<'a> for Scrub {
+        fn visit_str_mut(&mut self, name: &'static str, value: &mut std::borrow::Cow<'a, str>) {
+            if name == "ip" || name == "buyeruid" {
+                *value = "".into();
+            }
+        }
+
+        fn visit_ext_mut(&mut self, ext: &mut json_ext::Object<'a>) {
+            *ext = serde_json::from_str("{}").unwrap();
+        }
+    }
+
+    #[test]
+    fn request() -> serde_json::Result<()> {
+        let json = r#"{
+            "id": "1",
+            "imp": [{"id": "1", "banner": {"mimes": ["image/png"], "ext": {"a": 1}}}],
+            "app": {"publisher": {"id": "p"}},
+            "device": {"ip": "192.0.2.1", "geo": {"country": "USA"}},
+            "user": {"buyeruid": "u", "data": [{"segment": [{"id": "s1"}, {"value": "v"}]}], "ext": {"b": 2}}
+        }"#;
+        let mut req: crate::BidRequest = serde_json::from_str(json)?;
+
+        let mut counter = Counter::default();
+        counter.visit_bid_request(&req);
+        assert_eq!((counter.strs, counter.exts, counter.segments), (9, 2, 2));
+
+        Scrub.visit_bid_request_mut(&mut req);
+        assert_eq!(req.device.as_ref().unwrap().ip.as_deref(), Some(""));
+        let user = req.user.as_ref().unwrap();
+        assert_eq!(user.buyeruid.as_deref(), Some(""));
+        assert_eq!(serde_json::to_string(&user.ext)?, "{}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn response() -> serde_json::Result<()> {
+        let json = include_str!("../tests/json/6.4.3_direct_deal_ad_served_on_win_notice.json");
+        let res: crate::BidResponse = serde_json::from_str(json)?;
+
+        let mut counter = Counter::default();
+        counter.visit_bid_response(&res);
+        assert_eq!((counter.bids, counter.strs), (1, 13));
+
+        Ok(())
+    }
+}
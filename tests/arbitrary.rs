@@ -0,0 +1,117 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+
+/// Number of values generated per type.
+const CASES: u64 = 512;
+
+/// Fills `buf` with deterministic pseudo-random bytes (xorshift64*).
+fn fill(seed: u64, buf: &mut [u8]) {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    for chunk in buf.chunks_mut(8) {
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        let bytes = state.wrapping_mul(0x2545_F491_4F6C_DD1D).to_le_bytes();
+        chunk.copy_from_slice(&bytes[..chunk.len()]);
+    }
+}
+
+/// Checks that values of each type generated from `CASES` inputs of growing size survive a JSON
+/// round-trip.
+macro_rules! round_trip {
+    ($($name:ident: $ty:ty,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut buf = vec![0; 4096];
+                for seed in 0..CASES {
+                    let len = (seed as usize * 8) % buf.len();
+                    fill(seed, &mut buf[..len]);
+                    let value = <$ty>::arbitrary(&mut Unstructured::new(&buf[..len])).unwrap();
+                    let json = serde_json::to_string(&value).unwrap();
+                    let decoded: $ty = serde_json::from_str(&json).unwrap_or_else(|e| panic!("{}: {}", e, json));
+                    assert_eq!(decoded, value, "{}", json);
+                }
+            }
+        )*
+    };
+}
+
+round_trip! {
+    bid_request: openrtb2::BidRequest,
+    source: openrtb2::Source,
+    regs: openrtb2::Regs,
+    imp: openrtb2::Imp,
+    metric: openrtb2::Metric,
+    banner: openrtb2::Banner,
+    video: openrtb2::Video,
+    audio: openrtb2::Audio,
+    native: openrtb2::Native,
+    format: openrtb2::Format,
+    pmp: openrtb2::Pmp,
+    deal: openrtb2::Deal,
+    site: openrtb2::Site,
+    app: openrtb2::App,
+    publisher: openrtb2::Publisher,
+    content: openrtb2::Content,
+    producer: openrtb2::Producer,
+    device: openrtb2::Device,
+    geo: openrtb2::Geo,
+    user: openrtb2::User,
+    data: openrtb2::Data,
+    segment: openrtb2::Segment,
+    bid_response: openrtb2::BidResponse,
+    seat_bid: openrtb2::SeatBid,
+    bid: openrtb2::Bid,
+    distribution_channel: openrtb2::DistributionChannel,
+    banner_ad_type: openrtb2::BannerAdType,
+    creative_attribute: openrtb2::CreativeAttribute,
+    ad_position: openrtb2::AdPosition,
+    expandable_direction: openrtb2::ExpandableDirection,
+    api_framework: openrtb2::ApiFramework,
+    video_linearity: openrtb2::VideoLinearity,
+    protocol: openrtb2::Protocol,
+    video_placement_type: openrtb2::VideoPlacementType,
+    playback_method: openrtb2::PlaybackMethod,
+    playback_cessation_mode: openrtb2::PlaybackCessationMode,
+    start_delay: openrtb2::StartDelay,
+    production_quality: openrtb2::ProductionQuality,
+    companion_type: openrtb2::CompanionType,
+    content_delivery_method: openrtb2::ContentDeliveryMethod,
+    feed_type: openrtb2::FeedType,
+    volume_normalization_mode: openrtb2::VolumeNormalizationMode,
+    content_context: openrtb2::ContentContext,
+    iqg_media_rating: openrtb2::IqgMediaRating,
+    location_type: openrtb2::LocationType,
+    device_type: openrtb2::DeviceType,
+    connection_type: openrtb2::ConnectionType,
+    ip_location_service: openrtb2::IpLocationService,
+    no_bid_reason: openrtb2::NoBidReason,
+    loss_reason: openrtb2::LossReason,
+    auction_type: openrtb2::AuctionType,
+    gender: openrtb2::Gender,
+    max_extended_ad_duration: openrtb2::MaxExtendedAdDuration,
+    category_taxonomy: openrtb2::CategoryTaxonomy,
+    video_companion_mode: openrtb2::VideoCompanionMode,
+    source_relationship: openrtb2::SourceRelationship,
+    sale_decision: openrtb2::SaleDecision,
+}
+
+#[test]
+fn spec_valid() {
+    let mut buf = vec![0; 4096];
+    for seed in 0..CASES {
+        fill(seed, &mut buf);
+        let mut u = Unstructured::new(&buf);
+        let req = openrtb2::BidRequest::arbitrary(&mut u).unwrap();
+        assert!(!req.imp.is_empty());
+        assert!(req.imp.iter().filter_map(|imp| imp.bidfloor).all(|floor| floor >= 0.0));
+        if let openrtb2::AuctionType::ExchangeSpecific(value) = openrtb2::AuctionType::arbitrary(&mut u).unwrap() {
+            assert!(value > 500);
+        }
+        if let openrtb2::StartDelay::MidRoll(value) = openrtb2::StartDelay::arbitrary(&mut u).unwrap() {
+            assert!(value > 0);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+#![cfg(all(feature = "http-client", feature = "axum"))]
+
+use openrtb2::http::{Client, Encoding, OpenRtbRequest, OpenRtbResponse, Reply};
+
+async fn bidder(req: OpenRtbRequest) -> OpenRtbResponse<'static> {
+    if req.request.imp.is_empty() {
+        return req.respond(None);
+    }
+
+    let bid = openrtb2::Bid {
+        id: "1".into(),
+        impid: req.request.imp[0].id.clone(),
+        price: 1.5,
+        ..Default::default()
+    };
+    req.respond(Some(openrtb2::BidResponse {
+        id: req.request.id.clone(),
+        seatbid: Some(vec![openrtb2::SeatBid {
+            bid: vec![bid],
+            ..Default::default()
+        }]),
+        ..Default::default()
+    }))
+}
+
+async fn serve() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = axum::Router::new().route("/bid", axum::routing::post(bidder));
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{}/bid", addr)
+}
+
+#[tokio::test]
+async fn loopback() -> Result<(), openrtb2::http::Error> {
+    let url = serve().await;
+    let client = Client::default().encoding(Encoding::Gzip);
+
+    let mut req = openrtb2::BidRequest {
+        id: "req".into(),
+        imp: vec![openrtb2::Imp {
+            id: "imp".into(),
+            ..Default::default()
+        }],
+        tmax: Some(500),
+        ..Default::default()
+    };
+    match client.send(&url, &req).await? {
+        Reply::Bid(res) => {
+            assert_eq!(res.id, "req");
+            assert_eq!(res.seatbid.unwrap()[0].bid[0].impid, "imp");
+        }
+        reply => panic!("unexpected reply: {:?}", reply),
+    }
+
+    req.imp.clear();
+    assert_eq!(client.send(&url, &req).await?, Reply::NoBid(None));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn body_limit() -> Result<(), openrtb2::http::Error> {
+    let app = axum::Router::new()
+        .route("/bid", axum::routing::post(bidder))
+        .layer(axum::Extension(openrtb2::http::BodyLimit(64)));
+    let url = serve_with(app).await;
+
+    let req = openrtb2::BidRequest {
+        id: "req".repeat(64).into(),
+        imp: vec![Default::default()],
+        ..Default::default()
+    };
+    let client = Client::default().encoding(Encoding::Gzip);
+    match client.send(&url, &req).await {
+        Err(openrtb2::http::Error::Status(status)) => assert_eq!(status, axum::http::StatusCode::PAYLOAD_TOO_LARGE),
+        reply => panic!("unexpected reply: {:?}", reply),
+    }
+
+    Ok(())
+}
+
+async fn serve_with(app: axum::Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{}/bid", addr)
+}
+
+#[tokio::test]
+async fn fan_out() -> Result<(), openrtb2::http::Error> {
+    use openrtb2::http::{Bidder, FanOut};
+
+    async fn slow(req: OpenRtbRequest) -> OpenRtbResponse<'static> {
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        bidder(req).await
+    }
+    async fn broken() -> axum::http::StatusCode {
+        axum::http::StatusCode::INTERNAL_SERVER_ERROR
+    }
+
+    let fast = serve().await;
+    let slow = serve_with(axum::Router::new().route("/bid", axum::routing::post(slow))).await;
+    let broken = serve_with(axum::Router::new().route("/bid", axum::routing::post(broken))).await;
+
+    let fan_out = FanOut::new(
+        Client::default(),
+        vec![
+            Bidder::new("fast", fast),
+            Bidder::new("slow", slow),
+            Bidder::new("broken", broken),
+        ],
+    )
+    .allowance(std::time::Duration::from_millis(50));
+
+    let req = openrtb2::BidRequest {
+        id: "req".into(),
+        imp: vec![openrtb2::Imp {
+            id: "imp".into(),
+            ..Default::default()
+        }],
+        tmax: Some(250),
+        ..Default::default()
+    };
+    let results = fan_out.send(&req).await?;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].bidder, "fast");
+    assert_eq!(results[0].response().unwrap().id, "req");
+    assert!(results[1].is_timeout());
+    assert!(results[1].latency < std::time::Duration::from_millis(500));
+    assert!(results[2].is_error());
+
+    Ok(())
+}
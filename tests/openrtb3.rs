@@ -0,0 +1,117 @@
+use openrtb2::openrtb3;
+
+fn paths(unmapped: Vec<openrtb3::Unmapped>) -> Vec<String> {
+    unmapped.into_iter().map(|unmapped| unmapped.path).collect()
+}
+
+macro_rules! test_request {
+    ($name:ident, $path:expr, [$($unmapped:expr),*]) => {
+        #[test]
+        fn $name() -> Result<(), Box<dyn std::error::Error>> {
+            let json = include_str!($path);
+            let req = serde_json::from_str::<openrtb2::BidRequest>(json)?;
+            let expected: Vec<&str> = vec![$($unmapped),*];
+            let (openrtb, unmapped) = openrtb3::from_bid_request(&req);
+            assert_eq!(paths(unmapped), expected);
+
+            let payload = serde_json::to_string(&openrtb3::Payload { openrtb })?;
+            let payload = serde_json::from_str::<openrtb3::Payload>(&payload)?;
+            let (back, unmapped) = openrtb3::to_bid_request(&payload.openrtb)?;
+            assert!(unmapped.is_empty());
+            if expected.is_empty() {
+                assert_eq!(back, req);
+            }
+            Ok(())
+        }
+    };
+}
+
+macro_rules! test_response {
+    ($name:ident, $path:expr) => {
+        #[test]
+        fn $name() -> Result<(), Box<dyn std::error::Error>> {
+            let json = include_str!($path);
+            let res = serde_json::from_str::<openrtb2::BidResponse>(json)?;
+            let (openrtb, unmapped) = openrtb3::from_bid_response(&res);
+            assert!(unmapped.is_empty());
+
+            let payload = serde_json::to_string(&openrtb3::Payload { openrtb })?;
+            let payload = serde_json::from_str::<openrtb3::Payload>(&payload)?;
+            let (back, unmapped) = openrtb3::to_bid_response(&payload.openrtb)?;
+            assert!(unmapped.is_empty());
+            assert_eq!(back, res);
+            Ok(())
+        }
+    };
+}
+
+test_request!(simple_banner, "json/6.3.1_simple_banner.json", []);
+test_request!(
+    expandable_creative,
+    "json/6.3.2_expandable_creative.json",
+    ["imp[0].banner.expdir"]
+);
+test_request!(mobile, "json/6.3.3_mobile.json", ["imp[0].banner.btype"]);
+test_request!(
+    video,
+    "json/6.3.4_video.json",
+    [
+        "imp[0].video.playbackmethod",
+        "imp[0].video.companionad[0].battr",
+        "imp[0].video.companionad[0].expdir",
+        "imp[0].video.companionad[1].battr",
+        "device.flashver"
+    ]
+);
+test_request!(pmp_with_direct_deal, "json/6.3.5_pmp_with_direct_deal.json", []);
+test_request!(native_ad, "json/6.3.6_native_ad.json", ["imp[0].native.request"]);
+
+test_response!(ad_served_on_win_notice, "json/6.4.1_ad_served_on_win_notice.json");
+test_response!(
+    vast_xml_document_returned_inline,
+    "json/6.4.2_vast_xml_document_returned_inline.json"
+);
+test_response!(
+    direct_deal_ad_served_on_win_notice,
+    "json/6.4.3_direct_deal_ad_served_on_win_notice.json"
+);
+test_response!(
+    native_markup_returned_inline,
+    "json/6.4.4_native_markup_returned_inline.json"
+);
+
+#[test]
+fn downgrade_request() -> Result<(), Box<dyn std::error::Error>> {
+    let payload = serde_json::from_str::<openrtb3::Payload>(include_str!("json/openrtb3/request.json"))?;
+    let (req, unmapped) = openrtb3::to_bid_request(&payload.openrtb)?;
+    assert_eq!(
+        paths(unmapped),
+        [
+            "request.source.ts",
+            "request.source.ds",
+            "request.source.dsmap",
+            "request.source.cert"
+        ]
+    );
+
+    let expected = serde_json::from_str::<serde_json::Value>(include_str!("json/openrtb3/request_2.5.json"))?;
+    assert_eq!(serde_json::to_value(&req)?, expected);
+    Ok(())
+}
+
+#[test]
+fn downgrade_response() -> Result<(), Box<dyn std::error::Error>> {
+    let payload = serde_json::from_str::<openrtb3::Payload>(include_str!("json/openrtb3/response.json"))?;
+    let (res, unmapped) = openrtb3::to_bid_response(&payload.openrtb)?;
+    assert_eq!(
+        paths(unmapped),
+        [
+            "response.seatbid[0].bid[0].macro",
+            "response.seatbid[0].bid[0].media.ad.secure"
+        ]
+    );
+
+    let expected = serde_json::from_str::<serde_json::Value>(include_str!("json/openrtb3/response_2.5.json"))?;
+    assert_eq!(serde_json::to_value(&res)?, expected);
+    Ok(())
+}